@@ -0,0 +1,182 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use std::error;
+use std::fmt;
+use syn::{Attribute, DataStruct, Fields, Generics, Ident, Index, Member, Meta, NestedMeta};
+
+/// Supported block layouts.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Layout {
+  Std140,
+  Std430,
+}
+
+impl fmt::Display for Layout {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      Layout::Std140 => f.write_str("std140"),
+      Layout::Std430 => f.write_str("std430"),
+    }
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug)]
+pub(crate) enum DeriveBlockLayoutError {
+  UnsupportedUnit,
+  UnsupportedGenerics(Ident),
+  MissingReprC(Ident),
+}
+
+impl DeriveBlockLayoutError {
+  pub(crate) fn unsupported_unit() -> Self {
+    DeriveBlockLayoutError::UnsupportedUnit
+  }
+
+  pub(crate) fn unsupported_generics(ident: Ident) -> Self {
+    DeriveBlockLayoutError::UnsupportedGenerics(ident)
+  }
+
+  pub(crate) fn missing_repr_c(ident: Ident) -> Self {
+    DeriveBlockLayoutError::MissingReprC(ident)
+  }
+}
+
+impl fmt::Display for DeriveBlockLayoutError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      DeriveBlockLayoutError::UnsupportedUnit => f.write_str("unsupported unit struct"),
+      DeriveBlockLayoutError::UnsupportedGenerics(ref ident) => {
+        write!(f, "unsupported generic struct {}", ident)
+      }
+      DeriveBlockLayoutError::MissingReprC(ref ident) => {
+        write!(f, "{} must be annotated with #[repr(C)]", ident)
+      }
+    }
+  }
+}
+
+impl error::Error for DeriveBlockLayoutError {}
+
+/// Generate the Std140 or Std430 impl for a struct, along with its compile-time layout checks.
+pub(crate) fn generate_block_layout_impl<'a, A>(
+  ident: Ident,
+  attrs: A,
+  generics: Generics,
+  struct_: DataStruct,
+  layout: Layout,
+) -> Result<TokenStream, DeriveBlockLayoutError>
+where
+  A: Iterator<Item = &'a Attribute>,
+{
+  if !generics.params.is_empty() {
+    return Err(DeriveBlockLayoutError::unsupported_generics(ident));
+  }
+
+  if !has_repr_c(attrs) {
+    return Err(DeriveBlockLayoutError::missing_repr_c(ident));
+  }
+
+  let fields: Vec<_> = match struct_.fields {
+    Fields::Named(named_fields) => named_fields
+      .named
+      .into_iter()
+      .map(|field| (Member::Named(field.ident.unwrap()), field.ty))
+      .collect(),
+
+    Fields::Unnamed(unnamed_fields) => unnamed_fields
+      .unnamed
+      .into_iter()
+      .enumerate()
+      .map(|(i, field)| (Member::Unnamed(Index::from(i)), field.ty))
+      .collect(),
+
+    Fields::Unit => return Err(DeriveBlockLayoutError::unsupported_unit()),
+  };
+
+  // the base alignment of a structure is rounded up to the one of a vec4 in std140
+  let (layout_trait, base_alignment) = match layout {
+    Layout::Std140 => (quote! { luminance::block_layout::Std140 }, 16usize),
+    Layout::Std430 => (quote! { luminance::block_layout::Std430 }, 1usize),
+  };
+
+  let field_tys: Vec<_> = fields.iter().map(|(_, ty)| ty).collect();
+
+  let checks = fields.iter().map(|(member, ty)| {
+    let field_name = match member {
+      Member::Named(ident) => ident.to_string(),
+      Member::Unnamed(index) => index.index.to_string(),
+    };
+    let offset_msg = format!(
+      "field {} of {} is not at the offset required by {}; add explicit padding before it",
+      field_name, ident, layout
+    );
+    let size_msg = format!(
+      "field {} of {} doesn’t have the size required by {}",
+      field_name, ident, layout
+    );
+
+    quote! {
+      let offset = luminance::block_layout::align_offset(offset, <#ty as #layout_trait>::ALIGNMENT);
+      assert!(std::mem::offset_of!(#ident, #member) == offset, #offset_msg);
+      assert!(std::mem::size_of::<#ty>() == <#ty as #layout_trait>::SIZE, #size_msg);
+      let offset = offset + <#ty as #layout_trait>::SIZE;
+    }
+  });
+
+  let tail_msg = format!(
+    "{} doesn’t have the size required by {}; add explicit tail padding",
+    ident, layout
+  );
+
+  let output = quote! {
+    unsafe impl #layout_trait for #ident {
+      const ALIGNMENT: usize = {
+        let alignment = #base_alignment;
+        #(
+          let alignment = luminance::block_layout::max_alignment(
+            alignment,
+            <#field_tys as #layout_trait>::ALIGNMENT
+          );
+        )*
+        alignment
+      };
+
+      const SIZE: usize = {
+        let offset = 0usize;
+        #(
+          let offset = luminance::block_layout::align_offset(
+            offset,
+            <#field_tys as #layout_trait>::ALIGNMENT
+          ) + <#field_tys as #layout_trait>::SIZE;
+        )*
+        luminance::block_layout::align_offset(offset, <Self as #layout_trait>::ALIGNMENT)
+      };
+    }
+
+    const _: () = {
+      let offset = 0usize;
+      #(#checks)*
+      let _ = offset;
+      assert!(std::mem::size_of::<#ident>() == <#ident as #layout_trait>::SIZE, #tail_msg);
+    };
+  };
+
+  Ok(output.into())
+}
+
+fn has_repr_c<'a, A>(attrs: A) -> bool
+where
+  A: Iterator<Item = &'a Attribute>,
+{
+  attrs
+    .filter(|attr| attr.path.is_ident("repr"))
+    .filter_map(|attr| attr.parse_meta().ok())
+    .any(|meta| match meta {
+      Meta::List(list) => list.nested.iter().any(|nested| match nested {
+        NestedMeta::Meta(Meta::Path(path)) => path.is_ident("C"),
+        _ => false,
+      }),
+      _ => false,
+    })
+}
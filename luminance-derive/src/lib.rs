@@ -21,19 +21,30 @@
 //!
 //! [See the full documentation here](https://docs.rs/luminance-derive/latest/luminance_derive/derive.UniformInterface.html)
 //!
+//! # `Std140` and `Std430`
+//!
+//! Those macros allow to derive the [`Std140`] and [`Std430`] traits for a custom `#[repr(C)]`
+//! `struct` type, checking its memory layout at compile time.
+//!
+//! [See the full documentation here](https://docs.rs/luminance-derive/latest/luminance_derive/derive.Std140.html)
+//!
 //! [luminance]: https://docs.rs/luminance
 //! [`Vertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Vertex.html
 //! [`Semantics`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Semantics.html
+//! [`Std140`]: https://docs.rs/luminance/latest/luminance/block_layout/trait.Std140.html
+//! [`Std430`]: https://docs.rs/luminance/latest/luminance/block_layout/trait.Std430.html
 
 #![deny(missing_docs)]
 
 extern crate proc_macro;
 
 mod attrib;
+mod block_layout;
 mod semantics;
 mod uniform_interface;
 mod vertex;
 
+use crate::block_layout::{generate_block_layout_impl, Layout};
 use crate::semantics::generate_enum_semantics_impl;
 use crate::uniform_interface::generate_uniform_interface_impl;
 use crate::vertex::generate_vertex_impl;
@@ -228,3 +239,98 @@ pub fn derive_uniform_interface(input: TokenStream) -> TokenStream {
     _ => panic!("only structs are currently supported for deriving UniformInterface"),
   }
 }
+
+/// The [`Std140`] derive proc-macro.
+///
+/// Deriving [`Std140`] allows to use a `#[repr(C)]` struct in a uniform block laid out with the
+/// `std140` rules. Every field must have a type implementing [`Std140`] — scalars, vectors,
+/// matrices, [`Aligned16`], [`Array`] or another struct deriving [`Std140`].
+///
+/// The derived implementation checks, at compile time, that every field lies at the offset
+/// mandated by the `std140` rules and has the right size. If it’s not the case, compilation fails
+/// and you must insert explicit padding fields:
+///
+/// ```
+/// # use luminance::block_layout::{Aligned16, Array};
+/// # use luminance_derive::Std140;
+///
+/// #[derive(Clone, Copy, Std140)]
+/// #[repr(C)]
+/// struct Light {
+///   position: [f32; 3],
+///   intensity: f32, // packs into the last 4 bytes of position
+///   color: [f32; 3],
+///   _pad: f32, // the next field is 16-byte aligned
+///   weights: Array<Aligned16<f32>, 4>, // std140 arrays have a 16-byte stride
+/// }
+/// ```
+///
+/// The size of the struct must also be a multiple of its `std140` alignment, so you might need to
+/// add explicit padding at the end of it. For instance, this fails to compile, as `std140` rounds
+/// `Tail` up to 16 bytes while it’s only 12 bytes long in Rust:
+///
+/// ```compile_fail
+/// # use luminance_derive::Std140;
+///
+/// #[derive(Clone, Copy, Std140)]
+/// #[repr(C)]
+/// struct Tail {
+///   position: [f32; 2],
+///   intensity: f32,
+///   // missing _pad: f32,
+/// }
+/// ```
+///
+/// Generic structs are not supported.
+///
+/// [`Std140`]: https://docs.rs/luminance/latest/luminance/block_layout/trait.Std140.html
+/// [`Aligned16`]: https://docs.rs/luminance/latest/luminance/block_layout/struct.Aligned16.html
+/// [`Array`]: https://docs.rs/luminance/latest/luminance/block_layout/struct.Array.html
+#[proc_macro_derive(Std140)]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+  derive_block_layout(input, Layout::Std140)
+}
+
+/// The [`Std430`] derive proc-macro.
+///
+/// This is the same as the [`Std140`](derive.Std140.html) derive proc-macro, but for storage
+/// blocks laid out with the `std430` rules, in which arrays and structs are not rounded up to
+/// 16 bytes.
+///
+/// ```
+/// # use luminance::block_layout::Array;
+/// # use luminance_derive::Std430;
+///
+/// #[derive(Clone, Copy, Std430)]
+/// #[repr(C)]
+/// struct Particle {
+///   position: [f32; 3],
+///   life: f32,
+///   weights: Array<f32, 4>, // std430 arrays of scalars are tightly packed
+/// }
+/// ```
+///
+/// [`Std430`]: https://docs.rs/luminance/latest/luminance/block_layout/trait.Std430.html
+#[proc_macro_derive(Std430)]
+pub fn derive_std430(input: TokenStream) -> TokenStream {
+  derive_block_layout(input, Layout::Std430)
+}
+
+fn derive_block_layout(input: TokenStream, layout: Layout) -> TokenStream {
+  let di: DeriveInput = parse_macro_input!(input);
+
+  match di.data {
+    // for now, we only handle structs
+    Data::Struct(struct_) => {
+      match generate_block_layout_impl(di.ident, di.attrs.iter(), di.generics, struct_, layout) {
+        Ok(impl_) => impl_,
+        Err(e) => panic!("{}", e),
+      }
+    }
+
+    _ => panic!(
+      "only structs are currently supported for deriving {}",
+      layout
+    ),
+  }
+}
@@ -0,0 +1,90 @@
+use luminance::block_layout::{Aligned16, Array, Std140, Std430};
+use luminance_derive::{Std140, Std430};
+
+#[test]
+fn derive_std140() {
+  #[derive(Clone, Copy, Std140)]
+  #[repr(C)]
+  struct Light {
+    position: [f32; 3],
+    intensity: f32,
+    color: [f32; 3],
+    _pad: f32,
+  }
+
+  assert_eq!(<Light as Std140>::ALIGNMENT, 16);
+  assert_eq!(<Light as Std140>::SIZE, 32);
+}
+
+#[test]
+fn derive_std140_arrays() {
+  #[derive(Clone, Copy, Std140)]
+  #[repr(C)]
+  struct Weights {
+    count: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    weights: Array<Aligned16<f32>, 4>,
+  }
+
+  assert_eq!(<Weights as Std140>::SIZE, 80);
+}
+
+#[test]
+fn derive_std140_nested() {
+  #[derive(Clone, Copy, Std140)]
+  #[repr(C)]
+  struct Light {
+    position: [f32; 4],
+  }
+
+  #[derive(Clone, Copy, Std140)]
+  #[repr(C)]
+  struct Lights {
+    lights: Array<Light, 8>,
+    count: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+  }
+
+  assert_eq!(<Lights as Std140>::SIZE, 144);
+}
+
+#[test]
+fn derive_std140_matrices() {
+  #[derive(Clone, Copy, Std140)]
+  #[repr(C)]
+  struct Transform {
+    normal: [Aligned16<[f32; 3]>; 3],
+    model: [[f32; 4]; 4],
+  }
+
+  assert_eq!(<Transform as Std140>::SIZE, 112);
+}
+
+#[test]
+fn derive_std430() {
+  #[derive(Clone, Copy, Std430)]
+  #[repr(C)]
+  struct Particle {
+    position: [f32; 3],
+    life: f32,
+    weights: Array<f32, 3>,
+    _pad: f32,
+  }
+
+  assert_eq!(<Particle as Std430>::ALIGNMENT, 16);
+  assert_eq!(<Particle as Std430>::SIZE, 32);
+}
+
+#[test]
+fn derive_std430_unnamed() {
+  #[derive(Clone, Copy, Std430)]
+  #[repr(C)]
+  struct Pair([f32; 2], f32, f32);
+
+  assert_eq!(<Pair as Std430>::ALIGNMENT, 8);
+  assert_eq!(<Pair as Std430>::SIZE, 16);
+}
@@ -258,10 +258,14 @@ pub fn render_scene(scene: &mut Scene) {
             // Pick the right tessellation to use depending on the mode chosen and render it to the
             // surface.
             match tess_method {
-              TessMethod::Direct => tess_gate.render(direct_triangles),
-              TessMethod::Indexed => tess_gate.render(indexed_triangles),
-              TessMethod::DirectDeinterleaved => tess_gate.render(direct_deinterleaved_triangles),
-              TessMethod::IndexedDeinterleaved => tess_gate.render(indexed_deinterleaved_triangles),
+              TessMethod::Direct => tess_gate.render(direct_triangles).unwrap(),
+              TessMethod::Indexed => tess_gate.render(indexed_triangles).unwrap(),
+              TessMethod::DirectDeinterleaved => {
+                tess_gate.render(direct_deinterleaved_triangles).unwrap()
+              }
+              TessMethod::IndexedDeinterleaved => {
+                tess_gate.render(indexed_deinterleaved_triangles).unwrap()
+              }
             }
           });
         });
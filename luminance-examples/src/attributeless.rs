@@ -73,7 +73,7 @@ fn main() {
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
             // render the tessellation to the surface the regular way and let the vertex shader’s
             // magic do the rest!
-            tess_gate.render(&tess);
+            tess_gate.render(&tess).unwrap();
           });
         });
       },
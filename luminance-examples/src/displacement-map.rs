@@ -164,7 +164,7 @@ fn main() {
           );
 
           render_gate.render(&render_state, |mut tess_gate| {
-            tess_gate.render(&tess);
+            tess_gate.render(&tess).unwrap();
           })
         });
       },
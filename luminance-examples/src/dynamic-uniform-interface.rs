@@ -147,7 +147,7 @@ fn main() {
           //}
 
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-            tess_gate.render(&triangle);
+            tess_gate.render(&triangle).unwrap();
           });
         });
       },
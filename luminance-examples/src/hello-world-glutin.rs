@@ -261,13 +261,13 @@ fn main() {
                 // Pick the right tessellation to use depending on the mode chosen and render to
                 // the surface.
                 match demo {
-                  TessMethod::Direct => tess_gate.render(&direct_triangles),
-                  TessMethod::Indexed => tess_gate.render(&indexed_triangles),
+                  TessMethod::Direct => tess_gate.render(&direct_triangles).unwrap(),
+                  TessMethod::Indexed => tess_gate.render(&indexed_triangles).unwrap(),
                   TessMethod::DirectDeinterleaved => {
-                    tess_gate.render(&direct_deinterleaved_triangles)
+                    tess_gate.render(&direct_deinterleaved_triangles).unwrap()
                   }
                   TessMethod::IndexedDeinterleaved => {
-                    tess_gate.render(&indexed_deinterleaved_triangles)
+                    tess_gate.render(&indexed_deinterleaved_triangles).unwrap()
                   }
                 }
               });
@@ -159,7 +159,7 @@ fn main() {
       |_, mut shd_gate| {
         shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-            tess_gate.render(&triangle);
+            tess_gate.render(&triangle).unwrap();
           })
         });
       },
@@ -145,7 +145,7 @@ fn main() {
         shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
             // we render the triangle here by asking for the whole triangle
-            tess_gate.render(&triangle);
+            tess_gate.render(&triangle).unwrap();
           });
         });
       },
@@ -175,7 +175,7 @@ fn main() {
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
             // this will render the attributeless quad with both the offscreen framebuffer color
             // slots bound for the shader to fetch from
-            tess_gate.render(&quad);
+            tess_gate.render(&quad).unwrap();
           });
         });
       },
@@ -127,7 +127,7 @@ fn main() {
             rdr_gate.render(&RenderState::default(), |mut tess_gate| {
               // pick the right tessellation to use depending on the mode chosen
               // render the tessellation to the surface
-              tess_gate.render(&tris);
+              tess_gate.render(&tris).unwrap();
             });
           });
         });
@@ -160,13 +160,13 @@ fn main() {
 
           rdr_gate.render(&render_state, |mut tess_gate| match depth_method {
             DepthMethod::Under => {
-              tess_gate.render(&red_triangle);
-              tess_gate.render(&blue_triangle);
+              tess_gate.render(&red_triangle).unwrap();
+              tess_gate.render(&blue_triangle).unwrap();
             }
 
             DepthMethod::Atop => {
-              tess_gate.render(&blue_triangle);
-              tess_gate.render(&red_triangle);
+              tess_gate.render(&blue_triangle).unwrap();
+              tess_gate.render(&red_triangle).unwrap();
             }
           });
         });
@@ -193,7 +193,7 @@ fn main() {
               iface.set(&uni.triangle_size, t.cos().powf(2.));
 
               rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-                tess_gate.render(&triangle);
+                tess_gate.render(&triangle).unwrap();
               });
             });
           }
@@ -207,7 +207,7 @@ fn main() {
               iface.set(&uni.triangle_pos, triangle_pos);
 
               rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-                tess_gate.render(&triangle);
+                tess_gate.render(&triangle).unwrap();
               });
             });
           }
@@ -146,7 +146,7 @@ fn main() {
 
           rdr_gate.render(&RenderState::default(), |mut tess_gate| {
             // render the dynamically selected slice
-            tess_gate.render(&triangle);
+            tess_gate.render(&triangle).unwrap();
           });
         });
       },
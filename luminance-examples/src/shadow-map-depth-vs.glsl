@@ -0,0 +1,8 @@
+in vec3 co3;
+
+uniform mat4 light_view_proj;
+uniform mat4 model;
+
+void main() {
+  gl_Position = light_view_proj * model * vec4(co3, 1.);
+}
@@ -0,0 +1,21 @@
+in vec3 v_nor;
+in vec4 v_light_co;
+
+out vec4 frag;
+
+uniform vec3 light_dir;
+uniform vec3 color;
+uniform sampler2DShadow shadow_map;
+
+void main() {
+  // from the light clip space to the shadow map space, [0;1]
+  vec3 co = v_light_co.xyz / v_light_co.w * .5 + .5;
+
+  // the depth comparison is performed by the sampler; with linear filtering, we get the ratio of
+  // the neighboring texels that are not in shadow
+  float lit = texture(shadow_map, co);
+  float diffuse = max(0., dot(normalize(v_nor), light_dir));
+
+  frag = vec4(color * (.2 + .8 * diffuse * lit), 1.);
+  frag = pow(frag, vec4(1./2.2));
+}
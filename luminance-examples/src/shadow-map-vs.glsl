@@ -0,0 +1,18 @@
+in vec3 co3;
+in vec3 nor;
+
+out vec3 v_nor;
+out vec4 v_light_co;
+
+uniform mat4 projection;
+uniform mat4 view;
+uniform mat4 model;
+uniform mat4 light_view_proj;
+
+void main() {
+  vec4 p = model * vec4(co3, 1.);
+  gl_Position = projection * view * p;
+
+  v_nor = mat3(model) * nor;
+  v_light_co = light_view_proj * p;
+}
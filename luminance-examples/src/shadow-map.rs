@@ -0,0 +1,217 @@
+//! This program shows how to implement shadow mapping. The scene is first rendered from the point
+//! of view of a directional light into a depth-only framebuffer — the shadow map. The scene is then
+//! rendered to the screen, sampling the shadow map with a depth comparison to know whether
+//! fragments are lit.
+//!
+//! Press <escape> to quit or close the window.
+//!
+//! https://docs.rs/luminance
+
+mod common;
+
+use cgmath::{ortho, perspective, Deg, InnerSpace, Matrix4, Point3, Rad, Vector3};
+use glfw::{Action, Context as _, Key, WindowEvent};
+use luminance_derive::UniformInterface;
+use luminance_front::context::GraphicsContext as _;
+use luminance_front::depth_test::DepthComparison;
+use luminance_front::pipeline::{PipelineState, TextureBinding};
+use luminance_front::pixel::{Depth32F, Floating};
+use luminance_front::polygon_offset::PolygonOffset;
+use luminance_front::render_state::RenderState;
+use luminance_front::shader::Uniform;
+use luminance_front::tess::Mode;
+use luminance_front::texture::{Dim2, MagFilter, MinFilter, Sampler};
+use luminance_glfw::GlfwSurface;
+use luminance_windowing::{WindowDim, WindowOpt};
+
+// shader rendering the depth of the scene, as seen from the light
+const DEPTH_VS: &str = include_str!("shadow-map-depth-vs.glsl");
+const DEPTH_FS: &str = include_str!("shadow-map-depth-fs.glsl");
+
+// shader rendering the lit scene
+const VS: &str = include_str!("shadow-map-vs.glsl");
+const FS: &str = include_str!("shadow-map-fs.glsl");
+
+// size of the shadow map; the bigger, the sharper the shadows
+const SHADOW_MAP_SIZE: u32 = 2048;
+
+const FOVY: Deg<f32> = Deg(60.);
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 100.;
+
+#[derive(UniformInterface)]
+struct DepthShaderInterface {
+  #[uniform(unbound)]
+  light_view_proj: Uniform<[[f32; 4]; 4]>,
+  #[uniform(unbound)]
+  model: Uniform<[[f32; 4]; 4]>,
+}
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  #[uniform(unbound)]
+  projection: Uniform<[[f32; 4]; 4]>,
+  #[uniform(unbound)]
+  view: Uniform<[[f32; 4]; 4]>,
+  #[uniform(unbound)]
+  model: Uniform<[[f32; 4]; 4]>,
+  #[uniform(unbound)]
+  light_view_proj: Uniform<[[f32; 4]; 4]>,
+  #[uniform(unbound)]
+  light_dir: Uniform<[f32; 3]>,
+  #[uniform(unbound)]
+  color: Uniform<[f32; 3]>,
+  // a sampler2DShadow in the shader
+  #[uniform(unbound)]
+  shadow_map: Uniform<TextureBinding<Dim2, Floating>>,
+}
+
+fn main() {
+  let dim = WindowDim::Windowed {
+    width: 960,
+    height: 540,
+  };
+  let mut surface = GlfwSurface::new_gl33("Shadow map", WindowOpt::default().set_dim(dim))
+    .expect("GLFW surface creation");
+
+  let mut depth_program = surface
+    .new_shader_program::<common::Semantics, (), DepthShaderInterface>()
+    .from_strings(DEPTH_VS, None, None, DEPTH_FS)
+    .expect("depth program creation")
+    .ignore_warnings();
+
+  let mut program = surface
+    .new_shader_program::<common::Semantics, (), ShaderInterface>()
+    .from_strings(VS, None, None, FS)
+    .expect("program creation")
+    .ignore_warnings();
+
+  // a single cube is used for both the ground (scaled) and the object casting a shadow
+  let (cube_vertices, cube_indices) = common::cube(1.);
+  let cube = surface
+    .new_tess()
+    .set_vertices(&cube_vertices[..])
+    .set_indices(&cube_indices[..])
+    .set_mode(Mode::TriangleStrip)
+    .enable_primitive_restart()
+    .build()
+    .unwrap();
+
+  // the shadow map is a framebuffer without color slot; its depth texture is sampled with a depth
+  // comparison, and linear filtering smooths the edges of the shadows
+  let shadow_sampler = Sampler {
+    min_filter: MinFilter::Linear,
+    mag_filter: MagFilter::Linear,
+    depth_comparison: Some(DepthComparison::LessOrEqual),
+    ..Sampler::default()
+  };
+  let mut shadow_map = surface
+    .new_framebuffer::<Dim2, (), Depth32F>([SHADOW_MAP_SIZE, SHADOW_MAP_SIZE], 0, shadow_sampler)
+    .expect("shadow map creation");
+
+  // the light is directional; its view encloses the whole scene in an orthographic projection
+  let light_pos = Point3::new(4., 8., 3.);
+  let light_dir = (light_pos - Point3::new(0., 0., 0.)).normalize();
+  let light_view = Matrix4::look_at(light_pos, Point3::new(0., 0., 0.), Vector3::unit_y());
+  let light_view_proj: [[f32; 4]; 4] = (ortho(-8., 8., -8., 8., 1., 20.) * light_view).into();
+
+  let view: [[f32; 4]; 4] = Matrix4::look_at(
+    Point3::new(5., 5., 7.),
+    Point3::new(0., 0., 0.),
+    Vector3::unit_y(),
+  )
+  .into();
+
+  let ground_model: [[f32; 4]; 4] = (Matrix4::from_translation(Vector3::new(0., -1., 0.))
+    * Matrix4::from_nonuniform_scale(12., 0.2, 12.))
+  .into();
+
+  let mut back_buffer = surface.back_buffer().unwrap();
+  let [width, height] = back_buffer.size();
+  let mut projection = perspective(FOVY, width as f32 / height as f32, Z_NEAR, Z_FAR);
+
+  'app: loop {
+    surface.window.glfw.poll_events();
+    for (_, event) in surface.events_rx.try_iter() {
+      match event {
+        WindowEvent::Close | WindowEvent::Key(Key::Escape, _, Action::Release, _) => break 'app,
+
+        WindowEvent::FramebufferSize(width, height) => {
+          back_buffer = surface.back_buffer().unwrap();
+          projection = perspective(FOVY, width as f32 / height as f32, Z_NEAR, Z_FAR);
+        }
+
+        _ => (),
+      }
+    }
+
+    // the cube spins so that its shadow moves on the ground
+    let t = surface.window.glfw.get_time() as f32;
+    let cube_model: [[f32; 4]; 4] = (Matrix4::from_translation(Vector3::new(0., 0.5, 0.))
+      * Matrix4::from_angle_y(Rad(t))
+      * Matrix4::from_angle_x(Rad(t * 0.5)))
+    .into();
+
+    let objects = [
+      (ground_model, [0.8, 0.8, 0.8]),
+      (cube_model, [0.9, 0.3, 0.2]),
+    ];
+
+    let mut pipeline_gate = surface.new_pipeline_gate();
+
+    // first pass: render the depth of the scene, as seen from the light, into the shadow map; no
+    // color is written, and the depth is offset to prevent shadow acne
+    let depth_state = RenderState::depth_only().set_polygon_offset(PolygonOffset::shadow_map());
+    let render =
+      pipeline_gate.pipeline(&shadow_map, &PipelineState::default(), |_, mut shd_gate| {
+        shd_gate.shade(&mut depth_program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.light_view_proj, light_view_proj);
+
+          for (model, _) in &objects {
+            iface.set(&uni.model, *model);
+
+            rdr_gate.render(&depth_state, |mut tess_gate| {
+              tess_gate.render(&cube).unwrap();
+            });
+          }
+        });
+      });
+
+    if render.is_err() {
+      break 'app;
+    }
+
+    // second pass: render the scene to the screen, fetching the shadow map to know whether
+    // fragments are lit
+    let render = pipeline_gate.pipeline(
+      &back_buffer,
+      &PipelineState::default().set_clear_color([0.1, 0.1, 0.1, 1.]),
+      |pipeline, mut shd_gate| {
+        let bound_shadow_map = pipeline.bind_texture(shadow_map.depth_slot()).unwrap();
+
+        shd_gate.shade(&mut program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.projection, projection.into());
+          iface.set(&uni.view, view);
+          iface.set(&uni.light_view_proj, light_view_proj);
+          iface.set(&uni.light_dir, light_dir.into());
+          iface.set(&uni.shadow_map, bound_shadow_map.binding());
+
+          for (model, color) in &objects {
+            iface.set(&uni.model, *model);
+            iface.set(&uni.color, *color);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(&cube).unwrap();
+            });
+          }
+        });
+      },
+    );
+
+    if render.is_ok() {
+      surface.window.swap_buffers();
+    } else {
+      break 'app;
+    }
+  }
+}
@@ -215,7 +215,7 @@ fn run() -> Result<(), AppError> {
     .set_vertices(&cube_vertices[..])
     .set_indices(&cube_indices[..])
     .set_mode(Mode::TriangleStrip)
-    .set_primitive_restart_index(common::VertexIndex::max_value())
+    .enable_primitive_restart()
     .build()
     .unwrap();
 
@@ -410,7 +410,7 @@ fn run() -> Result<(), AppError> {
           iface.set(&unis.skybox, environment_map.binding());
 
           rdr_gate.render(&rdr_st, |mut tess_gate| {
-            tess_gate.render(&fullscreen_quad);
+            tess_gate.render(&fullscreen_quad).unwrap();
           });
         });
 
@@ -424,7 +424,7 @@ fn run() -> Result<(), AppError> {
             iface.set(&unis.environment, environment_map.binding());
 
             rdr_gate.render(&RenderState::default(), |mut tess_gate| {
-              tess_gate.render(&cube);
+              tess_gate.render(&cube).unwrap();
             });
           },
         );
@@ -149,7 +149,7 @@ fn main() {
             .unwrap();
 
             // render the dynamically selected view
-            tess_gate.render(view);
+            tess_gate.render(view).unwrap();
           });
         });
       },
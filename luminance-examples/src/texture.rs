@@ -117,7 +117,7 @@ fn run(texture_path: &Path) {
           rdr_gate.render(render_st, |mut tess_gate| {
             // render the tessellation to the surface the regular way and let the vertex shader’s
             // magic do the rest!
-            tess_gate.render(&tess);
+            tess_gate.render(&tess).unwrap();
           });
         });
       },
@@ -1,4 +1,4 @@
 use crate::Backend;
 
 pub type Buffer<T> = luminance::buffer::Buffer<Backend, T>;
-pub use luminance::buffer::{BufferError, BufferSlice, BufferSliceMut};
+pub use luminance::buffer::{BufferError, BufferSlice, BufferSliceMut, BufferUsage};
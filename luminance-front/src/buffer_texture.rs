@@ -0,0 +1,3 @@
+use crate::Backend;
+
+pub type BufferTexture<P> = luminance::buffer_texture::BufferTexture<Backend, P>;
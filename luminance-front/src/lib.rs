@@ -60,10 +60,13 @@
 //! [luminance]: https://crates.io/crates/luminance
 
 pub mod buffer;
+pub mod buffer_texture;
 pub mod context;
 pub mod framebuffer;
+pub mod palette;
 pub mod pipeline;
 pub mod render_gate;
+pub mod renderbuffer;
 pub mod shader;
 pub mod shading_gate;
 pub mod tess;
@@ -72,10 +75,18 @@ pub mod texture;
 
 // re-export
 pub use luminance::blending;
+pub use luminance::block_layout;
+pub use luminance::capabilities;
+pub use luminance::color_mask;
 pub use luminance::depth_test;
 pub use luminance::face_culling;
+pub use luminance::loader;
 pub use luminance::pixel;
+pub use luminance::polygon_offset;
+pub use luminance::provoking_vertex;
 pub use luminance::render_state;
+pub use luminance::scissor;
+pub use luminance::stencil;
 pub use luminance::vertex;
 
 // select the backend type
@@ -0,0 +1,4 @@
+use crate::Backend;
+
+pub type MatrixPalette = luminance::palette::MatrixPalette<Backend>;
+pub use luminance::palette::M44;
@@ -1,10 +1,13 @@
 use crate::Backend;
 
 pub use luminance::pipeline::{
-  BufferBinding, PipelineError, PipelineState, TextureBinding, Viewport,
+  BufferBinding, BufferTextureBinding, ClearColor, Eye, PipelineError, PipelineState,
+  TextureBinding, Viewport, FRAME_UNIFORM_BUFFER_BINDING,
 };
 
 pub type Pipeline<'a> = luminance::pipeline::Pipeline<'a, Backend>;
 pub type PipelineGate<'a> = luminance::pipeline::PipelineGate<'a, Backend>;
 pub type BoundBuffer<'a, T> = luminance::pipeline::BoundBuffer<'a, Backend, T>;
+pub type BoundAtomicCounterBuffer<'a> = luminance::pipeline::BoundAtomicCounterBuffer<'a, Backend>;
 pub type BoundTexture<'a, D, P> = luminance::pipeline::BoundTexture<'a, Backend, D, P>;
+pub type BoundBufferTexture<'a, P> = luminance::pipeline::BoundBufferTexture<'a, Backend, P>;
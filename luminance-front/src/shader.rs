@@ -1,8 +1,8 @@
 use crate::Backend;
 
 pub use luminance::shader::{
-  ProgramError, ProgramWarning, StageError, StageType, TessellationStages, Uniform, UniformType,
-  UniformWarning, VertexAttribWarning,
+  ActiveAttribute, ProgramError, ProgramWarning, StageError, StageType, TessellationStages,
+  Uniform, UniformType, UniformWarning, VertexAttribWarning,
 };
 
 pub type Stage = luminance::shader::Stage<Backend>;
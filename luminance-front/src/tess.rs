@@ -1,8 +1,8 @@
 use crate::Backend;
 
 pub use luminance::tess::{
-  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndexType, TessMapError,
-  TessViewError,
+  Aabb, Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndexType,
+  TessMapError, TessViewError,
 };
 
 pub type TessBuilder<'a, V, I, W, S> = luminance::tess::TessBuilder<'a, Backend, V, I, W, S>;
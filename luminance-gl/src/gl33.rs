@@ -1,27 +1,61 @@
 //! OpenGL 3.3 backend.
 
 mod buffer;
+mod buffer_texture;
+mod capabilities;
 mod depth_test;
+mod ext;
 mod framebuffer;
 mod pipeline;
 mod pixel;
+mod renderbuffer;
 mod shader;
 mod state;
+mod stencil;
 mod tess;
 mod texture;
 mod vertex_restart;
 
 pub use self::state::GLState;
 pub use self::state::StateQueryError;
+use gl::types::*;
+use luminance::render_state::RenderState;
 use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::ptr;
 use std::rc::Rc;
 
+/// Load the OpenGL functions with a custom loader.
+///
+/// `loader` is given the name of each OpenGL function (e.g. `"glDrawArrays"`) and must return its
+/// address — typically with the `get_proc_address` function of the windowing or platform layer —
+/// or a null pointer if the function is not available.
+///
+/// That function must be called once the OpenGL context is current, before any OpenGL use — and
+/// then before [`GL33::new`]. The platform crates (`luminance-glfw`, `luminance-glutin`, etc.)
+/// already do it when creating their surfaces; only custom integrations need to call it.
+///
+/// Besides the core functions, it loads the extension functions the backend uses. Loading the
+/// functions with `gl::load_with` instead works, but then the backend considers those extensions
+/// unsupported.
+pub fn load_with<F>(mut loader: F)
+where
+  F: FnMut(&'static str) -> *const c_void,
+{
+  gl::load_with(&mut loader);
+  self::ext::load_with(&mut loader);
+}
+
 /// The OpenGL backend.
 pub struct GL33 {
   pub(crate) state: Rc<RefCell<GLState>>,
 }
 
 impl GL33 {
+  /// Create the backend from the current OpenGL context.
+  ///
+  /// The OpenGL functions must have been loaded first; see [`load_with`].
   pub fn new() -> Result<Self, StateQueryError> {
     GLState::new().map(|state| GL33 {
       state: Rc::new(RefCell::new(state)),
@@ -31,4 +65,113 @@ impl GL33 {
   pub unsafe fn state(&self) -> &Rc<RefCell<GLState>> {
     &self.state
   }
+
+  /// Run a closure issuing raw OpenGL calls outside of luminance’s control.
+  ///
+  /// This is the integration point for third-party renderers sharing the OpenGL context, such as
+  /// immediate-mode UI backends. Once the closure returns, the whole cached graphics state is
+  /// invalidated, so that luminance re-applies everything it needs on its next commands, whatever
+  /// the closure changed.
+  ///
+  /// The OpenGL state is not snapshotted: the closure starts with whatever state luminance left
+  /// and must set everything it relies on, as UI backends usually do. Restoring luminance’s side is
+  /// done lazily by the invalidation, without querying OpenGL.
+  pub fn interop<F, R>(&mut self, f: F) -> R
+  where
+    F: FnOnce() -> R,
+  {
+    let r = f();
+    self.invalidate_state();
+    r
+  }
+
+  /// Invalidate the whole cached graphics state.
+  ///
+  /// luminance skips the OpenGL calls that would set the state it knows is already set. If
+  /// external code changes the OpenGL state without going through [`GL33::interop`] — e.g. from a
+  /// callback of a third-party library — call that function afterwards: the next pipelines and
+  /// render states are then applied in full, whatever the previous state. Only the first commands
+  /// after the invalidation pay for it.
+  pub fn invalidate_state(&mut self) {
+    self.state.borrow_mut().invalidate_all();
+  }
+
+  /// Read back the render state currently set in the OpenGL context.
+  ///
+  /// Contrary to the cached state luminance uses to skip redundant calls, the returned
+  /// [`RenderState`] is built by querying OpenGL directly. Comparing it to the render state you
+  /// expect to be set helps finding state leaks, such as external OpenGL code changing the state
+  /// without going through [`GL33::interop`]. Only the blending, color mask, depth test, face
+  /// culling and scissor configurations are read back; the other aspects are left to their
+  /// default values.
+  ///
+  /// This is a debugging tool: querying OpenGL state stalls the pipeline.
+  pub fn read_render_state(&self) -> Result<RenderState, StateQueryError> {
+    unsafe { self::state::get_ctx_render_state() }
+  }
+
+  /// Check whether the OpenGL context was lost.
+  ///
+  /// A context can be lost on a graphics reset (driver crash or update, GPU switch, etc.). All the
+  /// resources created with it (buffers, textures, shaders, framebuffers, etc.) are then dead: they
+  /// must be dropped and created again with a new context and a new [`GL33`] backend. Once a loss
+  /// is detected, that function keeps returning `true`.
+  ///
+  /// Graphics resets are only reported by robust contexts (created with a reset notification
+  /// strategy), with OpenGL 4.5 or the `GL_KHR_robustness` extension. On other contexts, that
+  /// function always returns `false`. Call it once per frame, for instance before starting a
+  /// pipeline gate.
+  pub fn is_context_lost(&self) -> bool {
+    unsafe { self.state.borrow_mut().is_context_lost() }
+  }
+
+  /// Check whether the OpenGL context is a debug context.
+  pub fn is_debug_context(&self) -> bool {
+    let mut flags = 0;
+    unsafe { gl::GetIntegerv(gl::CONTEXT_FLAGS, &mut flags) };
+    flags as GLenum & gl::CONTEXT_FLAG_DEBUG_BIT != 0
+  }
+
+  /// Route the OpenGL debug messages to the [log](https://crates.io/crates/log) crate.
+  ///
+  /// Messages are logged synchronously — i.e. from the OpenGL call causing them — with a level
+  /// matching their severity. Most drivers only emit messages for debug contexts, which must be
+  /// requested when creating the context: `luminance-glfw` does it and calls that function when
+  /// `WindowOpt::debug` is set, while `luminance-glutin` and `luminance-sdl2` call it whenever the
+  /// context they create is a debug context (see [`GL33::is_debug_context`]).
+  ///
+  /// Return `false` if debug output is not supported, which requires OpenGL 4.3 or the
+  /// `GL_KHR_debug` extension.
+  pub fn enable_debug_output(&mut self) -> bool {
+    if !gl::DebugMessageCallback::is_loaded() {
+      return false;
+    }
+
+    unsafe {
+      gl::Enable(gl::DEBUG_OUTPUT);
+      gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+      gl::DebugMessageCallback(Some(log_debug_message), ptr::null());
+    }
+
+    true
+  }
+}
+
+extern "system" fn log_debug_message(
+  _: GLenum,
+  _: GLenum,
+  id: GLuint,
+  severity: GLenum,
+  _: GLsizei,
+  message: *const GLchar,
+  _: *mut c_void,
+) {
+  let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+
+  match severity {
+    gl::DEBUG_SEVERITY_HIGH => log::error!("OpenGL ({}): {}", id, message),
+    gl::DEBUG_SEVERITY_MEDIUM => log::warn!("OpenGL ({}): {}", id, message),
+    gl::DEBUG_SEVERITY_LOW => log::info!("OpenGL ({}): {}", id, message),
+    _ => log::debug!("OpenGL ({}): {}", id, message),
+  }
 }
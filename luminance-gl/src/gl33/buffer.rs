@@ -13,7 +13,7 @@ use std::slice;
 use crate::gl33::state::{Bind, GLState};
 use crate::gl33::GL33;
 use luminance::backend::buffer::{Buffer as BufferBackend, BufferSlice as BufferSliceBackend};
-use luminance::buffer::BufferError;
+use luminance::buffer::{BufferError, BufferUsage};
 
 /// Wrapped OpenGL buffer.
 ///
@@ -45,7 +45,12 @@ impl<T> Buffer<T> {
   /// Build a buffer with a number of elements for a given type.
   ///
   /// That function is required to implement repeat without Default.
-  fn new(gl33: &mut GL33, len: usize, clear_value: T) -> Result<Self, BufferError>
+  fn new(
+    gl33: &mut GL33,
+    len: usize,
+    clear_value: T,
+    usage: BufferUsage,
+  ) -> Result<Self, BufferError>
   where
     T: Copy,
   {
@@ -67,7 +72,7 @@ impl<T> Buffer<T> {
         gl::ARRAY_BUFFER,
         bytes as isize,
         buf.as_ptr() as _,
-        gl::STREAM_DRAW,
+        opengl_buffer_usage(usage),
       );
     }
     let state = gl33.state.clone();
@@ -80,6 +85,26 @@ impl<T> Buffer<T> {
     self.gl_buf.handle
   }
 
+  /// Fetch the content of the GPU buffer and refresh the cached version with it.
+  ///
+  /// This is needed when the GPU writes to the buffer (e.g. atomic counters), as the cached version
+  /// is only updated by CPU writes otherwise.
+  pub(crate) unsafe fn read_back(&mut self) {
+    self
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(self.handle(), Bind::Cached);
+
+    let bytes = mem::size_of::<T>() * self.buf.len();
+    gl::GetBufferSubData(
+      gl::ARRAY_BUFFER,
+      0,
+      bytes as isize,
+      self.buf.as_mut_ptr() as _,
+    );
+  }
+
   pub(crate) fn into_raw(self) -> Buffer<u8> {
     let boxed = self.buf.into_boxed_slice();
     let len = boxed.len();
@@ -99,18 +124,26 @@ where
 {
   type BufferRepr = Buffer<T>;
 
-  unsafe fn new_buffer(&mut self, len: usize) -> Result<Self::BufferRepr, BufferError>
+  unsafe fn new_buffer(
+    &mut self,
+    len: usize,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>
   where
     T: Default,
   {
-    Buffer::new(self, len, T::default())
+    Buffer::new(self, len, T::default(), usage)
   }
 
   unsafe fn len(buffer: &Self::BufferRepr) -> usize {
     buffer.buf.len()
   }
 
-  unsafe fn from_vec(&mut self, vec: Vec<T>) -> Result<Self::BufferRepr, BufferError> {
+  unsafe fn from_vec(
+    &mut self,
+    vec: Vec<T>,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError> {
     let mut handle: GLuint = 0;
 
     gl::GenBuffers(1, &mut handle);
@@ -125,7 +158,7 @@ where
       gl::ARRAY_BUFFER,
       bytes as isize,
       vec.as_ptr() as _,
-      gl::STREAM_DRAW,
+      opengl_buffer_usage(usage),
     );
     let state = self.state.clone();
     let gl_buf = BufferWrapper { handle, state };
@@ -133,8 +166,13 @@ where
     Ok(Buffer { gl_buf, buf: vec })
   }
 
-  unsafe fn repeat(&mut self, len: usize, value: T) -> Result<Self::BufferRepr, BufferError> {
-    Buffer::new(self, len, value)
+  unsafe fn repeat(
+    &mut self,
+    len: usize,
+    value: T,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError> {
+    Buffer::new(self, len, value, usage)
   }
 
   unsafe fn at(buffer: &Self::BufferRepr, i: usize) -> Option<T> {
@@ -195,9 +233,16 @@ where
     Ok(())
   }
 
-  unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError> {
-    for item in &mut buffer.buf {
-      *item = x;
+  unsafe fn write_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    values: &[T],
+  ) -> Result<(), BufferError> {
+    let provided_len = values.len();
+    let buffer_len = buffer.buf.len();
+
+    if offset > buffer_len || provided_len > buffer_len - offset {
+      return Err(BufferError::out_of_bounds(offset, provided_len, buffer_len));
     }
 
     buffer
@@ -206,14 +251,92 @@ where
       .borrow_mut()
       .bind_array_buffer(buffer.handle(), Bind::Cached);
 
-    let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::WRITE_ONLY);
-    ptr::copy_nonoverlapping(buffer.buf.as_ptr(), ptr as *mut T, buffer.buf.len());
-    let _ = gl::UnmapBuffer(gl::ARRAY_BUFFER);
+    gl::BufferSubData(
+      gl::ARRAY_BUFFER,
+      (offset * mem::size_of::<T>()) as GLintptr,
+      mem::size_of_val(values) as GLsizeiptr,
+      values.as_ptr() as *const _,
+    );
+
+    buffer.buf[offset..offset + provided_len].copy_from_slice(values);
+
+    Ok(())
+  }
+
+  unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError> {
+    let len = buffer.buf.len();
+    <Self as BufferBackend<T>>::clear_part(buffer, 0, len, x)
+  }
+
+  unsafe fn clear_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    len: usize,
+    x: T,
+  ) -> Result<(), BufferError> {
+    let buffer_len = buffer.buf.len();
+
+    if offset > buffer_len || len > buffer_len - offset {
+      return Err(BufferError::out_of_bounds(offset, len, buffer_len));
+    }
+
+    let region = &mut buffer.buf[offset..offset + len];
+    for item in region.iter_mut() {
+      *item = x;
+    }
+
+    buffer
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(buffer.gl_buf.handle, Bind::Cached);
+
+    let byte_offset = (offset * mem::size_of::<T>()) as GLintptr;
+    let byte_len = mem::size_of_val(region) as GLsizeiptr;
+
+    match clear_format::<T>() {
+      // fill the buffer on the GPU side, by interpreting the value as a single (unsigned integer)
+      // texel, so that no data is uploaded
+      Some((iformat, format, ty)) if gl::ClearBufferSubData::is_loaded() => {
+        gl::ClearBufferSubData(
+          gl::ARRAY_BUFFER,
+          iformat,
+          byte_offset,
+          byte_len,
+          format,
+          ty,
+          &x as *const T as *const _,
+        );
+      }
+
+      _ => {
+        gl::BufferSubData(
+          gl::ARRAY_BUFFER,
+          byte_offset,
+          byte_len,
+          region.as_ptr() as *const _,
+        );
+      }
+    }
 
     Ok(())
   }
 }
 
+/// Unsigned integer texel format (internal format, format and type) with the same size as `T`,
+/// used to clear buffers of `T` with `glClearBufferSubData`.
+fn clear_format<T>() -> Option<(GLenum, GLenum, GLenum)> {
+  match mem::size_of::<T>() {
+    1 => Some((gl::R8UI, gl::RED_INTEGER, gl::UNSIGNED_BYTE)),
+    2 => Some((gl::R16UI, gl::RED_INTEGER, gl::UNSIGNED_SHORT)),
+    4 => Some((gl::R32UI, gl::RED_INTEGER, gl::UNSIGNED_INT)),
+    8 => Some((gl::RG32UI, gl::RG_INTEGER, gl::UNSIGNED_INT)),
+    12 => Some((gl::RGB32UI, gl::RGB_INTEGER, gl::UNSIGNED_INT)),
+    16 => Some((gl::RGBA32UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT)),
+    _ => None,
+  }
+}
+
 /// Wrapper to drop buffer slices.
 struct BufferSliceWrapper {
   handle: GLuint,
@@ -361,3 +484,17 @@ where
     Ok(slice::from_raw_parts_mut(slice.ptr, slice.len))
   }
 }
+
+fn opengl_buffer_usage(usage: BufferUsage) -> GLenum {
+  match usage {
+    BufferUsage::StaticDraw => gl::STATIC_DRAW,
+    BufferUsage::StaticRead => gl::STATIC_READ,
+    BufferUsage::StaticCopy => gl::STATIC_COPY,
+    BufferUsage::DynamicDraw => gl::DYNAMIC_DRAW,
+    BufferUsage::DynamicRead => gl::DYNAMIC_READ,
+    BufferUsage::DynamicCopy => gl::DYNAMIC_COPY,
+    BufferUsage::StreamDraw => gl::STREAM_DRAW,
+    BufferUsage::StreamRead => gl::STREAM_READ,
+    BufferUsage::StreamCopy => gl::STREAM_COPY,
+  }
+}
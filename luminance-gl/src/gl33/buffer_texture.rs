@@ -0,0 +1,83 @@
+use gl::types::*;
+use luminance::backend::buffer_texture::BufferTexture as BufferTextureBackend;
+use luminance::pixel::Pixel;
+use luminance::texture::TextureError;
+
+use crate::gl33::pixel::opengl_pixel_format;
+use crate::gl33::GL33;
+
+pub struct BufferTexture {
+  pub(crate) handle: GLuint,
+}
+
+impl Drop for BufferTexture {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteTextures(1, &self.handle);
+    }
+  }
+}
+
+unsafe impl<P> BufferTextureBackend<P> for GL33
+where
+  P: Pixel,
+{
+  type BufferTextureRepr = BufferTexture;
+
+  unsafe fn new_buffer_texture(
+    &mut self,
+    buffer: &Self::BufferRepr,
+  ) -> Result<Self::BufferTextureRepr, TextureError> {
+    let pf = P::pixel_format();
+    let iformat = opengl_pixel_format(pf)
+      .map(|(_, iformat, _)| iformat)
+      .filter(|&iformat| is_buffer_texture_format(iformat))
+      .ok_or_else(|| TextureError::unsupported_pixel_format(pf))?;
+
+    let mut state = self.state.borrow_mut();
+
+    let handle = state.create_texture();
+    state.bind_texture(gl::TEXTURE_BUFFER, handle);
+
+    gl::TexBuffer(gl::TEXTURE_BUFFER, iformat, buffer.handle());
+
+    Ok(BufferTexture { handle })
+  }
+}
+
+/// Internal formats that can be used with buffer textures in OpenGL 3.3.
+fn is_buffer_texture_format(iformat: GLenum) -> bool {
+  matches!(
+    iformat,
+    gl::R8
+      | gl::R16
+      | gl::R16F
+      | gl::R32F
+      | gl::R8I
+      | gl::R16I
+      | gl::R32I
+      | gl::R8UI
+      | gl::R16UI
+      | gl::R32UI
+      | gl::RG8
+      | gl::RG16
+      | gl::RG16F
+      | gl::RG32F
+      | gl::RG8I
+      | gl::RG16I
+      | gl::RG32I
+      | gl::RG8UI
+      | gl::RG16UI
+      | gl::RG32UI
+      | gl::RGBA8
+      | gl::RGBA16
+      | gl::RGBA16F
+      | gl::RGBA32F
+      | gl::RGBA8I
+      | gl::RGBA16I
+      | gl::RGBA32I
+      | gl::RGBA8UI
+      | gl::RGBA16UI
+      | gl::RGBA32UI
+  )
+}
@@ -0,0 +1,35 @@
+use gl::types::*;
+use luminance::backend::capabilities::Capabilities as CapabilitiesBackend;
+use luminance::capabilities::{Capabilities, FormatUsage};
+use luminance::pixel::PixelFormat;
+
+use crate::gl33::pixel::opengl_pixel_format;
+use crate::gl33::GL33;
+
+unsafe impl CapabilitiesBackend for GL33 {
+  unsafe fn capabilities(&mut self) -> Capabilities {
+    self.state.borrow().capabilities()
+  }
+
+  unsafe fn supports_pixel_format(&mut self, pf: PixelFormat, usage: FormatUsage) -> bool {
+    let iformat = match opengl_pixel_format(pf) {
+      Some((_, iformat, _)) => iformat,
+      None => return false,
+    };
+
+    // querying format support requires OpenGL 4.3 or GL_ARB_internalformat_query2
+    if !self.state.borrow().has_internalformat_query() {
+      return true;
+    }
+
+    let (pname, expected) = match usage {
+      FormatUsage::Sampled => (gl::INTERNALFORMAT_SUPPORTED, gl::TRUE as GLint),
+      FormatUsage::Rendered => (gl::FRAMEBUFFER_RENDERABLE, gl::FULL_SUPPORT as GLint),
+    };
+
+    let mut value = 0;
+    gl::GetInternalformativ(gl::TEXTURE_2D, iformat, pname, 1, &mut value);
+
+    value == expected
+  }
+}
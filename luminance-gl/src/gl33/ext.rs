@@ -0,0 +1,68 @@
+//! OpenGL extension functions and constants.
+//!
+//! The `gl` crate only has bindings for the core profile: the extension functions used by the
+//! backend are loaded along the core ones by [`load_with`](crate::gl33::load_with).
+
+use gl::types::*;
+use std::mem;
+use std::os::raw::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+// GL_KHR_blend_equation_advanced
+pub(crate) const MULTIPLY_KHR: GLenum = 0x9294;
+pub(crate) const SCREEN_KHR: GLenum = 0x9295;
+pub(crate) const OVERLAY_KHR: GLenum = 0x9296;
+pub(crate) const DARKEN_KHR: GLenum = 0x9297;
+pub(crate) const LIGHTEN_KHR: GLenum = 0x9298;
+pub(crate) const COLORDODGE_KHR: GLenum = 0x9299;
+pub(crate) const COLORBURN_KHR: GLenum = 0x929A;
+pub(crate) const HARDLIGHT_KHR: GLenum = 0x929B;
+pub(crate) const SOFTLIGHT_KHR: GLenum = 0x929C;
+pub(crate) const DIFFERENCE_KHR: GLenum = 0x929E;
+pub(crate) const EXCLUSION_KHR: GLenum = 0x92A0;
+pub(crate) const HSL_HUE_KHR: GLenum = 0x92AD;
+pub(crate) const HSL_SATURATION_KHR: GLenum = 0x92AE;
+pub(crate) const HSL_COLOR_KHR: GLenum = 0x92AF;
+pub(crate) const HSL_LUMINOSITY_KHR: GLenum = 0x92B0;
+
+// GL_EXT_depth_bounds_test
+pub(crate) const DEPTH_BOUNDS_TEST_EXT: GLenum = 0x8890;
+
+static BLEND_BARRIER_KHR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+static DEPTH_BOUNDS_EXT: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// Load the extension functions with the loader used for the core ones.
+pub(crate) fn load_with<F>(loader: &mut F)
+where
+  F: FnMut(&'static str) -> *const c_void,
+{
+  BLEND_BARRIER_KHR.store(
+    loader("glBlendBarrierKHR") as *mut c_void,
+    Ordering::Relaxed,
+  );
+  DEPTH_BOUNDS_EXT.store(loader("glDepthBoundsEXT") as *mut c_void, Ordering::Relaxed);
+}
+
+/// Whether `glBlendBarrierKHR` was loaded.
+pub(crate) fn blend_barrier_is_loaded() -> bool {
+  !BLEND_BARRIER_KHR.load(Ordering::Relaxed).is_null()
+}
+
+/// `glBlendBarrierKHR`; must be loaded.
+pub(crate) unsafe fn blend_barrier() {
+  let f: extern "system" fn() = mem::transmute(BLEND_BARRIER_KHR.load(Ordering::Relaxed));
+  f()
+}
+
+/// Whether `glDepthBoundsEXT` was loaded.
+pub(crate) fn depth_bounds_is_loaded() -> bool {
+  !DEPTH_BOUNDS_EXT.load(Ordering::Relaxed).is_null()
+}
+
+/// `glDepthBoundsEXT`; must be loaded.
+pub(crate) unsafe fn depth_bounds(min: GLdouble, max: GLdouble) {
+  let f: extern "system" fn(GLdouble, GLdouble) =
+    mem::transmute(DEPTH_BOUNDS_EXT.load(Ordering::Relaxed));
+  f(min, max)
+}
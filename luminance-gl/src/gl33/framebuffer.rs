@@ -3,12 +3,15 @@ use gl::types::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::gl33::pipeline::clear_color_buffer;
 use crate::gl33::state::{Bind, GLState};
 use crate::gl33::GL33;
 use luminance::backend::color_slot::ColorSlot;
 use luminance::backend::depth_slot::DepthSlot;
 use luminance::backend::framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer};
-use luminance::framebuffer::{FramebufferError, IncompleteReason};
+use luminance::framebuffer::{Attachment, FramebufferError, IncompleteReason};
+use luminance::pipeline::ClearColor;
+use luminance::pixel::PixelFormat;
 use luminance::texture::{Dim2, Dimensionable, Sampler};
 
 pub struct Framebuffer<D>
@@ -17,8 +20,10 @@ where
 {
   pub(crate) handle: GLuint,
   renderbuffer: Option<GLuint>,
+  // pixel formats of the color attachments, used to select the right clear command
+  pub(crate) color_formats: Vec<PixelFormat>,
   pub(crate) size: D::Size,
-  state: Rc<RefCell<GLState>>,
+  pub(crate) state: Rc<RefCell<GLState>>,
 }
 
 impl<D> Drop for Framebuffer<D>
@@ -75,7 +80,10 @@ where
 
     // color textures
     if color_formats.is_empty() {
+      // depth-only framebuffers must not reference any color buffer, neither for drawing nor for
+      // reading, or they are considered incomplete by some drivers
       gl::DrawBuffer(gl::NONE);
+      gl::ReadBuffer(gl::NONE);
     } else {
       // specify the list of color buffers to draw to
       let color_buf_nb = color_formats.len() as GLsizei;
@@ -112,6 +120,7 @@ where
     let framebuffer = Framebuffer {
       handle,
       renderbuffer: depth_renderbuffer,
+      color_formats,
       size,
       state: self.state.clone(),
     };
@@ -120,10 +129,15 @@ where
   }
 
   unsafe fn attach_color_texture(
-    _: &mut Self::FramebufferRepr,
+    framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
     attachment_index: usize,
   ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
     gl::FramebufferTexture(
       gl::FRAMEBUFFER,
       gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
@@ -135,9 +149,14 @@ where
   }
 
   unsafe fn attach_depth_texture(
-    _: &mut Self::FramebufferRepr,
+    framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
   ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
     gl::FramebufferTexture(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, texture.handle, 0);
 
     Ok(())
@@ -154,6 +173,89 @@ where
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
     framebuffer.size
   }
+
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError> {
+    framebuffer.size = size;
+
+    // the back buffer is resized by the windowing system
+    if framebuffer.handle == 0 {
+      return Ok(());
+    }
+
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    // reallocate the depth renderbuffer we created if no depth slot was provided
+    if let Some(renderbuffer) = framebuffer.renderbuffer {
+      gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+      gl::RenderbufferStorage(
+        gl::RENDERBUFFER,
+        gl::DEPTH_COMPONENT32F,
+        D::width(size) as GLsizei,
+        D::height(size) as GLsizei,
+      );
+      gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+    }
+
+    get_framebuffer_status().map_err(FramebufferError::from)
+  }
+
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  ) {
+    // glInvalidateFramebuffer is only available from OpenGL 4.3 on; it’s only a hint anyway
+    if !gl::InvalidateFramebuffer::is_loaded() || attachments.is_empty() {
+      return;
+    }
+
+    // the back buffer uses different names for its attachments
+    let is_back_buffer = framebuffer.handle == 0;
+    let attachments: Vec<_> = attachments
+      .iter()
+      .map(|attachment| match (*attachment, is_back_buffer) {
+        (Attachment::Color(_), true) => gl::COLOR,
+        (Attachment::Color(index), false) => gl::COLOR_ATTACHMENT0 + index as GLenum,
+        (Attachment::Depth, true) => gl::DEPTH,
+        (Attachment::Depth, false) => gl::DEPTH_ATTACHMENT,
+        (Attachment::Stencil, true) => gl::STENCIL,
+        (Attachment::Stencil, false) => gl::STENCIL_ATTACHMENT,
+      })
+      .collect();
+
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    gl::InvalidateFramebuffer(
+      gl::DRAW_FRAMEBUFFER,
+      attachments.len() as GLsizei,
+      attachments.as_ptr(),
+    );
+  }
+
+  unsafe fn clear_color_attachment(
+    framebuffer: &mut Self::FramebufferRepr,
+    index: usize,
+    clear_color: ClearColor,
+  ) {
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle);
+
+    state.prepare_clear();
+
+    clear_color_buffer(
+      index as GLint,
+      &framebuffer.color_formats[index],
+      clear_color,
+    );
+  }
 }
 
 fn get_framebuffer_status() -> Result<(), IncompleteReason> {
@@ -184,6 +286,7 @@ unsafe impl FramebufferBackBuffer for GL33 {
     Ok(Framebuffer {
       handle: 0,
       renderbuffer: None,
+      color_formats: Vec::new(),
       size,
       state: self.state.clone(),
     })
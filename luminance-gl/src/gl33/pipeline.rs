@@ -1,29 +1,44 @@
 use gl::types::*;
 
 use luminance::backend::pipeline::{
-  Pipeline as PipelineBackend, PipelineBase, PipelineBuffer, PipelineTexture,
+  Pipeline as PipelineBackend, PipelineAtomicCounterBuffer, PipelineBase, PipelineBuffer,
+  PipelineBufferTexture, PipelineTexture,
 };
 use luminance::backend::render_gate::RenderGate;
 use luminance::backend::shading_gate::ShadingGate;
 use luminance::backend::tess::Tess;
 use luminance::backend::tess_gate::TessGate;
 use luminance::blending::BlendingMode;
-use luminance::pipeline::{PipelineError, PipelineState, Viewport};
-use luminance::pixel::Pixel;
+use luminance::buffer::BufferError;
+use luminance::pipeline::{
+  ClearColor, PipelineError, PipelineState, Viewport, FRAME_UNIFORM_BUFFER_BINDING,
+};
+use luminance::pixel::{Pixel, PixelFormat, Type as PixelType};
 use luminance::render_state::RenderState;
-use luminance::tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData};
+use luminance::tess::{
+  Deinterleaved, DeinterleavedData, Interleaved, TessError, TessIndex, TessVertexData,
+};
 use luminance::texture::Dimensionable;
 use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::rc::Rc;
 
-use crate::gl33::state::{BlendingState, DepthTest, FaceCullingState, GLState};
+use crate::gl33::state::{
+  BlendingState, DepthTest, FaceCullingState, GLState, ScissorState, StencilTestState,
+};
 use crate::gl33::GL33;
 
 pub struct Pipeline {
   state: Rc<RefCell<GLState>>,
 }
 
+impl Drop for Pipeline {
+  fn drop(&mut self) {
+    // don’t leave stale bindings behind the pipeline
+    unsafe { self.state.borrow_mut().unbind_pipeline_resources() };
+  }
+}
+
 pub struct BoundBuffer {
   pub(crate) binding: u32,
   state: Rc<RefCell<GLState>>,
@@ -31,6 +46,11 @@ pub struct BoundBuffer {
 
 impl Drop for BoundBuffer {
   fn drop(&mut self) {
+    // the frame uniform buffer binding is reserved and never goes into the free list
+    if self.binding == FRAME_UNIFORM_BUFFER_BINDING {
+      return;
+    }
+
     // place the binding into the free list
     let mut state = self.state.borrow_mut();
     state
@@ -62,6 +82,19 @@ where
   }
 }
 
+pub struct BoundBufferTexture {
+  pub(crate) unit: u32,
+  state: Rc<RefCell<GLState>>,
+}
+
+impl Drop for BoundBufferTexture {
+  fn drop(&mut self) {
+    // place the binding into the free list
+    let mut state = self.state.borrow_mut();
+    state.binding_stack_mut().free_texture_units.push(self.unit);
+  }
+}
+
 unsafe impl PipelineBase for GL33 {
   type PipelineRepr = Pipeline;
 
@@ -72,6 +105,14 @@ unsafe impl PipelineBase for GL33 {
 
     Ok(pipeline)
   }
+
+  unsafe fn finish(&mut self) {
+    gl::Finish();
+  }
+
+  unsafe fn flush(&mut self) {
+    gl::Flush();
+  }
 }
 
 unsafe impl<D> PipelineBackend<D> for GL33
@@ -105,29 +146,70 @@ where
       }
     }
 
-    state.set_clear_color([
-      clear_color[0] as _,
-      clear_color[1] as _,
-      clear_color[2] as _,
-      clear_color[3] as _,
-    ]);
-
-    if pipeline_state.clear_color_enabled || pipeline_state.clear_depth_enabled {
-      let color_bit = if pipeline_state.clear_color_enabled {
-        gl::COLOR_BUFFER_BIT
-      } else {
-        0
-      };
-
-      let depth_bit = if pipeline_state.clear_depth_enabled {
-        gl::DEPTH_BUFFER_BIT
-      } else {
-        0
-      };
-      gl::Clear(color_bit | depth_bit);
+    // sRGB encoding applies to clears, so it must be set before clearing
+    state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
+
+    if pipeline_state.clear_color_enabled
+      || pipeline_state.clear_depth_enabled
+      || pipeline_state.clear_stencil_enabled
+    {
+      state.prepare_clear();
     }
 
-    state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
+    // every buffer is cleared with the matching glClearBuffer* command, for both custom
+    // framebuffers and the back buffer, so that only the buffers the pipeline asked for get cleared
+    if pipeline_state.clear_color_enabled {
+      clear_color_buffers(&framebuffer.color_formats, clear_color);
+    }
+
+    let clear_depth = pipeline_state.clear_depth as GLfloat;
+    let clear_stencil = pipeline_state.clear_stencil as GLint;
+
+    match (
+      pipeline_state.clear_depth_enabled,
+      pipeline_state.clear_stencil_enabled,
+    ) {
+      (true, true) => gl::ClearBufferfi(gl::DEPTH_STENCIL, 0, clear_depth, clear_stencil),
+      (true, false) => gl::ClearBufferfv(gl::DEPTH, 0, &clear_depth),
+      (false, true) => gl::ClearBufferiv(gl::STENCIL, 0, &clear_stencil),
+      (false, false) => (),
+    }
+  }
+}
+
+/// Clear every color attachment with the `glClearBuffer*` variant matching its pixel encoding.
+///
+/// The back buffer doesn’t have any color format and its only draw buffer is cleared as a
+/// floating-point buffer.
+unsafe fn clear_color_buffers(color_formats: &[PixelFormat], clear_color: ClearColor) {
+  if color_formats.is_empty() {
+    gl::ClearBufferfv(gl::COLOR, 0, clear_color.as_float().as_ptr());
+    return;
+  }
+
+  for (i, format) in color_formats.iter().enumerate() {
+    clear_color_buffer(i as GLint, format, clear_color);
+  }
+}
+
+/// Clear a single color attachment with the `glClearBuffer*` variant matching its pixel encoding.
+pub(crate) unsafe fn clear_color_buffer(
+  draw_buffer: GLint,
+  format: &PixelFormat,
+  clear_color: ClearColor,
+) {
+  match format.encoding {
+    PixelType::Integral => {
+      gl::ClearBufferiv(gl::COLOR, draw_buffer, clear_color.as_int().as_ptr());
+    }
+
+    PixelType::Unsigned => {
+      gl::ClearBufferuiv(gl::COLOR, draw_buffer, clear_color.as_uint().as_ptr());
+    }
+
+    _ => {
+      gl::ClearBufferfv(gl::COLOR, draw_buffer, clear_color.as_float().as_ptr());
+    }
   }
 }
 
@@ -159,11 +241,50 @@ where
     })
   }
 
+  unsafe fn bind_frame_uniform_buffer(
+    pipeline: &Self::PipelineRepr,
+    buffer: &Self::BufferRepr,
+  ) -> Result<Self::BoundBufferRepr, PipelineError> {
+    pipeline
+      .state
+      .borrow_mut()
+      .bind_buffer_base(buffer.handle(), FRAME_UNIFORM_BUFFER_BINDING);
+
+    Ok(BoundBuffer {
+      binding: FRAME_UNIFORM_BUFFER_BINDING,
+      state: pipeline.state.clone(),
+    })
+  }
+
   unsafe fn buffer_binding(bound: &Self::BoundBufferRepr) -> u32 {
     bound.binding
   }
 }
 
+unsafe impl PipelineAtomicCounterBuffer for GL33 {
+  unsafe fn bind_atomic_counter_buffer(
+    pipeline: &Self::PipelineRepr,
+    buffer: &Self::BufferRepr,
+    binding: u32,
+  ) -> Result<(), PipelineError> {
+    pipeline
+      .state
+      .borrow_mut()
+      .bind_atomic_counter_buffer_base(buffer.handle(), binding);
+    Ok(())
+  }
+
+  unsafe fn atomic_counters(buffer: &mut Self::BufferRepr) -> Result<Vec<u32>, BufferError> {
+    // make sure shader writes to the counters are visible before reading them back
+    if gl::MemoryBarrier::is_loaded() {
+      gl::MemoryBarrier(gl::BUFFER_UPDATE_BARRIER_BIT);
+    }
+
+    buffer.read_back();
+    Ok(buffer.buf.clone())
+  }
+}
+
 unsafe impl<D, P> PipelineTexture<D, P> for GL33
 where
   D: Dimensionable,
@@ -203,6 +324,39 @@ where
   }
 }
 
+unsafe impl<P> PipelineBufferTexture<P> for GL33
+where
+  P: Pixel,
+{
+  type BoundBufferTextureRepr = BoundBufferTexture;
+
+  unsafe fn bind_buffer_texture(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::BufferTextureRepr,
+  ) -> Result<Self::BoundBufferTextureRepr, PipelineError> {
+    let mut state = pipeline.state.borrow_mut();
+    let bstack = state.binding_stack_mut();
+
+    let unit = bstack.free_texture_units.pop().unwrap_or_else(|| {
+      // no more free units; reserve one
+      let unit = bstack.next_texture_unit;
+      bstack.next_texture_unit += 1;
+      unit
+    });
+
+    state.bind_texture_at(gl::TEXTURE_BUFFER, texture.handle, unit);
+
+    Ok(BoundBufferTexture {
+      unit,
+      state: pipeline.state.clone(),
+    })
+  }
+
+  unsafe fn buffer_texture_binding(bound: &Self::BoundBufferTextureRepr) -> u32 {
+    bound.unit
+  }
+}
+
 unsafe impl<V, I, W> TessGate<V, I, W, Interleaved> for GL33
 where
   V: TessVertexData<Interleaved, Data = Vec<V>>,
@@ -215,8 +369,9 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+  ) -> Result<(), TessError> {
+    self.state.borrow().blend_barrier();
+    <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb)
   }
 }
 
@@ -232,8 +387,9 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+  ) -> Result<(), TessError> {
+    self.state.borrow().blend_barrier();
+    <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb)
   }
 }
 
@@ -261,6 +417,8 @@ unsafe impl RenderGate for GL33 {
       }
     }
 
+    gfx_state.set_color_mask(rdr_st.color_mask());
+
     // depth-related state
     if let Some(depth_comparison) = rdr_st.depth_test() {
       gfx_state.set_depth_test(DepthTest::On);
@@ -271,6 +429,23 @@ unsafe impl RenderGate for GL33 {
 
     gfx_state.set_depth_write(rdr_st.depth_write());
 
+    // stencil state
+    match rdr_st.stencil_test() {
+      Some(stencil_test) => {
+        gfx_state.set_stencil_test(StencilTestState::On);
+        gfx_state.set_stencil_func(
+          stencil_test.comparison,
+          stencil_test.reference,
+          stencil_test.mask,
+        );
+        gfx_state.set_stencil_operations(stencil_test.operations);
+        gfx_state.set_stencil_write_mask(stencil_test.write_mask);
+      }
+      None => {
+        gfx_state.set_stencil_test(StencilTestState::Off);
+      }
+    }
+
     // face-culling state
     match rdr_st.face_culling() {
       Some(face_culling) => {
@@ -282,11 +457,55 @@ unsafe impl RenderGate for GL33 {
         gfx_state.set_face_culling_state(FaceCullingState::Off);
       }
     }
+
+    gfx_state.set_clip_distances(rdr_st.clip_distances());
+    gfx_state.set_provoking_vertex(rdr_st.provoking_vertex());
+    gfx_state.set_min_sample_shading(rdr_st.min_sample_shading());
+    gfx_state.set_rasterizer_discard(rdr_st.rasterizer_discard());
+
+    // scissor state
+    match rdr_st.scissor() {
+      Some(region) => {
+        gfx_state.set_scissor_state(ScissorState::On);
+        gfx_state.set_scissor_region(&region);
+      }
+      None => {
+        gfx_state.set_scissor_state(ScissorState::Off);
+      }
+    }
+
+    gfx_state.set_polygon_offset(rdr_st.polygon_offset());
+    gfx_state.set_depth_bounds(rdr_st.depth_bounds());
+  }
+
+  unsafe fn push_debug_group(&mut self, label: &str) {
+    // debug groups are OpenGL 4.3; ignore them if not available
+    if !gl::PushDebugGroup::is_loaded() {
+      return;
+    }
+
+    gl::PushDebugGroup(
+      gl::DEBUG_SOURCE_APPLICATION,
+      0,
+      label.len() as GLsizei,
+      label.as_ptr() as *const GLchar,
+    );
+  }
+
+  unsafe fn pop_debug_group(&mut self) {
+    if !gl::PopDebugGroup::is_loaded() {
+      return;
+    }
+
+    gl::PopDebugGroup();
   }
 }
 
 unsafe impl ShadingGate for GL33 {
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr) {
-    self.state.borrow_mut().use_program(shader_program.handle);
+    self
+      .state
+      .borrow_mut()
+      .use_program(shader_program.handle, shader_program.tessellated);
   }
 }
@@ -32,6 +32,7 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::R(Size::ThirtyTwo), Type::Unsigned) => {
       Some((gl::RED_INTEGER, gl::R32UI, gl::UNSIGNED_INT))
     }
+    (Format::R(Size::Sixteen), Type::Floating) => Some((gl::RED, gl::R16F, gl::FLOAT)),
     (Format::R(Size::ThirtyTwo), Type::Floating) => Some((gl::RED, gl::R32F, gl::FLOAT)),
 
     // red, blue channels
@@ -73,6 +74,9 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::Unsigned) => {
       Some((gl::RG_INTEGER, gl::RG32UI, gl::UNSIGNED_INT))
     }
+    (Format::RG(Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RG, gl::RG16F, gl::FLOAT))
+    }
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::Floating) => {
       Some((gl::RG, gl::RG32F, gl::FLOAT))
     }
@@ -120,6 +124,9 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::RGB(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo), Type::Unsigned) => {
       Some((gl::RGB_INTEGER, gl::RGB32UI, gl::UNSIGNED_INT))
     }
+    (Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RGB, gl::RGB16F, gl::FLOAT))
+    }
     (Format::RGB(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo), Type::Floating) => {
       Some((gl::RGB, gl::RGB32F, gl::FLOAT))
     }
@@ -169,6 +176,9 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
       Format::RGBA(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo),
       Type::Unsigned,
     ) => Some((gl::RGBA_INTEGER, gl::RGBA32UI, gl::UNSIGNED_INT)),
+    (Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RGBA, gl::RGBA16F, gl::FLOAT))
+    }
     (
       Format::RGBA(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo),
       Type::Floating,
@@ -192,6 +202,10 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
       Some((gl::DEPTH_COMPONENT, gl::DEPTH_COMPONENT32F, gl::FLOAT))
     }
 
+    (Format::Stencil(Size::Eight), Type::Unsigned) => {
+      Some((gl::STENCIL_INDEX, gl::STENCIL_INDEX8, gl::UNSIGNED_BYTE))
+    }
+
     _ => None,
   }
 }
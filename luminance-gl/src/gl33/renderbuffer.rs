@@ -0,0 +1,107 @@
+use gl::types::*;
+
+use crate::gl33::pixel::opengl_pixel_format;
+use crate::gl33::GL33;
+use luminance::backend::renderbuffer::Renderbuffer as RenderbufferBackend;
+use luminance::framebuffer::FramebufferError;
+use luminance::pixel::Pixel;
+use luminance::texture::{Dim2, Dimensionable, TextureError};
+
+pub struct Renderbuffer {
+  pub(crate) handle: GLuint,
+  iformat: GLenum,
+}
+
+impl Drop for Renderbuffer {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteRenderbuffers(1, &self.handle);
+    }
+  }
+}
+
+unsafe impl<P> RenderbufferBackend<P> for GL33
+where
+  P: Pixel,
+{
+  type RenderbufferRepr = Renderbuffer;
+
+  unsafe fn new_renderbuffer(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<Self::RenderbufferRepr, FramebufferError> {
+    let pf = P::pixel_format();
+    let (_, iformat, _) =
+      opengl_pixel_format(pf).ok_or_else(|| TextureError::unsupported_pixel_format(pf))?;
+
+    let mut handle: GLuint = 0;
+    gl::GenRenderbuffers(1, &mut handle);
+
+    let mut renderbuffer = Renderbuffer { handle, iformat };
+    <Self as RenderbufferBackend<P>>::resize_renderbuffer(&mut renderbuffer, size)?;
+
+    Ok(renderbuffer)
+  }
+
+  unsafe fn resize_renderbuffer(
+    renderbuffer: &mut Self::RenderbufferRepr,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<(), FramebufferError> {
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer.handle);
+    gl::RenderbufferStorage(
+      gl::RENDERBUFFER,
+      renderbuffer.iformat,
+      size[0] as GLsizei,
+      size[1] as GLsizei,
+    );
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+
+    Ok(())
+  }
+
+  unsafe fn attach_color_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    gl::FramebufferRenderbuffer(
+      gl::FRAMEBUFFER,
+      gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
+      gl::RENDERBUFFER,
+      renderbuffer.handle,
+    );
+
+    Ok(())
+  }
+
+  unsafe fn attach_depth_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+  ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    // stencil-only renderbuffers are attached to the stencil attachment point
+    let attachment = if P::pixel_format().is_stencil_pixel() {
+      gl::STENCIL_ATTACHMENT
+    } else {
+      gl::DEPTH_ATTACHMENT
+    };
+
+    gl::FramebufferRenderbuffer(
+      gl::FRAMEBUFFER,
+      attachment,
+      gl::RENDERBUFFER,
+      renderbuffer.handle,
+    );
+
+    Ok(())
+  }
+}
@@ -5,11 +5,11 @@ use std::ptr::{null, null_mut};
 
 use crate::gl33::GL33;
 use luminance::backend::shader::{Shader, Uniformable};
-use luminance::pipeline::{BufferBinding, TextureBinding};
+use luminance::pipeline::{BufferBinding, BufferTextureBinding, TextureBinding};
 use luminance::pixel::{SamplerType, Type as PixelType};
 use luminance::shader::{
-  ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType, UniformWarning,
-  VertexAttribWarning,
+  ActiveAttribute, ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType,
+  UniformWarning, VertexAttribWarning,
 };
 use luminance::texture::{Dim, Dimensionable};
 use luminance::vertex::Semantics;
@@ -31,6 +31,7 @@ impl Drop for Stage {
 #[derive(Debug)]
 pub struct Program {
   pub(crate) handle: GLuint,
+  pub(crate) tessellated: bool,
 }
 
 impl Drop for Program {
@@ -164,6 +165,7 @@ unsafe impl Shader for GL33 {
     fragment: &Self::StageRepr,
   ) -> Result<Self::ProgramRepr, ProgramError> {
     let handle = gl::CreateProgram();
+    let tessellated = tess.is_some();
 
     if let Some(TessellationStages {
       control,
@@ -182,7 +184,10 @@ unsafe impl Shader for GL33 {
 
     gl::AttachShader(handle, fragment.handle);
 
-    let program = Program { handle };
+    let program = Program {
+      handle,
+      tessellated,
+    };
     program.link().map(move |_| program)
   }
 
@@ -199,6 +204,57 @@ unsafe impl Shader for GL33 {
     Ok(warnings)
   }
 
+  unsafe fn active_attributes(program: &Self::ProgramRepr) -> Vec<ActiveAttribute> {
+    let mut count: GLint = 0;
+    gl::GetProgramiv(program.handle, gl::ACTIVE_ATTRIBUTES, &mut count);
+
+    let mut max_len: GLint = 0;
+    gl::GetProgramiv(
+      program.handle,
+      gl::ACTIVE_ATTRIBUTE_MAX_LENGTH,
+      &mut max_len,
+    );
+
+    let mut attributes = Vec::with_capacity(count as usize);
+    let mut name: Vec<u8> = vec![0; max_len.max(1) as usize];
+
+    for index in 0..count as GLuint {
+      let mut len: GLsizei = 0;
+      let mut size: GLint = 0;
+      let mut glty: GLenum = 0;
+
+      gl::GetActiveAttrib(
+        program.handle,
+        index,
+        name.len() as GLsizei,
+        &mut len,
+        &mut size,
+        &mut glty,
+        name.as_mut_ptr() as *mut GLchar,
+      );
+
+      let name = String::from_utf8_lossy(&name[..len as usize]).into_owned();
+
+      // built-in attributes can’t be bound by vertex buffers
+      if name.starts_with("gl_") {
+        continue;
+      }
+
+      if let (Some(ty), Ok(location)) = (
+        attrib_type_from_glenum(glty),
+        get_vertex_attrib_location(program, &name),
+      ) {
+        attributes.push(ActiveAttribute {
+          name,
+          location: location as usize,
+          ty,
+        });
+      }
+    }
+
+    attributes
+  }
+
   unsafe fn new_uniform_builder(
     program: &mut Self::ProgramRepr,
   ) -> Result<Self::UniformBuilderRepr, ProgramError> {
@@ -212,14 +268,28 @@ unsafe impl Shader for GL33 {
   where
     T: Uniformable<Self>,
   {
-    let uniform = match T::ty() {
+    let uniform: Uniform<T> = match T::ty() {
       UniformType::BufferBinding => uniform_builder.ask_uniform_block(name)?,
       _ => uniform_builder.ask_uniform(name)?,
     };
 
-    uniform_type_match(uniform_builder.handle, name, T::ty())?;
+    let size = uniform_type_match(uniform_builder.handle, name, T::ty())?;
 
-    Ok(uniform)
+    Ok(Uniform::new_array(uniform.index(), size))
+  }
+
+  unsafe fn ask_uniform_at<T>(
+    _: &mut Self::UniformBuilderRepr,
+    location: u32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    T: Uniformable<Self>,
+  {
+    // uniform blocks are bound by index, not by location
+    match T::ty() {
+      UniformType::BufferBinding => Err(UniformWarning::unsupported_explicit_location(location)),
+      _ => Ok(Uniform::new(location as GLint)),
+    }
   }
 
   unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
@@ -249,8 +319,12 @@ fn glsl_pragma_src(src: &str) -> String {
   pragma
 }
 
-fn uniform_type_match(program: GLuint, name: &str, ty: UniformType) -> Result<(), UniformWarning> {
-  let mut size: GLint = 0;
+fn uniform_type_match(
+  program: GLuint,
+  name: &str,
+  ty: UniformType,
+) -> Result<usize, UniformWarning> {
+  let mut size: GLint = 1;
   let mut glty: GLuint = 0;
 
   unsafe {
@@ -282,12 +356,9 @@ fn uniform_type_match(program: GLuint, name: &str, ty: UniformType) -> Result<()
     );
   }
 
-  // early-return if array – we don’t support them yet
-  if size != 1 {
-    return Ok(());
-  }
-
-  check_types_match(name, ty, glty)
+  // arrays report the type of their elements, which is the one of their Uniformable
+  // implementation
+  check_types_match(name, ty, glty).map(|_| size as usize)
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -302,6 +373,17 @@ fn check_types_match(name: &str, ty: UniformType, glty: GLuint) -> Result<(), Un
     }
   }
 
+  // shadow samplers are bound to depth textures with a depth comparison, which have the same type
+  // as their regular counterparts
+  match (ty, glty) {
+    (UniformType::Sampler1D, gl::SAMPLER_1D_SHADOW)
+    | (UniformType::Sampler2D, gl::SAMPLER_2D_SHADOW)
+    | (UniformType::Sampler1DArray, gl::SAMPLER_1D_ARRAY_SHADOW)
+    | (UniformType::Sampler2DArray, gl::SAMPLER_2D_ARRAY_SHADOW)
+    | (UniformType::Cubemap, gl::SAMPLER_CUBE_SHADOW) => return Ok(()),
+    _ => (),
+  }
+
   milkcheck!(
     ty,
     // scalars
@@ -344,7 +426,10 @@ fn check_types_match(name: &str, ty: UniformType, glty: GLuint) -> Result<(), Un
     (Sampler2DArray, SAMPLER_2D_ARRAY),
     (ICubemap, INT_SAMPLER_CUBE),
     (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
-    (Cubemap, SAMPLER_CUBE)
+    (Cubemap, SAMPLER_CUBE),
+    (ISamplerBuffer, INT_SAMPLER_BUFFER),
+    (UISamplerBuffer, UNSIGNED_INT_SAMPLER_BUFFER),
+    (SamplerBuffer, SAMPLER_BUFFER)
   )
 }
 
@@ -372,6 +457,28 @@ where
   warnings
 }
 
+/// Map an OpenGL vertex attribute type to a [`UniformType`], if representable.
+fn attrib_type_from_glenum(glty: GLenum) -> Option<UniformType> {
+  match glty {
+    gl::INT => Some(UniformType::Int),
+    gl::UNSIGNED_INT => Some(UniformType::UInt),
+    gl::FLOAT => Some(UniformType::Float),
+    gl::INT_VEC2 => Some(UniformType::IVec2),
+    gl::INT_VEC3 => Some(UniformType::IVec3),
+    gl::INT_VEC4 => Some(UniformType::IVec4),
+    gl::UNSIGNED_INT_VEC2 => Some(UniformType::UIVec2),
+    gl::UNSIGNED_INT_VEC3 => Some(UniformType::UIVec3),
+    gl::UNSIGNED_INT_VEC4 => Some(UniformType::UIVec4),
+    gl::FLOAT_VEC2 => Some(UniformType::Vec2),
+    gl::FLOAT_VEC3 => Some(UniformType::Vec3),
+    gl::FLOAT_VEC4 => Some(UniformType::Vec4),
+    gl::FLOAT_MAT2 => Some(UniformType::M22),
+    gl::FLOAT_MAT3 => Some(UniformType::M33),
+    gl::FLOAT_MAT4 => Some(UniformType::M44),
+    _ => None,
+  }
+}
+
 fn get_vertex_attrib_location(
   program: &Program,
   name: &str,
@@ -669,3 +776,20 @@ where
     gl::Uniform1i(uniform.index(), self.binding() as GLint)
   }
 }
+
+unsafe impl<S> Uniformable<GL33> for BufferTextureBinding<S>
+where
+  S: SamplerType,
+{
+  unsafe fn ty() -> UniformType {
+    match S::sample_type() {
+      PixelType::Integral => UniformType::ISamplerBuffer,
+      PixelType::Unsigned => UniformType::UISamplerBuffer,
+      _ => UniformType::SamplerBuffer,
+    }
+  }
+
+  unsafe fn update(self, _: &mut Program, uniform: &Uniform<Self>) {
+    gl::Uniform1i(uniform.index(), self.binding() as GLint)
+  }
+}
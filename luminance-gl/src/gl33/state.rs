@@ -3,14 +3,25 @@
 use gl::types::*;
 use std::cell::RefCell;
 use std::error;
+use std::ffi::CStr;
 use std::fmt;
 use std::marker::PhantomData;
 
 use crate::gl33::depth_test::depth_comparison_to_glenum;
+use crate::gl33::ext;
+use crate::gl33::stencil::{stencil_comparison_to_glenum, stencil_op_to_glenum};
 use crate::gl33::vertex_restart::VertexRestart;
-use luminance::blending::{Equation, Factor};
+use luminance::blending::{Blending, Equation, Factor};
+use luminance::capabilities::Capabilities;
+use luminance::color_mask::ColorMask;
 use luminance::depth_test::{DepthComparison, DepthWrite};
-use luminance::face_culling::{FaceCullingMode, FaceCullingOrder};
+use luminance::face_culling::{FaceCulling, FaceCullingMode, FaceCullingOrder};
+use luminance::pipeline::FRAME_UNIFORM_BUFFER_BINDING;
+use luminance::polygon_offset::PolygonOffset;
+use luminance::provoking_vertex::ProvokingVertex;
+use luminance::render_state::RenderState;
+use luminance::scissor::ScissorRegion;
+use luminance::stencil::{StencilComparison, StencilOperations};
 
 // TLS synchronization barrier for `GLState`.
 //
@@ -31,7 +42,8 @@ impl BindingStack {
     BindingStack {
       next_texture_unit: 0,
       free_texture_units: Vec::new(),
-      next_buffer_binding: 0,
+      // the first binding is reserved for the frame uniform buffer
+      next_buffer_binding: FRAME_UNIFORM_BUFFER_BINDING + 1,
       free_buffer_bindings: Vec::new(),
     }
   }
@@ -104,14 +116,14 @@ pub struct GLState {
   // viewport
   viewport: Cached<[GLint; 4]>,
 
-  // clear buffers
-  clear_color: Cached<[GLfloat; 4]>,
-
   // blending
   blending_state: Cached<BlendingState>,
   blending_equations: Cached<BlendingEquations>,
   blending_funcs: Cached<BlendingFactors>,
 
+  // color mask
+  color_mask: Cached<ColorMask>,
+
   // depth test
   depth_test: Cached<DepthTest>,
   depth_test_comparison: Cached<DepthComparison>,
@@ -119,17 +131,70 @@ pub struct GLState {
   // depth write
   depth_write: Cached<DepthWrite>,
 
+  // stencil test
+  stencil_test: Cached<StencilTestState>,
+  stencil_func: Cached<(StencilComparison, u8, u8)>,
+  stencil_operations: Cached<StencilOperations>,
+  stencil_write_mask: Cached<u8>,
+
   // face culling
   face_culling_state: Cached<FaceCullingState>,
   face_culling_order: Cached<FaceCullingOrder>,
   face_culling_mode: Cached<FaceCullingMode>,
 
+  // hardware limits
+  capabilities: Capabilities,
+  seamless_cubemap_per_texture: bool,
+  internalformat_query: bool,
+  blend_equation_advanced: bool,
+  blend_equation_advanced_coherent: bool,
+  depth_bounds_test: bool,
+
+  // set once the advanced blending fallback warning is logged
+  advanced_blending_warned: bool,
+  // set once the unsupported depth bounds test warning is logged
+  depth_bounds_warned: bool,
+  // set once the clamped clip distances warning is logged
+  clip_distances_warned: bool,
+
+  // number of alive cubemaps relying on the global seamless switch
+  seamless_cubemaps: usize,
+
+  // set once a graphics reset is detected; a lost context never comes back
+  context_lost: bool,
+
+  // clip distances
+  clip_distances: Cached<u8>,
+  max_clip_distances: u8,
+
+  // provoking vertex
+  provoking_vertex: Cached<ProvokingVertex>,
+
+  // sample shading
+  min_sample_shading: Cached<Option<f32>>,
+
+  // rasterizer discard
+  rasterizer_discard: Cached<bool>,
+
+  // scissor
+  scissor_state: Cached<ScissorState>,
+  scissor_region: Cached<ScissorRegion>,
+
+  // polygon offset
+  polygon_offset: Cached<Option<PolygonOffset>>,
+
+  // depth bounds test
+  depth_bounds: Cached<Option<(f32, f32)>>,
+
   // vertex restart
   vertex_restart: Cached<VertexRestart>,
 
   // patch primitive vertex number
   patch_vertex_nb: Cached<usize>,
 
+  // point size written by shaders (gl_PointSize)
+  program_point_size: Cached<bool>,
+
   // texture
   current_texture_unit: Cached<GLenum>,
   bound_textures: Vec<(GLenum, GLuint)>,
@@ -145,6 +210,9 @@ pub struct GLState {
   // uniform buffer
   bound_uniform_buffers: Vec<GLuint>,
 
+  // atomic counter buffer
+  bound_atomic_counter_buffers: Vec<GLuint>,
+
   // array buffer
   bound_array_buffer: GLuint,
 
@@ -159,6 +227,7 @@ pub struct GLState {
 
   // shader program
   current_program: GLuint,
+  current_program_tessellated: bool,
 
   // framebuffer sRGB
   srgb_framebuffer_enabled: Cached<bool>,
@@ -190,22 +259,48 @@ impl GLState {
     unsafe {
       let binding_stack = BindingStack::new();
       let viewport = Cached::new(get_ctx_viewport()?);
-      let clear_color = Cached::new(get_ctx_clear_color()?);
       let blending_state = Cached::new(get_ctx_blending_state()?);
       let blending_equations = Cached::new(get_ctx_blending_equations()?);
       let blending_funcs = Cached::new(get_ctx_blending_factors()?);
+      let color_mask = Cached::new(get_ctx_color_mask()?);
       let depth_test = Cached::new(get_ctx_depth_test()?);
       let depth_test_comparison = Cached::new(DepthComparison::Less);
       let depth_write = Cached::new(get_ctx_depth_write()?);
+      let stencil_test = Cached::new(get_ctx_stencil_test()?);
+      let stencil_func = Cached::new((StencilComparison::Always, 0, 0xFF));
+      let stencil_operations = Cached::new(StencilOperations::default());
+      let stencil_write_mask = Cached::new(0xFF);
       let face_culling_state = Cached::new(get_ctx_face_culling_state()?);
       let face_culling_order = Cached::new(get_ctx_face_culling_order()?);
       let face_culling_mode = Cached::new(get_ctx_face_culling_mode()?);
+      let capabilities = get_ctx_capabilities();
+      let seamless_cubemap_per_texture = has_extension("GL_ARB_seamless_cubemap_per_texture")
+        || has_extension("GL_AMD_seamless_cubemap_per_texture");
+      let internalformat_query = has_extension("GL_ARB_internalformat_query2");
+      let blend_equation_advanced =
+        has_extension("GL_KHR_blend_equation_advanced") && ext::blend_barrier_is_loaded();
+      // coherent advanced blending is enabled by default when supported
+      let blend_equation_advanced_coherent =
+        has_extension("GL_KHR_blend_equation_advanced_coherent");
+      let depth_bounds_test =
+        has_extension("GL_EXT_depth_bounds_test") && ext::depth_bounds_is_loaded();
+      let clip_distances = Cached::new(0);
+      let max_clip_distances = get_ctx_max_clip_distances()?;
+      let provoking_vertex = Cached::new(get_ctx_provoking_vertex()?);
+      let min_sample_shading = Cached::new(None);
+      let rasterizer_discard = Cached::new(get_ctx_rasterizer_discard()?);
+      let scissor_state = Cached::new(get_ctx_scissor_state()?);
+      let scissor_region = Cached::new(get_ctx_scissor_region()?);
+      let polygon_offset = Cached::new(get_ctx_polygon_offset()?);
+      let depth_bounds = Cached::new(None);
       let vertex_restart = Cached::new(get_ctx_vertex_restart()?);
       let patch_vertex_nb = Cached::new(0);
+      let program_point_size = Cached::new(get_ctx_program_point_size()?);
       let current_texture_unit = Cached::new(get_ctx_current_texture_unit()?);
       let bound_textures = vec![(gl::TEXTURE_2D, 0); 48]; // 48 is the platform minimal requirement
       let texture_swimming_pool = Vec::new();
       let bound_uniform_buffers = vec![0; 36]; // 36 is the platform minimal requirement
+      let bound_atomic_counter_buffers = vec![0; 1]; // 1 is the platform minimal requirement
       let bound_array_buffer = 0;
       let bound_element_array_buffer = 0;
       let bound_draw_framebuffer = Cached::new(get_ctx_bound_draw_framebuffer()?);
@@ -217,32 +312,102 @@ impl GLState {
         _a: PhantomData,
         binding_stack,
         viewport,
-        clear_color,
         blending_state,
         blending_equations,
         blending_funcs,
+        color_mask,
         depth_test,
         depth_test_comparison,
         depth_write,
+        stencil_test,
+        stencil_func,
+        stencil_operations,
+        stencil_write_mask,
         face_culling_state,
         face_culling_order,
         face_culling_mode,
+        capabilities,
+        seamless_cubemap_per_texture,
+        internalformat_query,
+        blend_equation_advanced,
+        blend_equation_advanced_coherent,
+        depth_bounds_test,
+        advanced_blending_warned: false,
+        depth_bounds_warned: false,
+        clip_distances_warned: false,
+        seamless_cubemaps: 0,
+        context_lost: false,
+        clip_distances,
+        max_clip_distances,
+        provoking_vertex,
+        min_sample_shading,
+        rasterizer_discard,
+        scissor_state,
+        scissor_region,
+        polygon_offset,
+        depth_bounds,
         vertex_restart,
         patch_vertex_nb,
+        program_point_size,
         current_texture_unit,
         bound_textures,
         texture_swimming_pool,
         bound_uniform_buffers,
+        bound_atomic_counter_buffers,
         bound_array_buffer,
         bound_element_array_buffer,
         bound_draw_framebuffer,
         bound_vertex_array,
         current_program,
+        current_program_tessellated: false,
         srgb_framebuffer_enabled,
       })
     }
   }
 
+  /// Invalidate the whole cached state.
+  ///
+  /// This is needed when the graphics state was modified behind our back — e.g. by third-party
+  /// code issuing raw OpenGL calls — so that the next commands are issued unconditionally.
+  pub fn invalidate_all(&mut self) {
+    self.invalidate_vertex_array();
+    self.invalidate_array_buffer();
+    self.invalidate_shader_program();
+    self.invalidate_framebuffer();
+    self.invalidate_element_array_buffer();
+    self.invalidate_texture_unit();
+    self.invalidate_bound_textures();
+    self.invalidate_bound_uniform_buffers();
+    self.invalidate_bound_atomic_counter_buffers();
+    self.invalidate_viewport();
+    self.invalidate_blending_state();
+    self.invalidate_blending_equation();
+    self.invalidate_blending_func();
+    self.invalidate_color_mask();
+    self.invalidate_depth_test();
+    self.invalidate_depth_test_comparison();
+    self.invalidate_depth_write();
+    self.invalidate_stencil_test();
+    self.invalidate_stencil_func();
+    self.invalidate_stencil_operations();
+    self.invalidate_stencil_write_mask();
+    self.invalidate_face_culling_state();
+    self.invalidate_face_culling_order();
+    self.invalidate_face_culling_mode();
+    self.invalidate_clip_distances();
+    self.invalidate_provoking_vertex();
+    self.invalidate_min_sample_shading();
+    self.invalidate_rasterizer_discard();
+    self.invalidate_scissor_state();
+    self.invalidate_scissor_region();
+    self.invalidate_polygon_offset();
+    self.invalidate_depth_bounds();
+    self.invalidate_vertex_restart();
+    self.invalidate_patch_vertex_nb();
+    self.invalidate_program_point_size();
+    self.invalidate_srgb_framebuffer_enabled();
+  }
+
   /// Invalidate the currently in-use vertex array.
   pub fn invalidate_vertex_array(&mut self) {
     self.bound_vertex_array = 0;
@@ -256,6 +421,7 @@ impl GLState {
   /// Invalidate the currently in-use shader program.
   pub fn invalidate_shader_program(&mut self) {
     self.current_program = 0;
+    self.current_program_tessellated = false;
   }
 
   /// Invalidate the currently in-use framebuffer.
@@ -287,15 +453,38 @@ impl GLState {
     }
   }
 
+  /// Invalidate the atomic counter buffer bindings.
+  pub fn invalidate_bound_atomic_counter_buffers(&mut self) {
+    for b in &mut self.bound_atomic_counter_buffers {
+      *b = 0;
+    }
+  }
+
   /// Invalidate the currently in-use viewport.
   pub fn invalidate_viewport(&mut self) {
     self.viewport.invalidate()
   }
 
   /// Invalidate the currently in-use clear color.
-  pub fn invalidate_clear_color(&mut self) {
-    self.clear_color.invalidate()
-  }
+  ///
+  /// Clear values are not cached anymore, as every buffer is cleared with `glClearBuffer*`; this
+  /// does nothing.
+  #[deprecated(note = "clear values are not cached anymore; this does nothing")]
+  pub fn invalidate_clear_color(&mut self) {}
+
+  /// Invalidate the currently in-use clear depth.
+  ///
+  /// Clear values are not cached anymore, as every buffer is cleared with `glClearBuffer*`; this
+  /// does nothing.
+  #[deprecated(note = "clear values are not cached anymore; this does nothing")]
+  pub fn invalidate_clear_depth(&mut self) {}
+
+  /// Invalidate the currently in-use clear stencil.
+  ///
+  /// Clear values are not cached anymore, as every buffer is cleared with `glClearBuffer*`; this
+  /// does nothing.
+  #[deprecated(note = "clear values are not cached anymore; this does nothing")]
+  pub fn invalidate_clear_stencil(&mut self) {}
 
   /// Invalidate the currently in-use blending state.
   pub fn invalidate_blending_state(&mut self) {
@@ -312,6 +501,11 @@ impl GLState {
     self.blending_funcs.invalidate()
   }
 
+  /// Invalidate the currently in-use color mask.
+  pub fn invalidate_color_mask(&mut self) {
+    self.color_mask.invalidate()
+  }
+
   /// Invalidate the currently in-use depth test.
   pub fn invalidate_depth_test(&mut self) {
     self.depth_test.invalidate()
@@ -327,6 +521,26 @@ impl GLState {
     self.depth_write.invalidate()
   }
 
+  /// Invalidate the currently in-use stencil test state.
+  pub fn invalidate_stencil_test(&mut self) {
+    self.stencil_test.invalidate()
+  }
+
+  /// Invalidate the currently in-use stencil function.
+  pub fn invalidate_stencil_func(&mut self) {
+    self.stencil_func.invalidate()
+  }
+
+  /// Invalidate the currently in-use stencil operations.
+  pub fn invalidate_stencil_operations(&mut self) {
+    self.stencil_operations.invalidate()
+  }
+
+  /// Invalidate the currently in-use stencil write mask.
+  pub fn invalidate_stencil_write_mask(&mut self) {
+    self.stencil_write_mask.invalidate()
+  }
+
   /// Invalidate the currently in-use face culling state.
   pub fn invalidate_face_culling_state(&mut self) {
     self.face_culling_state.invalidate()
@@ -342,6 +556,46 @@ impl GLState {
     self.face_culling_mode.invalidate()
   }
 
+  /// Invalidate the currently enabled clip distances.
+  pub fn invalidate_clip_distances(&mut self) {
+    self.clip_distances.invalidate()
+  }
+
+  /// Invalidate the currently in-use provoking vertex.
+  pub fn invalidate_provoking_vertex(&mut self) {
+    self.provoking_vertex.invalidate()
+  }
+
+  /// Invalidate the currently in-use sample shading.
+  pub fn invalidate_min_sample_shading(&mut self) {
+    self.min_sample_shading.invalidate()
+  }
+
+  /// Invalidate the currently in-use rasterizer discard state.
+  pub fn invalidate_rasterizer_discard(&mut self) {
+    self.rasterizer_discard.invalidate()
+  }
+
+  /// Invalidate the currently in-use scissor state.
+  pub fn invalidate_scissor_state(&mut self) {
+    self.scissor_state.invalidate()
+  }
+
+  /// Invalidate the currently in-use scissor region.
+  pub fn invalidate_scissor_region(&mut self) {
+    self.scissor_region.invalidate()
+  }
+
+  /// Invalidate the currently in-use polygon offset.
+  pub fn invalidate_polygon_offset(&mut self) {
+    self.polygon_offset.invalidate()
+  }
+
+  /// Invalidate the currently in-use depth bounds test.
+  pub fn invalidate_depth_bounds(&mut self) {
+    self.depth_bounds.invalidate()
+  }
+
   /// Invalidate the currently in-use vertex restart state.
   pub fn invalidate_vertex_restart(&mut self) {
     self.vertex_restart.invalidate()
@@ -352,11 +606,24 @@ impl GLState {
     self.patch_vertex_nb.invalidate()
   }
 
+  /// Invalidate the currently in-use program point size state.
+  pub fn invalidate_program_point_size(&mut self) {
+    self.program_point_size.invalidate()
+  }
+
   /// Invalidate the currently in-use sRGB framebuffer state.
   pub fn invalidate_srgb_framebuffer_enabled(&mut self) {
     self.srgb_framebuffer_enabled.invalidate()
   }
 
+  pub(crate) fn capabilities(&self) -> Capabilities {
+    self.capabilities
+  }
+
+  pub(crate) fn has_internalformat_query(&self) -> bool {
+    self.internalformat_query
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -392,18 +659,6 @@ impl GLState {
     }
   }
 
-  pub(crate) unsafe fn set_clear_color(&mut self, clear_color: [GLfloat; 4]) {
-    if self.clear_color.is_invalid(&clear_color) {
-      gl::ClearColor(
-        clear_color[0],
-        clear_color[1],
-        clear_color[2],
-        clear_color[3],
-      );
-      self.clear_color.set(clear_color);
-    }
-  }
-
   pub(crate) unsafe fn set_blending_state(&mut self, state: BlendingState) {
     if self.blending_state.is_invalid(&state) {
       match state {
@@ -416,6 +671,7 @@ impl GLState {
   }
 
   pub(crate) unsafe fn set_blending_equation(&mut self, equation: Equation) {
+    let equation = self.supported_blending_equation(equation, false);
     let equations = BlendingEquations {
       rgb: equation,
       alpha: equation,
@@ -432,6 +688,8 @@ impl GLState {
     equation_rgb: Equation,
     equation_alpha: Equation,
   ) {
+    let equation_rgb = self.supported_blending_equation(equation_rgb, true);
+    let equation_alpha = self.supported_blending_equation(equation_alpha, true);
     let equations = BlendingEquations {
       rgb: equation_rgb,
       alpha: equation_alpha,
@@ -447,6 +705,49 @@ impl GLState {
     }
   }
 
+  /// Replace an advanced blending equation by [`Equation::Additive`] if it cannot be used.
+  ///
+  /// Advanced equations require `GL_KHR_blend_equation_advanced` and cannot be used with separate
+  /// blending.
+  fn supported_blending_equation(&mut self, equation: Equation, separate: bool) -> Equation {
+    if !equation.is_advanced() || (self.blend_equation_advanced && !separate) {
+      return equation;
+    }
+
+    if !self.advanced_blending_warned {
+      self.advanced_blending_warned = true;
+
+      if separate {
+        log::warn!(
+          "advanced blending equations cannot be separate; falling back to additive blending"
+        );
+      } else {
+        log::warn!(
+          "GL_KHR_blend_equation_advanced not supported; falling back to additive blending"
+        );
+      }
+    }
+
+    Equation::Additive
+  }
+
+  /// Issue a blend barrier if the current blending requires one between overlapping draws.
+  ///
+  /// That is the case of advanced blending equations, unless they are coherent.
+  pub(crate) unsafe fn blend_barrier(&self) {
+    if self.blend_equation_advanced_coherent {
+      return;
+    }
+
+    let blending = self.blending_state.0 == Some(BlendingState::On);
+    let advanced =
+      matches!(self.blending_equations.0, Some(ref equations) if equations.rgb.is_advanced());
+
+    if blending && advanced {
+      ext::blend_barrier();
+    }
+  }
+
   pub(crate) unsafe fn set_blending_func(&mut self, src: Factor, dst: Factor) {
     let funcs = BlendingFactors {
       src_rgb: src,
@@ -524,6 +825,66 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn set_color_mask(&mut self, color_mask: ColorMask) {
+    if self.color_mask.is_invalid(&color_mask) {
+      gl::ColorMask(
+        color_mask.red as GLboolean,
+        color_mask.green as GLboolean,
+        color_mask.blue as GLboolean,
+        color_mask.alpha as GLboolean,
+      );
+
+      self.color_mask.set(color_mask);
+    }
+  }
+
+  pub(crate) unsafe fn set_stencil_test(&mut self, state: StencilTestState) {
+    if self.stencil_test.is_invalid(&state) {
+      match state {
+        StencilTestState::On => gl::Enable(gl::STENCIL_TEST),
+        StencilTestState::Off => gl::Disable(gl::STENCIL_TEST),
+      }
+
+      self.stencil_test.set(state);
+    }
+  }
+
+  pub(crate) unsafe fn set_stencil_func(
+    &mut self,
+    comparison: StencilComparison,
+    reference: u8,
+    mask: u8,
+  ) {
+    let func = (comparison, reference, mask);
+
+    if self.stencil_func.is_invalid(&func) {
+      gl::StencilFunc(
+        stencil_comparison_to_glenum(comparison),
+        reference as GLint,
+        mask as GLuint,
+      );
+      self.stencil_func.set(func);
+    }
+  }
+
+  pub(crate) unsafe fn set_stencil_operations(&mut self, operations: StencilOperations) {
+    if self.stencil_operations.is_invalid(&operations) {
+      gl::StencilOp(
+        stencil_op_to_glenum(operations.stencil_fails),
+        stencil_op_to_glenum(operations.depth_fails),
+        stencil_op_to_glenum(operations.depth_passes),
+      );
+      self.stencil_operations.set(operations);
+    }
+  }
+
+  pub(crate) unsafe fn set_stencil_write_mask(&mut self, write_mask: u8) {
+    if self.stencil_write_mask.is_invalid(&write_mask) {
+      gl::StencilMask(write_mask as GLuint);
+      self.stencil_write_mask.set(write_mask);
+    }
+  }
+
   pub(crate) unsafe fn set_face_culling_state(&mut self, state: FaceCullingState) {
     if self.face_culling_state.is_invalid(&state) {
       match state {
@@ -558,6 +919,140 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn set_clip_distances(&mut self, clip_distances: u8) {
+    if cfg!(debug_assertions)
+      && clip_distances > self.max_clip_distances
+      && !self.clip_distances_warned
+    {
+      self.clip_distances_warned = true;
+      log::warn!(
+        "{} clip distances requested but only {} supported; clamping",
+        clip_distances,
+        self.max_clip_distances
+      );
+    }
+
+    let clip_distances = clip_distances.min(self.max_clip_distances);
+
+    if self.clip_distances.is_invalid(&clip_distances) {
+      for i in 0..self.max_clip_distances {
+        let cap = gl::CLIP_DISTANCE0 + i as GLenum;
+
+        if i < clip_distances {
+          gl::Enable(cap);
+        } else {
+          gl::Disable(cap);
+        }
+      }
+
+      self.clip_distances.set(clip_distances);
+    }
+  }
+
+  pub(crate) unsafe fn set_provoking_vertex(&mut self, provoking_vertex: ProvokingVertex) {
+    if self.provoking_vertex.is_invalid(&provoking_vertex) {
+      match provoking_vertex {
+        ProvokingVertex::First => gl::ProvokingVertex(gl::FIRST_VERTEX_CONVENTION),
+        ProvokingVertex::Last => gl::ProvokingVertex(gl::LAST_VERTEX_CONVENTION),
+      }
+
+      self.provoking_vertex.set(provoking_vertex);
+    }
+  }
+
+  pub(crate) unsafe fn set_min_sample_shading(&mut self, min_sample_shading: Option<f32>) {
+    // sample shading is OpenGL 4.0; ignore it if not available
+    if !gl::MinSampleShading::is_loaded() {
+      return;
+    }
+
+    if self.min_sample_shading.is_invalid(&min_sample_shading) {
+      match min_sample_shading {
+        Some(fraction) => {
+          gl::Enable(gl::SAMPLE_SHADING);
+          gl::MinSampleShading(fraction.clamp(0., 1.));
+        }
+        None => gl::Disable(gl::SAMPLE_SHADING),
+      }
+
+      self.min_sample_shading.set(min_sample_shading);
+    }
+  }
+
+  pub(crate) unsafe fn set_rasterizer_discard(&mut self, rasterizer_discard: bool) {
+    if self.rasterizer_discard.is_invalid(&rasterizer_discard) {
+      if rasterizer_discard {
+        gl::Enable(gl::RASTERIZER_DISCARD);
+      } else {
+        gl::Disable(gl::RASTERIZER_DISCARD);
+      }
+
+      self.rasterizer_discard.set(rasterizer_discard);
+    }
+  }
+
+  pub(crate) unsafe fn set_scissor_state(&mut self, state: ScissorState) {
+    if self.scissor_state.is_invalid(&state) {
+      match state {
+        ScissorState::On => gl::Enable(gl::SCISSOR_TEST),
+        ScissorState::Off => gl::Disable(gl::SCISSOR_TEST),
+      }
+
+      self.scissor_state.set(state);
+    }
+  }
+
+  pub(crate) unsafe fn set_scissor_region(&mut self, region: &ScissorRegion) {
+    if self.scissor_region.is_invalid(region) {
+      gl::Scissor(
+        region.x as GLint,
+        region.y as GLint,
+        region.width as GLsizei,
+        region.height as GLsizei,
+      );
+
+      self.scissor_region.set(*region);
+    }
+  }
+
+  pub(crate) unsafe fn set_polygon_offset(&mut self, polygon_offset: Option<PolygonOffset>) {
+    if self.polygon_offset.is_invalid(&polygon_offset) {
+      match polygon_offset {
+        Some(offset) => {
+          gl::Enable(gl::POLYGON_OFFSET_FILL);
+          gl::PolygonOffset(offset.factor, offset.units);
+        }
+        None => gl::Disable(gl::POLYGON_OFFSET_FILL),
+      }
+
+      self.polygon_offset.set(polygon_offset);
+    }
+  }
+
+  pub(crate) unsafe fn set_depth_bounds(&mut self, depth_bounds: Option<(f32, f32)>) {
+    // the depth bounds test requires GL_EXT_depth_bounds_test; ignore it if not available
+    if !self.depth_bounds_test {
+      if depth_bounds.is_some() && !self.depth_bounds_warned {
+        self.depth_bounds_warned = true;
+        log::warn!("GL_EXT_depth_bounds_test not supported; ignoring the depth bounds test");
+      }
+
+      return;
+    }
+
+    if self.depth_bounds.is_invalid(&depth_bounds) {
+      match depth_bounds {
+        Some((min, max)) => {
+          gl::Enable(ext::DEPTH_BOUNDS_TEST_EXT);
+          ext::depth_bounds(min as GLdouble, max as GLdouble);
+        }
+        None => gl::Disable(ext::DEPTH_BOUNDS_TEST_EXT),
+      }
+
+      self.depth_bounds.set(depth_bounds);
+    }
+  }
+
   pub(crate) unsafe fn set_vertex_restart(&mut self, state: VertexRestart) {
     if self.vertex_restart.is_invalid(&state) {
       match state {
@@ -576,6 +1071,13 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn enable_program_point_size(&mut self) {
+    if self.program_point_size.is_invalid(&true) {
+      gl::Enable(gl::PROGRAM_POINT_SIZE);
+      self.program_point_size.set(true);
+    }
+  }
+
   pub(crate) unsafe fn set_texture_unit(&mut self, unit: u32) {
     let unit = unit as GLenum;
 
@@ -639,6 +1141,50 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn bind_atomic_counter_buffer_base(&mut self, handle: GLuint, binding: u32) {
+    let binding_ = binding as usize;
+
+    match self.bound_atomic_counter_buffers.get(binding_) {
+      Some(&handle_) if handle != handle_ => {
+        gl::BindBufferBase(gl::ATOMIC_COUNTER_BUFFER, binding as GLuint, handle);
+        self.bound_atomic_counter_buffers[binding_] = handle;
+      }
+
+      None => {
+        gl::BindBufferBase(gl::ATOMIC_COUNTER_BUFFER, binding as GLuint, handle);
+
+        // not enough registered buffer bindings; let’s grow a bit more
+        self.bound_atomic_counter_buffers.resize(binding_ + 1, 0);
+        self.bound_atomic_counter_buffers[binding_] = handle;
+      }
+
+      _ => (), // cached
+    }
+  }
+
+  /// Unbind all the textures, uniform buffers and atomic counter buffers.
+  pub(crate) unsafe fn unbind_pipeline_resources(&mut self) {
+    for unit in 0..self.bound_textures.len() {
+      let (target, handle) = self.bound_textures[unit];
+
+      if handle != 0 {
+        self.bind_texture_at(target, 0, unit as u32);
+      }
+    }
+
+    for binding in 0..self.bound_uniform_buffers.len() {
+      if self.bound_uniform_buffers[binding] != 0 {
+        self.bind_buffer_base(0, binding as u32);
+      }
+    }
+
+    for binding in 0..self.bound_atomic_counter_buffers.len() {
+      if self.bound_atomic_counter_buffers[binding] != 0 {
+        self.bind_atomic_counter_buffer_base(0, binding as u32);
+      }
+    }
+  }
+
   pub(crate) unsafe fn bind_array_buffer(&mut self, handle: GLuint, bind: Bind) {
     if bind == Bind::Forced || self.bound_array_buffer != handle {
       gl::BindBuffer(gl::ARRAY_BUFFER, handle);
@@ -664,6 +1210,12 @@ impl GLState {
       .find(|h| **h == handle)
     {
       *handle_ = 0;
+    } else if let Some(handle_) = self
+      .bound_atomic_counter_buffers
+      .iter_mut()
+      .find(|h| **h == handle)
+    {
+      *handle_ = 0;
     }
   }
 
@@ -685,13 +1237,29 @@ impl GLState {
     self.bind_vertex_array(0, Bind::Cached)
   }
 
-  pub(crate) unsafe fn use_program(&mut self, handle: GLuint) {
+  pub(crate) unsafe fn use_program(&mut self, handle: GLuint, tessellated: bool) {
     if self.current_program != handle {
       gl::UseProgram(handle);
       self.current_program = handle;
+      self.current_program_tessellated = tessellated;
     }
   }
 
+  /// Whether the currently in-use shader program has tessellation stages.
+  pub(crate) fn is_current_program_tessellated(&self) -> bool {
+    self.current_program_tessellated
+  }
+
+  /// Reset the states honored by clear commands, which might have been changed by a previous render
+  /// state, so that clears affect the whole buffers.
+  pub(crate) unsafe fn prepare_clear(&mut self) {
+    self.set_scissor_state(ScissorState::Off);
+    self.set_rasterizer_discard(false);
+    self.set_color_mask(ColorMask::all());
+    self.set_depth_write(DepthWrite::On);
+    self.set_stencil_write_mask(0xFF);
+  }
+
   pub(crate) unsafe fn enable_srgb_framebuffer(&mut self, srgb_framebuffer_enabled: bool) {
     if self
       .srgb_framebuffer_enabled
@@ -706,6 +1274,50 @@ impl GLState {
       self.srgb_framebuffer_enabled.set(srgb_framebuffer_enabled);
     }
   }
+
+  /// Set the seamless filtering of the cubemap currently bound to `target`.
+  ///
+  /// Without per-texture seamless filtering, it falls back to the global switch, which remains
+  /// enabled as long as a seamless cubemap is alive. Returns whether the cubemap relies on the
+  /// global switch, in which case [`GLState::release_seamless_cubemap`] must be called once it is
+  /// dropped.
+  pub(crate) unsafe fn set_seamless_cubemap(&mut self, target: GLenum, seamless: bool) -> bool {
+    if self.seamless_cubemap_per_texture {
+      gl::TexParameteri(target, gl::TEXTURE_CUBE_MAP_SEAMLESS, seamless as GLint);
+      false
+    } else if seamless {
+      if self.seamless_cubemaps == 0 {
+        gl::Enable(gl::TEXTURE_CUBE_MAP_SEAMLESS);
+      }
+
+      self.seamless_cubemaps += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Release the global seamless switch held by a dropped cubemap, disabling it if no seamless
+  /// cubemap remains.
+  pub(crate) unsafe fn release_seamless_cubemap(&mut self) {
+    self.seamless_cubemaps -= 1;
+
+    if self.seamless_cubemaps == 0 {
+      gl::Disable(gl::TEXTURE_CUBE_MAP_SEAMLESS);
+    }
+  }
+
+  /// Check whether the context was lost because of a graphics reset.
+  ///
+  /// Resets are only reported by robust contexts with OpenGL 4.5 or `GL_KHR_robustness`; otherwise,
+  /// the context is never considered lost.
+  pub(crate) unsafe fn is_context_lost(&mut self) -> bool {
+    if !self.context_lost && gl::GetGraphicsResetStatus::is_loaded() {
+      self.context_lost = gl::GetGraphicsResetStatus() != gl::NO_ERROR;
+    }
+
+    self.context_lost
+  }
 }
 
 /// Should the binding be cached or forced to the provided value?
@@ -723,6 +1335,21 @@ fn from_blending_equation(equation: Equation) -> GLenum {
     Equation::ReverseSubtract => gl::FUNC_REVERSE_SUBTRACT,
     Equation::Min => gl::MIN,
     Equation::Max => gl::MAX,
+    Equation::Multiply => ext::MULTIPLY_KHR,
+    Equation::Screen => ext::SCREEN_KHR,
+    Equation::Overlay => ext::OVERLAY_KHR,
+    Equation::Darken => ext::DARKEN_KHR,
+    Equation::Lighten => ext::LIGHTEN_KHR,
+    Equation::ColorDodge => ext::COLORDODGE_KHR,
+    Equation::ColorBurn => ext::COLORBURN_KHR,
+    Equation::HardLight => ext::HARDLIGHT_KHR,
+    Equation::SoftLight => ext::SOFTLIGHT_KHR,
+    Equation::Difference => ext::DIFFERENCE_KHR,
+    Equation::Exclusion => ext::EXCLUSION_KHR,
+    Equation::HslHue => ext::HSL_HUE_KHR,
+    Equation::HslSaturation => ext::HSL_SATURATION_KHR,
+    Equation::HslColor => ext::HSL_COLOR_KHR,
+    Equation::HslLuminosity => ext::HSL_LUMINOSITY_KHR,
   }
 }
 
@@ -762,18 +1389,32 @@ pub enum StateQueryError {
   UnknownBlendingDstFactor(GLenum),
   /// Corrupted depth test state.
   UnknownDepthTestState(GLboolean),
+  /// Corrupted depth test comparison.
+  UnknownDepthTestComparison(GLenum),
   /// Corrupted depth write state.
   UnknownDepthWriteState(GLboolean),
+  /// Corrupted stencil test state.
+  UnknownStencilTestState(GLboolean),
   /// Corrupted face culling state.
   UnknownFaceCullingState(GLboolean),
   /// Corrupted face culling order.
   UnknownFaceCullingOrder(GLenum),
   /// Corrupted face culling mode.
   UnknownFaceCullingMode(GLenum),
+  /// Corrupted provoking vertex.
+  UnknownProvokingVertex(GLenum),
+  /// Corrupted scissor state.
+  UnknownScissorState(GLboolean),
   /// Corrupted vertex restart state.
   UnknownVertexRestartState(GLboolean),
   /// Corrupted sRGB framebuffer state.
   UnknownSRGBFramebufferState(GLboolean),
+  /// Corrupted program point size state.
+  UnknownProgramPointSizeState(GLboolean),
+  /// Corrupted rasterizer discard state.
+  UnknownRasterizerDiscardState(GLboolean),
+  /// Corrupted polygon offset state.
+  UnknownPolygonOffsetState(GLboolean),
 }
 
 impl fmt::Display for StateQueryError {
@@ -791,9 +1432,15 @@ impl fmt::Display for StateQueryError {
         write!(f, "unknown blending destination factor: {}", k)
       }
       StateQueryError::UnknownDepthTestState(ref s) => write!(f, "unknown depth test state: {}", s),
+      StateQueryError::UnknownDepthTestComparison(ref c) => {
+        write!(f, "unknown depth test comparison: {}", c)
+      }
       StateQueryError::UnknownDepthWriteState(ref s) => {
         write!(f, "unknown depth write state: {}", s)
       }
+      StateQueryError::UnknownStencilTestState(ref s) => {
+        write!(f, "unknown stencil test state: {}", s)
+      }
       StateQueryError::UnknownFaceCullingState(ref s) => {
         write!(f, "unknown face culling state: {}", s)
       }
@@ -803,12 +1450,25 @@ impl fmt::Display for StateQueryError {
       StateQueryError::UnknownFaceCullingMode(ref m) => {
         write!(f, "unknown face culling mode: {}", m)
       }
+      StateQueryError::UnknownProvokingVertex(ref c) => {
+        write!(f, "unknown provoking vertex: {}", c)
+      }
+      StateQueryError::UnknownScissorState(ref s) => write!(f, "unknown scissor state: {}", s),
       StateQueryError::UnknownVertexRestartState(ref s) => {
         write!(f, "unknown vertex restart state: {}", s)
       }
       StateQueryError::UnknownSRGBFramebufferState(ref s) => {
         write!(f, "unknown sRGB framebuffer state: {}", s)
       }
+      StateQueryError::UnknownProgramPointSizeState(ref s) => {
+        write!(f, "unknown program point size state: {}", s)
+      }
+      StateQueryError::UnknownRasterizerDiscardState(ref s) => {
+        write!(f, "unknown rasterizer discard state: {}", s)
+      }
+      StateQueryError::UnknownPolygonOffsetState(ref s) => {
+        write!(f, "unknown polygon offset state: {}", s)
+      }
     }
   }
 }
@@ -821,12 +1481,6 @@ unsafe fn get_ctx_viewport() -> Result<[GLint; 4], StateQueryError> {
   Ok(data)
 }
 
-unsafe fn get_ctx_clear_color() -> Result<[GLfloat; 4], StateQueryError> {
-  let mut data = [0.; 4];
-  gl::GetFloatv(gl::COLOR_CLEAR_VALUE, data.as_mut_ptr());
-  Ok(data)
-}
-
 unsafe fn get_ctx_blending_state() -> Result<BlendingState, StateQueryError> {
   let state = gl::IsEnabled(gl::BLEND);
 
@@ -886,6 +1540,21 @@ fn map_enum_to_blending_equation(data: GLenum) -> Result<Equation, StateQueryErr
     gl::FUNC_REVERSE_SUBTRACT => Ok(Equation::ReverseSubtract),
     gl::MIN => Ok(Equation::Min),
     gl::MAX => Ok(Equation::Max),
+    ext::MULTIPLY_KHR => Ok(Equation::Multiply),
+    ext::SCREEN_KHR => Ok(Equation::Screen),
+    ext::OVERLAY_KHR => Ok(Equation::Overlay),
+    ext::DARKEN_KHR => Ok(Equation::Darken),
+    ext::LIGHTEN_KHR => Ok(Equation::Lighten),
+    ext::COLORDODGE_KHR => Ok(Equation::ColorDodge),
+    ext::COLORBURN_KHR => Ok(Equation::ColorBurn),
+    ext::HARDLIGHT_KHR => Ok(Equation::HardLight),
+    ext::SOFTLIGHT_KHR => Ok(Equation::SoftLight),
+    ext::DIFFERENCE_KHR => Ok(Equation::Difference),
+    ext::EXCLUSION_KHR => Ok(Equation::Exclusion),
+    ext::HSL_HUE_KHR => Ok(Equation::HslHue),
+    ext::HSL_SATURATION_KHR => Ok(Equation::HslSaturation),
+    ext::HSL_COLOR_KHR => Ok(Equation::HslColor),
+    ext::HSL_LUMINOSITY_KHR => Ok(Equation::HslLuminosity),
     _ => Err(StateQueryError::UnknownBlendingEquation(data)),
   }
 }
@@ -918,8 +1587,40 @@ unsafe fn get_ctx_depth_test() -> Result<DepthTest, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_color_mask() -> Result<ColorMask, StateQueryError> {
+  let mut data = [gl::TRUE; 4];
+  gl::GetBooleanv(gl::COLOR_WRITEMASK, data.as_mut_ptr());
+
+  Ok(ColorMask::new(
+    data[0] == gl::TRUE,
+    data[1] == gl::TRUE,
+    data[2] == gl::TRUE,
+    data[3] == gl::TRUE,
+  ))
+}
+
+unsafe fn get_ctx_depth_test_comparison() -> Result<DepthComparison, StateQueryError> {
+  let mut comparison = gl::LESS as GLint;
+  gl::GetIntegerv(gl::DEPTH_FUNC, &mut comparison);
+
+  let comparison = comparison as GLenum;
+  match comparison {
+    gl::NEVER => Ok(DepthComparison::Never),
+    gl::ALWAYS => Ok(DepthComparison::Always),
+    gl::EQUAL => Ok(DepthComparison::Equal),
+    gl::NOTEQUAL => Ok(DepthComparison::NotEqual),
+    gl::LESS => Ok(DepthComparison::Less),
+    gl::LEQUAL => Ok(DepthComparison::LessOrEqual),
+    gl::GREATER => Ok(DepthComparison::Greater),
+    gl::GEQUAL => Ok(DepthComparison::GreaterOrEqual),
+    _ => Err(StateQueryError::UnknownDepthTestComparison(comparison)),
+  }
+}
+
 unsafe fn get_ctx_depth_write() -> Result<DepthWrite, StateQueryError> {
-  let state = gl::IsEnabled(gl::DEPTH_WRITEMASK);
+  // the depth write mask is not a capability, so it cannot be queried with glIsEnabled
+  let mut state = gl::TRUE;
+  gl::GetBooleanv(gl::DEPTH_WRITEMASK, &mut state);
 
   match state {
     gl::TRUE => Ok(DepthWrite::On),
@@ -928,6 +1629,16 @@ unsafe fn get_ctx_depth_write() -> Result<DepthWrite, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_stencil_test() -> Result<StencilTestState, StateQueryError> {
+  let state = gl::IsEnabled(gl::STENCIL_TEST);
+
+  match state {
+    gl::TRUE => Ok(StencilTestState::On),
+    gl::FALSE => Ok(StencilTestState::Off),
+    _ => Err(StateQueryError::UnknownStencilTestState(state)),
+  }
+}
+
 unsafe fn get_ctx_face_culling_state() -> Result<FaceCullingState, StateQueryError> {
   let state = gl::IsEnabled(gl::CULL_FACE);
 
@@ -938,6 +1649,89 @@ unsafe fn get_ctx_face_culling_state() -> Result<FaceCullingState, StateQueryErr
   }
 }
 
+// Not exposed by the OpenGL 3.3 bindings, as it comes from the GL_EXT_texture_filter_anisotropic
+// extension (core since OpenGL 4.6).
+const MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FF;
+
+unsafe fn get_ctx_capabilities() -> Capabilities {
+  let max_anisotropy = if has_extension("GL_EXT_texture_filter_anisotropic")
+    || has_extension("GL_ARB_texture_filter_anisotropic")
+  {
+    let mut max = 0.;
+    gl::GetFloatv(MAX_TEXTURE_MAX_ANISOTROPY, &mut max);
+    Some(max).filter(|&max| max >= 1.)
+  } else {
+    None
+  };
+
+  Capabilities::new()
+    .set_max_texture_size(get_ctx_limit(gl::MAX_TEXTURE_SIZE))
+    .set_max_3d_texture_size(get_ctx_limit(gl::MAX_3D_TEXTURE_SIZE))
+    .set_max_array_layers(get_ctx_limit(gl::MAX_ARRAY_TEXTURE_LAYERS))
+    .set_max_samples(get_ctx_limit(gl::MAX_SAMPLES))
+    .set_max_anisotropy(max_anisotropy)
+    .set_max_color_attachments(get_ctx_limit(gl::MAX_COLOR_ATTACHMENTS))
+    .set_framebuffer_fetch(has_extension("GL_EXT_shader_framebuffer_fetch"))
+}
+
+/// Get a limit, returning `None` if it cannot be queried.
+unsafe fn get_ctx_limit(param: GLenum) -> Option<usize> {
+  let mut limit = 0;
+  gl::GetIntegerv(param, &mut limit);
+  Some(limit as usize).filter(|_| limit > 0)
+}
+
+/// Check whether an extension is supported by the current context.
+unsafe fn has_extension(name: &str) -> bool {
+  let mut count = 0;
+  gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+
+  (0..count.max(0) as GLuint).any(|i| {
+    let ext = gl::GetStringi(gl::EXTENSIONS, i);
+    !ext.is_null() && CStr::from_ptr(ext as *const _).to_bytes() == name.as_bytes()
+  })
+}
+
+unsafe fn get_ctx_max_clip_distances() -> Result<u8, StateQueryError> {
+  let mut max = 0;
+  gl::GetIntegerv(gl::MAX_CLIP_DISTANCES, &mut max);
+  Ok(max.clamp(0, u8::MAX as GLint) as u8)
+}
+
+unsafe fn get_ctx_provoking_vertex() -> Result<ProvokingVertex, StateQueryError> {
+  let mut convention = gl::LAST_VERTEX_CONVENTION as GLint;
+  gl::GetIntegerv(gl::PROVOKING_VERTEX, &mut convention);
+
+  let convention = convention as GLenum;
+  match convention {
+    gl::FIRST_VERTEX_CONVENTION => Ok(ProvokingVertex::First),
+    gl::LAST_VERTEX_CONVENTION => Ok(ProvokingVertex::Last),
+    _ => Err(StateQueryError::UnknownProvokingVertex(convention)),
+  }
+}
+
+unsafe fn get_ctx_scissor_state() -> Result<ScissorState, StateQueryError> {
+  let state = gl::IsEnabled(gl::SCISSOR_TEST);
+
+  match state {
+    gl::TRUE => Ok(ScissorState::On),
+    gl::FALSE => Ok(ScissorState::Off),
+    _ => Err(StateQueryError::UnknownScissorState(state)),
+  }
+}
+
+unsafe fn get_ctx_scissor_region() -> Result<ScissorRegion, StateQueryError> {
+  let mut data = [0; 4];
+  gl::GetIntegerv(gl::SCISSOR_BOX, data.as_mut_ptr());
+
+  Ok(ScissorRegion {
+    x: data[0] as u32,
+    y: data[1] as u32,
+    width: data[2] as u32,
+    height: data[3] as u32,
+  })
+}
+
 unsafe fn get_ctx_face_culling_order() -> Result<FaceCullingOrder, StateQueryError> {
   let mut order = gl::CCW as GLint;
   gl::GetIntegerv(gl::FRONT_FACE, &mut order);
@@ -1007,6 +1801,84 @@ unsafe fn get_ctx_srgb_framebuffer_enabled() -> Result<bool, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_program_point_size() -> Result<bool, StateQueryError> {
+  let state = gl::IsEnabled(gl::PROGRAM_POINT_SIZE);
+
+  match state {
+    gl::TRUE => Ok(true),
+    gl::FALSE => Ok(false),
+    _ => Err(StateQueryError::UnknownProgramPointSizeState(state)),
+  }
+}
+
+unsafe fn get_ctx_rasterizer_discard() -> Result<bool, StateQueryError> {
+  let state = gl::IsEnabled(gl::RASTERIZER_DISCARD);
+
+  match state {
+    gl::TRUE => Ok(true),
+    gl::FALSE => Ok(false),
+    _ => Err(StateQueryError::UnknownRasterizerDiscardState(state)),
+  }
+}
+
+unsafe fn get_ctx_polygon_offset() -> Result<Option<PolygonOffset>, StateQueryError> {
+  let state = gl::IsEnabled(gl::POLYGON_OFFSET_FILL);
+
+  match state {
+    gl::TRUE => {
+      let mut factor = 0.;
+      let mut units = 0.;
+      gl::GetFloatv(gl::POLYGON_OFFSET_FACTOR, &mut factor);
+      gl::GetFloatv(gl::POLYGON_OFFSET_UNITS, &mut units);
+
+      Ok(Some(PolygonOffset::new(factor, units)))
+    }
+    gl::FALSE => Ok(None),
+    _ => Err(StateQueryError::UnknownPolygonOffsetState(state)),
+  }
+}
+
+/// Read the render state currently set in the OpenGL context, bypassing the cache.
+///
+/// Only the blending, color mask, depth test, face culling and scissor configurations are read
+/// back; the other aspects are left to their default values.
+pub(crate) unsafe fn get_ctx_render_state() -> Result<RenderState, StateQueryError> {
+  let mut rdr_st = RenderState::default()
+    .set_color_mask(get_ctx_color_mask()?)
+    .set_depth_write(get_ctx_depth_write()?);
+
+  if get_ctx_blending_state()? == BlendingState::On {
+    let equations = get_ctx_blending_equations()?;
+    let factors = get_ctx_blending_factors()?;
+    let rgb = Blending::new(equations.rgb, factors.src_rgb, factors.dst_rgb);
+    let alpha = Blending::new(equations.alpha, factors.src_alpha, factors.dst_alpha);
+
+    rdr_st = if rgb == alpha {
+      rdr_st.set_blending(rgb)
+    } else {
+      rdr_st.set_blending_separate(rgb, alpha)
+    };
+  }
+
+  rdr_st = match get_ctx_depth_test()? {
+    DepthTest::On => rdr_st.set_depth_test(get_ctx_depth_test_comparison()?),
+    DepthTest::Off => rdr_st.set_depth_test(None),
+  };
+
+  if get_ctx_face_culling_state()? == FaceCullingState::On {
+    rdr_st = rdr_st.set_face_culling(FaceCulling::new(
+      get_ctx_face_culling_order()?,
+      get_ctx_face_culling_mode()?,
+    ));
+  }
+
+  if get_ctx_scissor_state()? == ScissorState::On {
+    rdr_st = rdr_st.set_scissor(get_ctx_scissor_region()?);
+  }
+
+  Ok(rdr_st)
+}
+
 /// Whether or not enable blending.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum BlendingState {
@@ -1039,6 +1911,15 @@ pub(crate) enum DepthTest {
   Off,
 }
 
+/// Should the stencil test be enabled?
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum StencilTestState {
+  /// Enable the stencil test.
+  On,
+  /// Disable the stencil test.
+  Off,
+}
+
 /// Should face culling be enabled?
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum FaceCullingState {
@@ -1047,3 +1928,12 @@ pub(crate) enum FaceCullingState {
   /// Disable face culling.
   Off,
 }
+
+/// Should the scissor test be enabled?
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ScissorState {
+  /// Enable the scissor test.
+  On,
+  /// Disable the scissor test.
+  Off,
+}
@@ -15,6 +15,7 @@ use luminance::backend::tess::{
   IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
   VertexSlice as VertexSliceBackend,
 };
+use luminance::buffer::BufferUsage;
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
   TessMapError, TessVertexData,
@@ -60,12 +61,30 @@ where
     let inst_nb = inst_nb as GLsizei;
 
     let mut gfx_st = self.state.borrow_mut();
-    gfx_st.bind_vertex_array(self.vao, Bind::Cached);
 
     if self.mode == gl::PATCHES {
+      // patches can only be consumed by tessellation stages
+      if !gfx_st.is_current_program_tessellated() {
+        return Err(TessError::missing_tessellation_stages());
+      }
+
       gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+    } else if self.mode == gl::POINTS {
+      // let vertex shaders set the size of points via gl_PointSize; point sprites (and then
+      // gl_PointCoord) are always enabled in core profile
+      gfx_st.enable_program_point_size();
     }
 
+    // instanced draws are not available on contexts older than OpenGL 3.1; render nothing rather
+    // than calling a null function pointer
+    if inst_nb > 1
+      && !(gl::DrawArraysInstanced::is_loaded() && gl::DrawElementsInstanced::is_loaded())
+    {
+      return Err(TessError::unsupported_instancing());
+    }
+
+    gfx_st.bind_vertex_array(self.vao, Bind::Cached);
+
     match (I::INDEX_TYPE, self.index_state.as_ref()) {
       (Some(index_ty), Some(index_state)) => {
         // indexed render
@@ -477,7 +496,7 @@ where
       let vb = if vertices.is_empty() {
         None
       } else {
-        let vb = unsafe { gl33.from_vec(vertices)? };
+        let vb = unsafe { gl33.from_vec(vertices, BufferUsage::default())? };
 
         // force binding as it’s meaningful when a vao is bound
         unsafe {
@@ -511,7 +530,7 @@ where
         .into_iter()
         .zip(V::vertex_desc())
         .map(|(attribute, fmt)| {
-          let vb = unsafe { gl33.from_vec(attribute.into_vec())? };
+          let vb = unsafe { gl33.from_vec(attribute.into_vec(), BufferUsage::default())? };
 
           // force binding as it’s meaningful when a vao is bound
           unsafe {
@@ -542,7 +561,7 @@ where
 {
   let ids = if !data.is_empty() {
     let ib = IndexedDrawState {
-      buffer: unsafe { gl33.from_vec(data)? },
+      buffer: unsafe { gl33.from_vec(data, BufferUsage::default())? },
       restart_index,
     };
 
@@ -20,12 +20,17 @@ pub struct Texture {
   pub(crate) handle: GLuint, // handle to the GPU texture object
   pub(crate) target: GLenum, // “type” of the texture; used for bindings
   mipmaps: usize,
+  global_seamless: bool, // whether the texture relies on the global seamless cubemap switch
   state: Rc<RefCell<GLState>>,
 }
 
 impl Drop for Texture {
   fn drop(&mut self) {
     unsafe {
+      if self.global_seamless {
+        self.state.borrow_mut().release_seamless_cubemap();
+      }
+
       gl::DeleteTextures(1, &self.handle);
     }
   }
@@ -56,10 +61,14 @@ where
 
     create_texture::<D>(target, size, mipmaps, P::pixel_format(), sampler)?;
 
+    let global_seamless = target == gl::TEXTURE_CUBE_MAP
+      && state.set_seamless_cubemap(target, sampler.seamless_cubemap);
+
     let texture = Texture {
       handle,
       target,
       mipmaps,
+      global_seamless,
       state: self.state.clone(),
     };
 
@@ -70,6 +79,37 @@ where
     texture.mipmaps
   }
 
+  unsafe fn resize(texture: &mut Self::TextureRepr, size: D::Size) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, texture.handle);
+
+    // re-specifying the storage keeps the same texture object, so that it remains attached
+    set_texture_levels(texture.target, texture.mipmaps);
+    create_texture_storage::<D>(size, texture.mipmaps, P::pixel_format())?;
+
+    gfx_state.bind_texture(texture.target, 0);
+
+    Ok(())
+  }
+
+  unsafe fn set_mipmap_levels(
+    texture: &mut Self::TextureRepr,
+    base_level: usize,
+    max_level: usize,
+  ) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, texture.handle);
+
+    gl::TexParameteri(texture.target, gl::TEXTURE_BASE_LEVEL, base_level as GLint);
+    gl::TexParameteri(texture.target, gl::TEXTURE_MAX_LEVEL, max_level as GLint);
+
+    gfx_state.bind_texture(texture.target, 0);
+
+    Ok(())
+  }
+
   unsafe fn clear_part(
     texture: &mut Self::TextureRepr,
     gen_mipmaps: GenMipmaps,
@@ -163,6 +203,43 @@ where
     )
   }
 
+  unsafe fn copy_part(
+    src: &Self::TextureRepr,
+    src_level: usize,
+    src_offset: D::Offset,
+    dst: &mut Self::TextureRepr,
+    dst_level: usize,
+    dst_offset: D::Offset,
+    size: D::Size,
+  ) -> Result<(), TextureError> {
+    // glCopyImageSubData is only available from OpenGL 4.3 on, or with GL_ARB_copy_image
+    if !gl::CopyImageSubData::is_loaded() {
+      return Err(TextureError::cannot_copy_texels(
+        "glCopyImageSubData is not available",
+      ));
+    }
+
+    gl::CopyImageSubData(
+      src.handle,
+      src.target,
+      src_level as GLint,
+      D::x_offset(src_offset) as GLint,
+      D::y_offset(src_offset) as GLint,
+      D::z_offset(src_offset) as GLint,
+      dst.handle,
+      dst.target,
+      dst_level as GLint,
+      D::x_offset(dst_offset) as GLint,
+      D::y_offset(dst_offset) as GLint,
+      D::z_offset(dst_offset) as GLint,
+      D::width(size) as GLsizei,
+      D::height(size) as GLsizei,
+      D::depth(size) as GLsizei,
+    );
+
+    Ok(())
+  }
+
   unsafe fn get_raw_texels(
     texture: &Self::TextureRepr,
     _: D::Size,
@@ -183,8 +260,10 @@ where
     gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_WIDTH, &mut w);
     gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_HEIGHT, &mut h);
 
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.size() * w as usize) % 8;
+    // set the packing alignment based on the number of bytes to skip; texels are retrieved with
+    // their raw encoding, which can be bigger than the size of the pixel format
+    let pf_size = pf.canals_len() * mem::size_of::<P::RawEncoding>();
+    let skip_bytes = (pf_size * w as usize) % 8;
     set_pack_alignment(skip_bytes);
 
     // resize the vec to allocate enough space to host the returned texels
@@ -282,6 +361,10 @@ fn apply_sampler_to_texture(target: GLenum, sampler: Sampler) {
         gl::TexParameteri(target, gl::TEXTURE_COMPARE_MODE, gl::NONE as GLint);
       }
     }
+
+    gl::TexParameterf(target, gl::TEXTURE_MIN_LOD, sampler.min_lod);
+    gl::TexParameterf(target, gl::TEXTURE_MAX_LOD, sampler.max_lod);
+    gl::TexParameterf(target, gl::TEXTURE_LOD_BIAS, sampler.lod_bias);
   }
 }
 
@@ -523,8 +606,8 @@ fn create_cubemap_storage(
 fn set_unpack_alignment(skip_bytes: usize) {
   let unpack_alignment = match skip_bytes {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
   };
 
@@ -535,8 +618,8 @@ fn set_unpack_alignment(skip_bytes: usize) {
 fn set_pack_alignment(skip_bytes: usize) {
   let pack_alignment = match skip_bytes {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
   };
 
@@ -557,7 +640,9 @@ where
   // number of bytes in the input texels argument
   let input_bytes = texels.len() * mem::size_of::<T>();
   let pf = P::pixel_format();
-  let pf_size = pf.format.size();
+  // size of a pixel as sent by the client, which can be bigger than the size of the pixel format
+  // — e.g. 16-bit floating formats are sent as 32-bit floating values
+  let pf_size = pf.canals_len() * mem::size_of::<P::RawEncoding>();
   let expected_bytes = D::count(size) * pf_size;
 
   if input_bytes < expected_bytes {
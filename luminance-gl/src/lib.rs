@@ -2,4 +2,4 @@
 
 pub mod gl33;
 
-pub use gl33::GL33;
+pub use gl33::{load_with, GL33};
@@ -3,7 +3,6 @@
 
 #![deny(missing_docs)]
 
-use gl;
 use glfw::{self, Context, CursorMode as GlfwCursorMode, SwapInterval, Window, WindowMode};
 use glfw::{InitError, WindowEvent};
 use luminance::context::GraphicsContext;
@@ -97,6 +96,7 @@ impl GlfwSurface {
     glfw.window_hint(glfw::WindowHint::ContextVersionMajor(3));
     glfw.window_hint(glfw::WindowHint::ContextVersionMinor(3));
     glfw.window_hint(glfw::WindowHint::Samples(*win_opt.num_samples()));
+    glfw.window_hint(glfw::WindowHint::OpenGlDebugContext(win_opt.debug()));
 
     // open a window in windowed or fullscreen mode
     let title = title.as_ref();
@@ -143,9 +143,14 @@ impl GlfwSurface {
     glfw.set_swap_interval(SwapInterval::Sync(1));
 
     // init OpenGL
-    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+    luminance_gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+
+    let mut gl = GL33::new().map_err(GlfwSurfaceError::GraphicsStateError)?;
+
+    if win_opt.debug() && !gl.enable_debug_output() {
+      log::warn!("OpenGL debug output is not supported; debug messages won’t be logged");
+    }
 
-    let gl = GL33::new().map_err(GlfwSurfaceError::GraphicsStateError)?;
     let surface = GlfwSurface {
       window,
       events_rx,
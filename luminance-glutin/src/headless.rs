@@ -0,0 +1,115 @@
+//! Headless (offscreen) OpenGL contexts.
+//!
+//! A headless context doesn’t have any window attached and hence no back buffer you can present.
+//! Instead, you render into a [`Framebuffer`] created with the context and read back its texels,
+//! which is handy to run rendering tests on machines without a display.
+//!
+//! This module is only available when the `headless` feature is enabled.
+//!
+//! [`Framebuffer`]: luminance::framebuffer::Framebuffer
+
+use glutin::{
+  dpi::PhysicalSize, event_loop::EventLoop, Api, Context, ContextBuilder, GlProfile, GlRequest,
+  PossiblyCurrent,
+};
+use luminance::context::GraphicsContext;
+use luminance_gl::GL33;
+use std::os::raw::c_void;
+
+use crate::{enable_debug_output, GlutinError};
+
+/// An offscreen Glutin surface.
+///
+/// Such a surface has no default framebuffer you can render to: you have to create your own
+/// [`Framebuffer`] and read back its color and depth slots.
+pub struct GlutinOffscreen {
+  /// The headless context.
+  pub ctx: Context<PossiblyCurrent>,
+  /// The event loop the context was created with.
+  ///
+  /// It must outlive the context on some platforms, so it’s kept around.
+  _event_loop: EventLoop<()>,
+  /// Size of the headless surface.
+  size: [u32; 2],
+  /// OpenGL 3.3 state.
+  gl: GL33,
+}
+
+unsafe impl GraphicsContext for GlutinOffscreen {
+  type Backend = GL33;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.gl
+  }
+}
+
+impl GlutinOffscreen {
+  /// Create a new [`GlutinOffscreen`] with the given `size`.
+  ///
+  /// Depending on the platform, the context is backed by an EGL pbuffer / surfaceless context or
+  /// by a hidden platform surface. In debug builds, a debug context is requested and its messages
+  /// are routed to the log crate.
+  pub fn new_gl33(size: [u32; 2]) -> Result<Self, GlutinError> {
+    let event_loop = new_event_loop();
+
+    let ctx = ContextBuilder::new()
+      .with_gl(GlRequest::Specific(Api::OpenGl, (3, 3)))
+      .with_gl_profile(GlProfile::Core)
+      .with_gl_debug_flag(cfg!(debug_assertions))
+      .build_headless(&event_loop, PhysicalSize::new(size[0], size[1]))?;
+
+    let ctx = unsafe { ctx.make_current().map_err(|(_, e)| e)? };
+
+    // init OpenGL
+    luminance_gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+
+    let mut gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    enable_debug_output(&mut gl);
+
+    Ok(GlutinOffscreen {
+      ctx,
+      _event_loop: event_loop,
+      size,
+      gl,
+    })
+  }
+
+  /// Get the size of the offscreen surface.
+  pub fn size(&self) -> [u32; 2] {
+    self.size
+  }
+}
+
+/// Create the event loop the headless context is built with.
+///
+/// Tests are run on their own threads, so the event loop is allowed to be created outside of the
+/// main thread where the platform supports it.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+fn new_event_loop() -> EventLoop<()> {
+  use glutin::platform::unix::EventLoopExtUnix;
+  EventLoop::new_any_thread()
+}
+
+#[cfg(target_os = "windows")]
+fn new_event_loop() -> EventLoop<()> {
+  use glutin::platform::windows::EventLoopExtWindows;
+  EventLoop::new_any_thread()
+}
+
+#[cfg(not(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  target_os = "windows"
+)))]
+fn new_event_loop() -> EventLoop<()> {
+  EventLoop::new()
+}
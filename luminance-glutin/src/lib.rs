@@ -2,10 +2,21 @@
 //!
 //! [glutin]: https://crates.io/crates/glutin
 //! [luminance-windowing]: https://crates.io/crates/luminance-windowing
+//!
+//! # Feature flags
+//!
+//! - `headless`: add [`GlutinOffscreen`], a surface without window, rendering offscreen. Useful
+//!   to run rendering tests without a display.
+//!
+//! # Debug contexts
+//!
+//! Glutin requests debug contexts in debug builds by default; use
+//! [`ContextBuilder::with_gl_debug_flag`] with [`GlutinSurface::new_gl33_from_builders`] to change
+//! that. The messages of debug contexts are routed to the [log](https://crates.io/crates/log)
+//! crate.
 
 #![deny(missing_docs)]
 
-use gl;
 use glutin::{
   event_loop::EventLoop, window::WindowBuilder, Api, ContextBuilder, ContextError, CreationError,
   GlProfile, GlRequest, NotCurrent, PossiblyCurrent, WindowedContext,
@@ -19,6 +30,12 @@ use std::error;
 use std::fmt;
 use std::os::raw::c_void;
 
+#[cfg(feature = "headless")]
+mod headless;
+
+#[cfg(feature = "headless")]
+pub use headless::GlutinOffscreen;
+
 /// Error that might occur when creating a Glutin surface.
 #[derive(Debug)]
 pub enum GlutinError {
@@ -98,7 +115,8 @@ impl GlutinSurface {
   /// whole `glutin` types.
   ///
   /// `window_builder` is the default object when passed to your closure and `ctx_builder` is
-  /// already initialized for the OpenGL context (you’re not supposed to change it!).
+  /// already initialized for the OpenGL context (you’re not supposed to change it, besides
+  /// options such as [`ContextBuilder::with_gl_debug_flag`]!).
   pub fn new_gl33_from_builders<'a, WB, CB>(
     window_builder: WB,
     ctx_builder: CB,
@@ -123,11 +141,13 @@ impl GlutinSurface {
     let ctx = unsafe { windowed_ctx.make_current().map_err(|(_, e)| e)? };
 
     // init OpenGL
-    gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+    luminance_gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
 
     ctx.window().set_visible(true);
 
-    let gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    let mut gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    enable_debug_output(&mut gl);
+
     let surface = GlutinSurface { ctx, gl };
 
     Ok((surface, event_loop))
@@ -145,16 +165,19 @@ impl GlutinSurface {
       .with_gl_profile(GlProfile::Core)
       .with_multisampling(samples)
       .with_double_buffer(Some(true))
+      .with_gl_debug_flag(cfg!(debug_assertions))
       .build_windowed(window_builder, &event_loop)?;
 
     let ctx = unsafe { windowed_ctx.make_current().map_err(|(_, e)| e)? };
 
     // init OpenGL
-    gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+    luminance_gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
 
     ctx.window().set_visible(true);
 
-    let gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    let mut gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    enable_debug_output(&mut gl);
+
     let surface = GlutinSurface { ctx, gl };
 
     Ok((surface, event_loop))
@@ -179,3 +202,10 @@ impl GlutinSurface {
     let _ = self.ctx.swap_buffers();
   }
 }
+
+/// Route the debug messages of debug contexts to the log crate.
+pub(crate) fn enable_debug_output(gl: &mut GL33) {
+  if gl.is_debug_context() && !gl.enable_debug_output() {
+    log::warn!("OpenGL debug output is not supported; debug messages won’t be logged");
+  }
+}
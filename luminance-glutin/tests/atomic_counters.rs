@@ -0,0 +1,81 @@
+//! Atomic counters tests, run with `cargo test --features headless`.
+
+#![cfg(feature = "headless")]
+
+mod common;
+
+use luminance::context::GraphicsContext as _;
+use luminance::pipeline::PipelineState;
+use luminance::pixel::RGBA32F;
+use luminance::render_state::RenderState;
+use luminance::tess::Mode;
+use luminance::texture::{Dim2, Sampler};
+
+const FS: &str = "#version 330 core
+#extension GL_ARB_shader_atomic_counters : require
+
+layout (binding = 0) uniform atomic_uint count;
+
+out vec4 frag;
+
+void main() {
+  atomicCounterIncrement(count);
+  frag = vec4(1.);
+}";
+
+#[test]
+fn atomic_counters_read_back() {
+  let mut surface = match common::offscreen([4, 4]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let mut program = match surface.new_shader_program::<(), (), ()>().from_strings(
+    common::FULLSCREEN_VS,
+    None,
+    None,
+    FS,
+  ) {
+    Ok(program) => program.ignore_warnings(),
+    Err(e) => {
+      eprintln!("skipping test, atomic counters not supported: {}", e);
+      return;
+    }
+  };
+
+  let tess = surface
+    .new_tess()
+    .set_vertex_nb(3)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+  let framebuffer = surface
+    .new_framebuffer::<Dim2, RGBA32F, ()>([4, 4], 0, Sampler::default())
+    .unwrap();
+  let mut counters = surface.new_buffer_from_vec(vec![0u32]).unwrap();
+
+  let mut values = Vec::new();
+  surface
+    .new_pipeline_gate()
+    .pipeline(
+      &framebuffer,
+      &PipelineState::default(),
+      |pipeline, mut shd_gate| {
+        let mut bound_counters = pipeline
+          .bind_atomic_counter_buffer(&mut counters, 0)
+          .unwrap();
+
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(&tess).unwrap();
+          });
+        });
+
+        values = bound_counters.counters().unwrap();
+      },
+    )
+    .unwrap();
+
+  // every fragment of the 4×4 framebuffer increments the counter once
+  assert_eq!(values, vec![16]);
+}
@@ -0,0 +1,164 @@
+//! Blending tests, run with `cargo test --features headless`.
+
+#![cfg(feature = "headless")]
+
+mod common;
+
+use gl::types::*;
+use luminance::blending::{Blending, Equation, Factor};
+use luminance::context::GraphicsContext as _;
+use luminance::pipeline::PipelineState;
+use luminance::pixel::RGBA32F;
+use luminance::render_state::RenderState;
+use luminance::tess::Mode;
+use luminance::texture::{Dim2, Sampler};
+use luminance_glutin::GlutinOffscreen;
+
+const FS: &str = "#version 330 core
+
+layout (location = 0) out vec4 frag0;
+layout (location = 1) out vec4 frag1;
+
+void main() {
+  frag0 = vec4(1.);
+  frag1 = vec4(1.);
+}";
+
+const MULTIPLY_FS: &str = "#version 330 core
+#extension GL_KHR_blend_equation_advanced : enable
+
+#ifdef GL_KHR_blend_equation_advanced
+layout (blend_support_multiply) out;
+#endif
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(.5, .5, .5, 1.);
+}";
+
+/// Get the source and destination RGB blending factors of a draw buffer.
+fn draw_buffer_blending_funcs(draw_buffer: GLuint) -> (GLint, GLint) {
+  let mut src = 0;
+  let mut dst = 0;
+
+  unsafe {
+    gl::GetIntegeri_v(gl::BLEND_SRC_RGB, draw_buffer, &mut src);
+    gl::GetIntegeri_v(gl::BLEND_DST_RGB, draw_buffer, &mut dst);
+  }
+
+  (src, dst)
+}
+
+#[test]
+fn global_blending_resets_indexed_blending() {
+  let mut surface = match common::offscreen([4, 4]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  if !gl::BlendFunci::is_loaded() {
+    eprintln!("skipping test, indexed blending not supported");
+    return;
+  }
+
+  let mut program = surface
+    .new_shader_program::<(), (), ()>()
+    .from_strings(common::FULLSCREEN_VS, None, None, FS)
+    .unwrap()
+    .ignore_warnings();
+  let tess = surface
+    .new_tess()
+    .set_vertex_nb(3)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+  let framebuffer = surface
+    .new_framebuffer::<Dim2, (RGBA32F, RGBA32F), ()>([4, 4], 0, Sampler::default())
+    .unwrap();
+
+  let rdr_st = RenderState::default().set_blending(Blending::alpha());
+  let mut render = |surface: &mut GlutinOffscreen| {
+    surface
+      .new_pipeline_gate()
+      .pipeline(
+        &framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+            rdr_gate.render_tess(&rdr_st, &tess).unwrap();
+          });
+        },
+      )
+      .unwrap();
+  };
+
+  render(&mut surface);
+
+  // external code sets the blending of the second draw buffer only
+  surface.backend().interop(|| unsafe {
+    gl::BlendFunci(1, gl::ONE, gl::ONE);
+  });
+  assert_eq!(
+    draw_buffer_blending_funcs(1),
+    (gl::ONE as GLint, gl::ONE as GLint)
+  );
+
+  // rendering with global blending again must reset the blending of every draw buffer
+  render(&mut surface);
+
+  let expected = (gl::SRC_ALPHA as GLint, gl::ONE_MINUS_SRC_ALPHA as GLint);
+  assert_eq!(draw_buffer_blending_funcs(0), expected);
+  assert_eq!(draw_buffer_blending_funcs(1), expected);
+}
+
+#[test]
+fn advanced_blending_or_fallback() {
+  let mut surface = match common::offscreen([4, 4]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let mut program = surface
+    .new_shader_program::<(), (), ()>()
+    .from_strings(common::FULLSCREEN_VS, None, None, MULTIPLY_FS)
+    .unwrap()
+    .ignore_warnings();
+  let tess = surface
+    .new_tess()
+    .set_vertex_nb(3)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+  let mut framebuffer = surface
+    .new_framebuffer::<Dim2, RGBA32F, ()>([4, 4], 0, Sampler::default())
+    .unwrap();
+
+  // the factors are only used by the fallback, which then outputs the source color
+  let blending = Blending::new(Equation::Multiply, Factor::One, Factor::Zero);
+  let rdr_st = RenderState::default().set_blending(blending);
+
+  surface
+    .new_pipeline_gate()
+    .pipeline(
+      &framebuffer,
+      &PipelineState::default().set_clear_color([0.5, 0.5, 0.5, 1.]),
+      |_, mut shd_gate| {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render_tess(&rdr_st, &tess).unwrap();
+        });
+      },
+    )
+    .unwrap();
+
+  let expected = if common::has_extension("GL_KHR_blend_equation_advanced") {
+    0.25
+  } else {
+    0.5
+  };
+  let texels = framebuffer.color_slot().get_raw_texels().unwrap();
+
+  for texel in texels.chunks(4) {
+    assert!((texel[0] - expected).abs() < 1e-3, "{:?}", texel);
+  }
+}
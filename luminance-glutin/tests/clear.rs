@@ -0,0 +1,35 @@
+//! Clear tests, run with `cargo test --features headless`.
+
+#![cfg(feature = "headless")]
+
+mod common;
+
+use luminance::context::GraphicsContext as _;
+use luminance::pipeline::PipelineState;
+use luminance::pixel::R32UI;
+use luminance::texture::{Dim2, Sampler};
+
+#[test]
+fn clear_unsigned_attachment_exactly() {
+  let mut surface = match common::offscreen([4, 4]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let mut framebuffer = surface
+    .new_framebuffer::<Dim2, R32UI, ()>([4, 4], 0, Sampler::default())
+    .unwrap();
+
+  // a picking sentinel that a f32 cannot represent
+  surface
+    .new_pipeline_gate()
+    .pipeline(
+      &framebuffer,
+      &PipelineState::default().set_clear_color([u32::MAX - 1, 0, 0, 0]),
+      |_, _| {},
+    )
+    .unwrap();
+
+  let texels = framebuffer.color_slot().get_raw_texels().unwrap();
+  assert!(texels.iter().all(|&texel| texel == u32::MAX - 1));
+}
@@ -0,0 +1,46 @@
+//! Helpers for the rendering tests requiring an OpenGL context.
+//!
+//! When no headless context can be created (e.g. no GPU / driver available), the tests are skipped.
+
+// each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use gl::types::*;
+use luminance_glutin::GlutinOffscreen;
+use std::ffi::CStr;
+use std::panic;
+
+/// Vertex shader rendering a full-screen triangle without any vertex attribute.
+pub const FULLSCREEN_VS: &str = "#version 330 core
+
+void main() {
+  vec2 p = vec2(float((gl_VertexID << 1) & 2), float(gl_VertexID & 2)) * 2. - 1.;
+  gl_Position = vec4(p, 0., 1.);
+}";
+
+/// Create a headless context, or return [`None`] if the platform cannot provide one.
+pub fn offscreen(size: [u32; 2]) -> Option<GlutinOffscreen> {
+  // winit panics instead of failing when no display server is available
+  match panic::catch_unwind(|| GlutinOffscreen::new_gl33(size)) {
+    Ok(Ok(surface)) => Some(surface),
+    Ok(Err(e)) => {
+      eprintln!("skipping test, cannot create a headless context: {}", e);
+      None
+    }
+    Err(_) => {
+      eprintln!("skipping test, no display available");
+      None
+    }
+  }
+}
+
+/// Check whether an OpenGL extension is supported by the current context.
+pub fn has_extension(name: &str) -> bool {
+  let mut count = 0;
+  unsafe { gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count) };
+
+  (0..count as GLuint).any(|i| {
+    let ext = unsafe { CStr::from_ptr(gl::GetStringi(gl::EXTENSIONS, i) as *const _) };
+    ext.to_bytes() == name.as_bytes()
+  })
+}
@@ -0,0 +1,75 @@
+//! Render state tests, run with `cargo test --features headless`.
+
+#![cfg(feature = "headless")]
+
+mod common;
+
+use luminance::context::GraphicsContext as _;
+use luminance::depth_test::DepthWrite;
+use luminance::pipeline::PipelineState;
+use luminance::pixel::{Depth32F, RGBA32F};
+use luminance::render_state::RenderState;
+use luminance::tess::Mode;
+use luminance::texture::{Dim2, Sampler};
+
+const FS: &str = "#version 330 core
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.);
+}";
+
+#[test]
+fn depth_bounds_test() {
+  let mut surface = match common::offscreen([4, 4]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let mut program = surface
+    .new_shader_program::<(), (), ()>()
+    .from_strings(common::FULLSCREEN_VS, None, None, FS)
+    .unwrap()
+    .ignore_warnings();
+  let tess = surface
+    .new_tess()
+    .set_vertex_nb(3)
+    .set_mode(Mode::Triangle)
+    .build()
+    .unwrap();
+  let mut framebuffer = surface
+    .new_framebuffer::<Dim2, RGBA32F, Depth32F>([4, 4], 0, Sampler::default())
+    .unwrap();
+
+  // the depth buffer is cleared to 1, which is outside of the bounds
+  let rdr_st = RenderState::default()
+    .set_depth_test(None)
+    .set_depth_write(DepthWrite::Off)
+    .set_depth_bounds((0., 0.5));
+
+  surface
+    .new_pipeline_gate()
+    .pipeline(
+      &framebuffer,
+      &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+      |_, mut shd_gate| {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render_tess(&rdr_st, &tess).unwrap();
+        });
+      },
+    )
+    .unwrap();
+
+  // without the extension, the depth bounds test is ignored and the fragments are written
+  let expected = if common::has_extension("GL_EXT_depth_bounds_test") {
+    0.
+  } else {
+    1.
+  };
+  let texels = framebuffer.color_slot().get_raw_texels().unwrap();
+
+  for texel in texels.chunks(4) {
+    assert_eq!(texel[0], expected, "{:?}", texel);
+  }
+}
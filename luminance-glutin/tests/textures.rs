@@ -0,0 +1,43 @@
+//! Texture tests, run with `cargo test --features headless`.
+
+#![cfg(feature = "headless")]
+
+mod common;
+
+use luminance::context::GraphicsContext as _;
+use luminance::pixel::{Pixel as _, Stencil8, RGBA8UI};
+use luminance::texture::{Dim2, Sampler, TextureError};
+
+#[test]
+fn stencil_texture_rejected() {
+  let mut surface = match common::offscreen([16, 16]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let texture = surface.new_texture::<Dim2, Stencil8>([16, 16], 0, Sampler::default());
+
+  match texture {
+    Err(TextureError::UnsupportedPixelFormat(pf)) => assert_eq!(pf, Stencil8::pixel_format()),
+    Err(e) => panic!("unexpected error: {}", e),
+    Ok(_) => panic!("stencil texture created"),
+  }
+}
+
+#[test]
+fn atlas_uv_overflow() {
+  let mut surface = match common::offscreen([16, 16]) {
+    Some(surface) => surface,
+    None => return,
+  };
+
+  let texture = surface
+    .new_texture::<Dim2, RGBA8UI>([16, 16], 0, Sampler::default())
+    .unwrap();
+
+  assert_eq!(
+    texture.atlas_uv([4, 8], [4, 8], false),
+    Some([0.25, 0.5, 0.5, 1.])
+  );
+  assert_eq!(texture.atlas_uv([u32::MAX, 0], [1, 1], false), None);
+}
@@ -75,7 +75,9 @@ impl GL33Surface {
   ///
   /// The callback is passed a reference to [`sdl2::VideoSubsystem`].
   /// This is your chance to change GL attributes before creating the window with your preferred
-  /// settings.
+  /// settings. Request a debug context with
+  /// `gl_attr.set_context_flags().forward_compatible().debug().set()` to have its messages routed
+  /// to the log crate.
   ///
   /// ```ignore
   /// use luminance_sdl2::GL33Surface;
@@ -115,9 +117,15 @@ impl GL33Surface {
       .gl_create_context()
       .map_err(Sdl2SurfaceError::GlContextInitFailed)?;
 
-    gl::load_with(|s| video_system.gl_get_proc_address(s) as *const c_void);
+    luminance_gl::load_with(|s| video_system.gl_get_proc_address(s) as *const c_void);
+
+    let mut gl = GL33::new().map_err(Sdl2SurfaceError::GraphicsStateError)?;
+
+    // route the messages of debug contexts to the log crate
+    if gl.is_debug_context() && !gl.enable_debug_output() {
+      log::warn!("OpenGL debug output is not supported; debug messages won’t be logged");
+    }
 
-    let gl = GL33::new().map_err(Sdl2SurfaceError::GraphicsStateError)?;
     let surface = GL33Surface {
       sdl,
       window,
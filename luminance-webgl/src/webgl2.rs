@@ -1,9 +1,11 @@
 //! WebGL 2.0 backend support.
 mod array_buffer;
 pub mod buffer;
+mod capabilities;
 pub mod framebuffer;
 pub mod pipeline;
 pub mod pixel;
+pub mod renderbuffer;
 pub mod shader;
 pub(crate) mod state;
 pub mod tess;
@@ -27,4 +29,14 @@ impl WebGL2 {
       state: Rc::new(RefCell::new(state)),
     })
   }
+
+  /// Check whether the WebGL context was lost.
+  ///
+  /// Browsers can lose the context at any time (GPU reset, too many contexts, tab backgrounded on
+  /// mobile, etc.). All the resources created with it are then dead: they must be dropped and
+  /// created again once the canvas emits the `webglcontextrestored` event. Call it once per frame,
+  /// for instance before starting a pipeline gate.
+  pub fn is_context_lost(&self) -> bool {
+    self.state.borrow().ctx.is_context_lost()
+  }
 }
@@ -12,7 +12,7 @@ use web_sys::{WebGl2RenderingContext, WebGlBuffer};
 use crate::webgl2::state::{Bind, WebGL2State};
 use crate::webgl2::WebGL2;
 use luminance::backend::buffer::{Buffer as BufferBackend, BufferSlice as BufferSliceBackend};
-use luminance::buffer::BufferError;
+use luminance::buffer::{BufferError, BufferUsage};
 
 /// Wrapped WebGL buffer.
 ///
@@ -46,7 +46,13 @@ impl<T> Buffer<T> {
   /// The `target` parameter allows to create the buffer with
   /// [`WebGl2RenderingContext::ARRAY_BUFFER`] or [`WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER`]
   /// directly, as WebGL2 doesn’t support changing the target type after the buffer is created.
-  fn new(webgl2: &mut WebGL2, len: usize, clear_value: T, target: u32) -> Result<Self, BufferError>
+  fn new(
+    webgl2: &mut WebGL2,
+    len: usize,
+    clear_value: T,
+    target: u32,
+    usage: BufferUsage,
+  ) -> Result<Self, BufferError>
   where
     T: Copy,
   {
@@ -66,7 +72,7 @@ impl<T> Buffer<T> {
     let bytes = mem::size_of::<T>() * len;
     state
       .ctx
-      .buffer_data_with_i32(target, bytes as i32, WebGl2RenderingContext::STREAM_DRAW);
+      .buffer_data_with_i32(target, bytes as i32, webgl_buffer_usage(usage));
 
     let gl_buf = BufferWrapper {
       handle,
@@ -80,6 +86,7 @@ impl<T> Buffer<T> {
     webgl2: &mut WebGL2,
     vec: Vec<T>,
     target: u32,
+    usage: BufferUsage,
   ) -> Result<Self, BufferError> {
     let mut state = webgl2.state.borrow_mut();
     let len = vec.len();
@@ -94,7 +101,7 @@ impl<T> Buffer<T> {
     let data = unsafe { slice::from_raw_parts(vec.as_ptr() as *const _, bytes) };
     state
       .ctx
-      .buffer_data_with_u8_array(target, data, WebGl2RenderingContext::STREAM_DRAW);
+      .buffer_data_with_u8_array(target, data, webgl_buffer_usage(usage));
 
     let gl_buf = BufferWrapper {
       handle,
@@ -144,7 +151,11 @@ where
 {
   type BufferRepr = Buffer<T>;
 
-  unsafe fn new_buffer(&mut self, len: usize) -> Result<Self::BufferRepr, BufferError>
+  unsafe fn new_buffer(
+    &mut self,
+    len: usize,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>
   where
     T: Default,
   {
@@ -153,6 +164,7 @@ where
       len,
       T::default(),
       WebGl2RenderingContext::ARRAY_BUFFER,
+      usage,
     )
   }
 
@@ -160,12 +172,27 @@ where
     buffer.buf.len()
   }
 
-  unsafe fn from_vec(&mut self, vec: Vec<T>) -> Result<Self::BufferRepr, BufferError> {
-    Buffer::from_vec(self, vec, WebGl2RenderingContext::ARRAY_BUFFER)
+  unsafe fn from_vec(
+    &mut self,
+    vec: Vec<T>,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError> {
+    Buffer::from_vec(self, vec, WebGl2RenderingContext::ARRAY_BUFFER, usage)
   }
 
-  unsafe fn repeat(&mut self, len: usize, value: T) -> Result<Self::BufferRepr, BufferError> {
-    Buffer::<T>::new(self, len, value, WebGl2RenderingContext::ARRAY_BUFFER)
+  unsafe fn repeat(
+    &mut self,
+    len: usize,
+    value: T,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError> {
+    Buffer::<T>::new(
+      self,
+      len,
+      value,
+      WebGl2RenderingContext::ARRAY_BUFFER,
+      usage,
+    )
   }
 
   unsafe fn at(buffer: &Self::BufferRepr, i: usize) -> Option<T> {
@@ -232,20 +259,63 @@ where
     Ok(())
   }
 
+  unsafe fn write_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    values: &[T],
+  ) -> Result<(), BufferError> {
+    let len = values.len();
+    let buffer_len = buffer.buf.len();
+
+    if offset > buffer_len || len > buffer_len - offset {
+      return Err(BufferError::out_of_bounds(offset, len, buffer_len));
+    }
+
+    buffer.buf[offset..offset + len].copy_from_slice(values);
+
+    // update only the written region on GPU
+    let mut state = buffer.gl_buf.state.borrow_mut();
+    update_webgl_buffer(
+      &mut state,
+      &buffer.gl_buf.handle,
+      buffer.buf[offset..].as_ptr() as *const u8,
+      mem::size_of_val(values),
+      offset * mem::size_of::<T>(),
+    );
+
+    Ok(())
+  }
+
   unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError> {
-    // copy the value everywhere in the buffer, then simply update the WebGL buffer
-    for item in &mut buffer.buf {
+    let len = buffer.buf.len();
+    <Self as BufferBackend<T>>::clear_part(buffer, 0, len, x)
+  }
+
+  unsafe fn clear_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    len: usize,
+    x: T,
+  ) -> Result<(), BufferError> {
+    let buffer_len = buffer.buf.len();
+
+    if offset > buffer_len || len > buffer_len - offset {
+      return Err(BufferError::out_of_bounds(offset, len, buffer_len));
+    }
+
+    // copy the value everywhere in the region, then simply update the WebGL buffer; WebGL2 has no
+    // way to fill a buffer on the GPU side
+    for item in &mut buffer.buf[offset..offset + len] {
       *item = x;
     }
 
     let mut state = buffer.gl_buf.state.borrow_mut();
-    let bytes = buffer.buf.len() * mem::size_of::<T>();
     update_webgl_buffer(
       &mut state,
       &buffer.gl_buf.handle,
-      buffer.buf.as_ptr() as *const u8,
-      bytes,
-      0,
+      buffer.buf[offset..].as_ptr() as *const u8,
+      len * mem::size_of::<T>(),
+      offset * mem::size_of::<T>(),
     );
 
     Ok(())
@@ -412,3 +482,17 @@ fn update_webgl_buffer(
       0,
     );
 }
+
+fn webgl_buffer_usage(usage: BufferUsage) -> u32 {
+  match usage {
+    BufferUsage::StaticDraw => WebGl2RenderingContext::STATIC_DRAW,
+    BufferUsage::StaticRead => WebGl2RenderingContext::STATIC_READ,
+    BufferUsage::StaticCopy => WebGl2RenderingContext::STATIC_COPY,
+    BufferUsage::DynamicDraw => WebGl2RenderingContext::DYNAMIC_DRAW,
+    BufferUsage::DynamicRead => WebGl2RenderingContext::DYNAMIC_READ,
+    BufferUsage::DynamicCopy => WebGl2RenderingContext::DYNAMIC_COPY,
+    BufferUsage::StreamDraw => WebGl2RenderingContext::STREAM_DRAW,
+    BufferUsage::StreamRead => WebGl2RenderingContext::STREAM_READ,
+    BufferUsage::StreamCopy => WebGl2RenderingContext::STREAM_COPY,
+  }
+}
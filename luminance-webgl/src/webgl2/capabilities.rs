@@ -0,0 +1,35 @@
+use luminance::backend::capabilities::Capabilities as CapabilitiesBackend;
+use luminance::capabilities::{Capabilities, FormatUsage};
+use luminance::pixel::{Format, PixelFormat, Size, Type};
+
+use crate::webgl2::pixel::webgl_pixel_format;
+use crate::webgl2::WebGL2;
+
+unsafe impl CapabilitiesBackend for WebGL2 {
+  unsafe fn capabilities(&mut self) -> Capabilities {
+    self.state.borrow().capabilities()
+  }
+
+  unsafe fn supports_pixel_format(&mut self, pf: PixelFormat, usage: FormatUsage) -> bool {
+    if webgl_pixel_format(pf).is_none() {
+      return false;
+    }
+
+    match usage {
+      FormatUsage::Sampled => !pf.is_stencil_pixel(),
+      FormatUsage::Rendered => is_renderable(pf),
+    }
+  }
+}
+
+/// Color-renderable formats, as specified by WebGL2 with the `EXT_color_buffer_float` extension,
+/// which is required by the backend.
+fn is_renderable(pf: PixelFormat) -> bool {
+  match (pf.format, pf.encoding) {
+    (Format::RGB(Size::Eleven, Size::Eleven, Size::Ten), Type::Floating) => true,
+    (Format::RGB(..), Type::NormUnsigned) => true,
+    (Format::RGB(..), _) | (Format::SRGB(..), _) => false,
+    (_, Type::NormIntegral) => !pf.is_color_pixel(),
+    _ => true,
+  }
+}
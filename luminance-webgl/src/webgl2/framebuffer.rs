@@ -4,12 +4,15 @@ use js_sys::Uint32Array;
 use luminance::backend::color_slot::ColorSlot;
 use luminance::backend::depth_slot::DepthSlot;
 use luminance::backend::framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer};
-use luminance::framebuffer::{FramebufferError, IncompleteReason};
+use luminance::framebuffer::{Attachment, FramebufferError, IncompleteReason};
+use luminance::pipeline::ClearColor;
+use luminance::pixel::PixelFormat;
 use luminance::texture::{Dim2, Dimensionable, Sampler};
 use std::cell::RefCell;
 use std::rc::Rc;
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlRenderbuffer};
 
+use crate::webgl2::pipeline::clear_color_buffer;
 use crate::webgl2::state::WebGL2State;
 use crate::webgl2::WebGL2;
 
@@ -20,8 +23,10 @@ where
   // None is the default framebuffer…
   pub(crate) handle: Option<WebGlFramebuffer>,
   renderbuffer: Option<WebGlRenderbuffer>,
+  // pixel formats of the color attachments, used to select the right clear command
+  pub(crate) color_formats: Vec<PixelFormat>,
   pub(crate) size: D::Size,
-  state: Rc<RefCell<WebGL2State>>,
+  pub(crate) state: Rc<RefCell<WebGL2State>>,
 }
 
 impl<D> Drop for Framebuffer<D>
@@ -69,7 +74,10 @@ where
 
     // color textures
     if color_formats.is_empty() {
+      // depth-only framebuffers must not reference any color buffer, neither for drawing nor for
+      // reading
       state.ctx.draw_buffers(&WebGl2RenderingContext::NONE.into());
+      state.ctx.read_buffer(WebGl2RenderingContext::NONE);
     } else {
       // Specify the list of color buffers to draw to; to do so, we need to generate a temporary
       // list (Vec) of 32-bit integers and turn it into a Uint32Array to pass it across WASM
@@ -114,6 +122,7 @@ where
     let framebuffer = Framebuffer {
       handle: Some(handle),
       renderbuffer: depth_renderbuffer,
+      color_formats,
       size,
       state: self.state.clone(),
     };
@@ -128,7 +137,9 @@ where
   ) -> Result<(), FramebufferError> {
     match texture.target {
       WebGl2RenderingContext::TEXTURE_2D => {
-        let state = framebuffer.state.borrow();
+        let mut state = framebuffer.state.borrow_mut();
+        state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
         state.ctx.framebuffer_texture_2d(
           WebGl2RenderingContext::FRAMEBUFFER,
           WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32,
@@ -150,7 +161,9 @@ where
   ) -> Result<(), FramebufferError> {
     match texture.target {
       WebGl2RenderingContext::TEXTURE_2D => {
-        let state = framebuffer.state.borrow();
+        let mut state = framebuffer.state.borrow_mut();
+        state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
         state.ctx.framebuffer_texture_2d(
           WebGl2RenderingContext::FRAMEBUFFER,
           WebGl2RenderingContext::DEPTH_ATTACHMENT,
@@ -176,6 +189,91 @@ where
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
     framebuffer.size
   }
+
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError> {
+    framebuffer.size = size;
+
+    // the default framebuffer is resized by the canvas
+    if framebuffer.handle.is_none() {
+      return Ok(());
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    // reallocate the depth renderbuffer we created if no depth slot was provided
+    if let Some(ref renderbuffer) = framebuffer.renderbuffer {
+      state
+        .ctx
+        .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(renderbuffer));
+
+      state.ctx.renderbuffer_storage(
+        WebGl2RenderingContext::RENDERBUFFER,
+        WebGl2RenderingContext::DEPTH_COMPONENT32F,
+        D::width(size) as i32,
+        D::height(size) as i32,
+      );
+    }
+
+    get_framebuffer_status(&mut state)?;
+    Ok(())
+  }
+
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  ) {
+    if attachments.is_empty() {
+      return;
+    }
+
+    // the default framebuffer uses different names for its attachments
+    let is_default = framebuffer.handle.is_none();
+    let attachments: Vec<_> = attachments
+      .iter()
+      .map(|attachment| match (*attachment, is_default) {
+        (Attachment::Color(_), true) => WebGl2RenderingContext::COLOR,
+        (Attachment::Color(index), false) => {
+          WebGl2RenderingContext::COLOR_ATTACHMENT0 + index as u32
+        }
+        (Attachment::Depth, true) => WebGl2RenderingContext::DEPTH,
+        (Attachment::Depth, false) => WebGl2RenderingContext::DEPTH_ATTACHMENT,
+        (Attachment::Stencil, true) => WebGl2RenderingContext::STENCIL,
+        (Attachment::Stencil, false) => WebGl2RenderingContext::STENCIL_ATTACHMENT,
+      })
+      .collect();
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    let attachments = Uint32Array::view(&attachments);
+
+    // invalidation is only a hint, so failing to invalidate is not an error
+    let _ = state
+      .ctx
+      .invalidate_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, attachments.as_ref());
+  }
+
+  unsafe fn clear_color_attachment(
+    framebuffer: &mut Self::FramebufferRepr,
+    index: usize,
+    clear_color: ClearColor,
+  ) {
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    state.prepare_clear();
+
+    clear_color_buffer(
+      &state.ctx,
+      index as i32,
+      &framebuffer.color_formats[index],
+      clear_color,
+    );
+  }
 }
 
 fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReason> {
@@ -210,6 +308,7 @@ unsafe impl FramebufferBackBuffer for WebGL2 {
     Ok(Framebuffer {
       handle: None, // None is the default framebuffer in WebGL
       renderbuffer: None,
+      color_formats: Vec::new(),
       size,
       state: self.state.clone(),
     })
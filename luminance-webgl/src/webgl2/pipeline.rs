@@ -8,10 +8,14 @@ use luminance::backend::shading_gate::ShadingGate;
 use luminance::backend::tess::Tess;
 use luminance::backend::tess_gate::TessGate;
 use luminance::blending::BlendingMode;
-use luminance::pipeline::{PipelineError, PipelineState, Viewport};
-use luminance::pixel::Pixel;
+use luminance::pipeline::{
+  ClearColor, PipelineError, PipelineState, Viewport, FRAME_UNIFORM_BUFFER_BINDING,
+};
+use luminance::pixel::{Pixel, PixelFormat, Type as PixelType};
 use luminance::render_state::RenderState;
-use luminance::tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData};
+use luminance::tess::{
+  Deinterleaved, DeinterleavedData, Interleaved, TessError, TessIndex, TessVertexData,
+};
 use luminance::texture::Dimensionable;
 use std::cell::RefCell;
 use std::marker::PhantomData;
@@ -19,13 +23,22 @@ use std::rc::Rc;
 use web_sys::WebGl2RenderingContext;
 
 use super::array_buffer::IntoArrayBuffer;
-use crate::webgl2::state::{BlendingState, DepthTest, FaceCullingState, WebGL2State};
+use crate::webgl2::state::{
+  BlendingState, DepthTest, FaceCullingState, ScissorState, StencilTestState, WebGL2State,
+};
 use crate::webgl2::WebGL2;
 
 pub struct Pipeline {
   state: Rc<RefCell<WebGL2State>>,
 }
 
+impl Drop for Pipeline {
+  fn drop(&mut self) {
+    // don’t leave stale bindings behind the pipeline
+    self.state.borrow_mut().unbind_pipeline_resources();
+  }
+}
+
 pub struct BoundBuffer {
   pub(crate) binding: u32,
   state: Rc<RefCell<WebGL2State>>,
@@ -33,6 +46,11 @@ pub struct BoundBuffer {
 
 impl Drop for BoundBuffer {
   fn drop(&mut self) {
+    // the frame uniform buffer binding is reserved and never goes into the free list
+    if self.binding == FRAME_UNIFORM_BUFFER_BINDING {
+      return;
+    }
+
     // place the binding into the free list
     self
       .state
@@ -79,6 +97,14 @@ unsafe impl PipelineBase for WebGL2 {
 
     Ok(pipeline)
   }
+
+  unsafe fn finish(&mut self) {
+    self.state.borrow().ctx.finish();
+  }
+
+  unsafe fn flush(&mut self) {
+    self.state.borrow().ctx.flush();
+  }
 }
 
 unsafe impl<D> PipelineBackend<D> for WebGL2
@@ -94,9 +120,6 @@ where
 
     state.bind_draw_framebuffer(framebuffer.handle.as_ref());
 
-    let clear_color = pipeline_state.clear_color;
-    state.set_clear_color(clear_color);
-
     let size = framebuffer.size;
 
     let (x, y, w, h) = match pipeline_state.viewport {
@@ -111,20 +134,100 @@ where
 
     state.set_viewport([x as _, y as _, w as _, h as _]);
 
-    if pipeline_state.clear_color_enabled || pipeline_state.clear_depth_enabled {
-      let color_bit = if pipeline_state.clear_color_enabled {
-        WebGl2RenderingContext::COLOR_BUFFER_BIT
-      } else {
-        0
-      };
+    if pipeline_state.clear_color_enabled
+      || pipeline_state.clear_depth_enabled
+      || pipeline_state.clear_stencil_enabled
+    {
+      state.prepare_clear();
+    }
+
+    // every buffer is cleared with the matching clearBuffer* command, for both custom framebuffers
+    // and the back buffer, so that only the buffers the pipeline asked for get cleared
+    if pipeline_state.clear_color_enabled {
+      clear_color_buffers(
+        &state.ctx,
+        &framebuffer.color_formats,
+        pipeline_state.clear_color,
+      );
+    }
+
+    let clear_depth = pipeline_state.clear_depth;
+    let clear_stencil = pipeline_state.clear_stencil;
+
+    match (
+      pipeline_state.clear_depth_enabled,
+      pipeline_state.clear_stencil_enabled,
+    ) {
+      (true, true) => state.ctx.clear_bufferfi(
+        WebGl2RenderingContext::DEPTH_STENCIL,
+        0,
+        clear_depth,
+        clear_stencil,
+      ),
+      (true, false) => {
+        state
+          .ctx
+          .clear_bufferfv_with_f32_array(WebGl2RenderingContext::DEPTH, 0, &[clear_depth])
+      }
+      (false, true) => state.ctx.clear_bufferiv_with_i32_array(
+        WebGl2RenderingContext::STENCIL,
+        0,
+        &[clear_stencil],
+      ),
+      (false, false) => (),
+    }
+  }
+}
 
-      let depth_bit = if pipeline_state.clear_depth_enabled {
-        WebGl2RenderingContext::DEPTH_BUFFER_BIT
-      } else {
-        0
-      };
+/// Clear every color attachment with the `clearBuffer*` variant matching its pixel encoding.
+///
+/// The back buffer doesn’t have any color format and its only draw buffer is cleared as a
+/// floating-point buffer.
+fn clear_color_buffers(
+  ctx: &WebGl2RenderingContext,
+  color_formats: &[PixelFormat],
+  clear_color: ClearColor,
+) {
+  if color_formats.is_empty() {
+    ctx.clear_bufferfv_with_f32_array(WebGl2RenderingContext::COLOR, 0, &clear_color.as_float());
+    return;
+  }
+
+  for (i, format) in color_formats.iter().enumerate() {
+    clear_color_buffer(ctx, i as i32, format, clear_color);
+  }
+}
+
+/// Clear a single color attachment with the `clearBuffer*` variant matching its pixel encoding.
+pub(crate) fn clear_color_buffer(
+  ctx: &WebGl2RenderingContext,
+  draw_buffer: i32,
+  format: &PixelFormat,
+  clear_color: ClearColor,
+) {
+  match format.encoding {
+    PixelType::Integral => {
+      ctx.clear_bufferiv_with_i32_array(
+        WebGl2RenderingContext::COLOR,
+        draw_buffer,
+        &clear_color.as_int(),
+      );
+    }
+
+    PixelType::Unsigned => {
+      ctx.clear_bufferuiv_with_u32_array(
+        WebGl2RenderingContext::COLOR,
+        draw_buffer,
+        &clear_color.as_uint(),
+      );
+    }
 
-      state.ctx.clear(color_bit | depth_bit);
+    _ => {
+      ctx.clear_bufferfv_with_f32_array(
+        WebGl2RenderingContext::COLOR,
+        draw_buffer,
+        &clear_color.as_float(),
+      );
     }
   }
 }
@@ -157,6 +260,21 @@ where
     })
   }
 
+  unsafe fn bind_frame_uniform_buffer(
+    pipeline: &Self::PipelineRepr,
+    buffer: &Self::BufferRepr,
+  ) -> Result<Self::BoundBufferRepr, PipelineError> {
+    pipeline
+      .state
+      .borrow_mut()
+      .bind_buffer_base(buffer.handle(), FRAME_UNIFORM_BUFFER_BINDING);
+
+    Ok(BoundBuffer {
+      binding: FRAME_UNIFORM_BUFFER_BINDING,
+      state: pipeline.state.clone(),
+    })
+  }
+
   unsafe fn buffer_binding(bound: &Self::BoundBufferRepr) -> u32 {
     bound.binding
   }
@@ -216,8 +334,8 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb)
   }
 }
 
@@ -233,8 +351,8 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb)
   }
 }
 
@@ -262,6 +380,8 @@ unsafe impl RenderGate for WebGL2 {
       }
     }
 
+    state.set_color_mask(rdr_st.color_mask());
+
     // depth-related state
     if let Some(depth_comparison) = rdr_st.depth_test() {
       state.set_depth_test(DepthTest::On);
@@ -272,6 +392,23 @@ unsafe impl RenderGate for WebGL2 {
 
     state.set_depth_write(rdr_st.depth_write());
 
+    // stencil state
+    match rdr_st.stencil_test() {
+      Some(stencil_test) => {
+        state.set_stencil_test(StencilTestState::On);
+        state.set_stencil_func(
+          stencil_test.comparison,
+          stencil_test.reference,
+          stencil_test.mask,
+        );
+        state.set_stencil_operations(stencil_test.operations);
+        state.set_stencil_write_mask(stencil_test.write_mask);
+      }
+      None => {
+        state.set_stencil_test(StencilTestState::Off);
+      }
+    }
+
     // face culling state
     match rdr_st.face_culling() {
       Some(face_culling) => {
@@ -283,7 +420,29 @@ unsafe impl RenderGate for WebGL2 {
         state.set_face_culling_state(FaceCullingState::Off);
       }
     }
+
+    state.set_rasterizer_discard(rdr_st.rasterizer_discard());
+
+    // scissor state
+    match rdr_st.scissor() {
+      Some(region) => {
+        state.set_scissor_state(ScissorState::On);
+        state.set_scissor_region(&region);
+      }
+      None => {
+        state.set_scissor_state(ScissorState::Off);
+      }
+    }
+
+    state.set_polygon_offset(rdr_st.polygon_offset());
+    state.set_depth_bounds(rdr_st.depth_bounds());
   }
+
+  unsafe fn push_debug_group(&mut self, _: &str) {
+    // debug groups are not supported in WebGL2
+  }
+
+  unsafe fn pop_debug_group(&mut self) {}
 }
 
 unsafe impl ShadingGate for WebGL2 {
@@ -57,6 +57,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::R32UI,
       WebGl2RenderingContext::UNSIGNED_INT,
     )),
+    (Format::R(Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RED,
+      WebGl2RenderingContext::R16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
     (Format::R(Size::ThirtyTwo), Type::Floating) => Some((
       WebGl2RenderingContext::RED,
       WebGl2RenderingContext::R32F,
@@ -116,6 +121,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::RG32UI,
       WebGl2RenderingContext::UNSIGNED_INT,
     )),
+    (Format::RG(Size::Sixteen, Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RG,
+      WebGl2RenderingContext::RG16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::Floating) => Some((
       WebGl2RenderingContext::RG,
       WebGl2RenderingContext::RG32F,
@@ -181,6 +191,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::RGB32UI,
       WebGl2RenderingContext::UNSIGNED_INT,
     )),
+    (Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RGB,
+      WebGl2RenderingContext::RGB16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
     (Format::RGB(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo), Type::Floating) => Some((
       WebGl2RenderingContext::RGB,
       WebGl2RenderingContext::RGB32F,
@@ -260,6 +275,13 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::RGBA32UI,
       WebGl2RenderingContext::UNSIGNED_INT,
     )),
+    (Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((
+        WebGl2RenderingContext::RGBA,
+        WebGl2RenderingContext::RGBA16F,
+        WebGl2RenderingContext::FLOAT,
+      ))
+    }
     (
       Format::RGBA(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo),
       Type::Floating,
@@ -301,6 +323,14 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::FLOAT,
     )),
 
+    // WebGL2 doesn’t support stencil textures, so that only the internal format — used by
+    // renderbuffers — matters
+    (Format::Stencil(Size::Eight), Type::Unsigned) => Some((
+      WebGl2RenderingContext::STENCIL_INDEX8,
+      WebGl2RenderingContext::STENCIL_INDEX8,
+      WebGl2RenderingContext::UNSIGNED_BYTE,
+    )),
+
     _ => None,
   }
 }
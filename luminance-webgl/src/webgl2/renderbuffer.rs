@@ -0,0 +1,125 @@
+//! Renderbuffer support for WebGL2.
+
+use luminance::backend::renderbuffer::Renderbuffer as RenderbufferBackend;
+use luminance::framebuffer::FramebufferError;
+use luminance::pixel::Pixel;
+use luminance::texture::{Dim2, Dimensionable, TextureError};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{WebGl2RenderingContext, WebGlRenderbuffer};
+
+use crate::webgl2::pixel::webgl_pixel_format;
+use crate::webgl2::state::WebGL2State;
+use crate::webgl2::WebGL2;
+
+pub struct Renderbuffer {
+  pub(crate) handle: WebGlRenderbuffer,
+  iformat: u32,
+  state: Rc<RefCell<WebGL2State>>,
+}
+
+impl Drop for Renderbuffer {
+  fn drop(&mut self) {
+    self
+      .state
+      .borrow()
+      .ctx
+      .delete_renderbuffer(Some(&self.handle));
+  }
+}
+
+unsafe impl<P> RenderbufferBackend<P> for WebGL2
+where
+  P: Pixel,
+{
+  type RenderbufferRepr = Renderbuffer;
+
+  unsafe fn new_renderbuffer(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<Self::RenderbufferRepr, FramebufferError> {
+    let pf = P::pixel_format();
+    let (_, iformat, _) = webgl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
+
+    let handle = self
+      .state
+      .borrow()
+      .ctx
+      .create_renderbuffer()
+      .ok_or_else(FramebufferError::cannot_create)?;
+
+    let mut renderbuffer = Renderbuffer {
+      handle,
+      iformat,
+      state: self.state.clone(),
+    };
+    <Self as RenderbufferBackend<P>>::resize_renderbuffer(&mut renderbuffer, size)?;
+
+    Ok(renderbuffer)
+  }
+
+  unsafe fn resize_renderbuffer(
+    renderbuffer: &mut Self::RenderbufferRepr,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<(), FramebufferError> {
+    let state = renderbuffer.state.borrow();
+
+    state.ctx.bind_renderbuffer(
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer.handle),
+    );
+    state.ctx.renderbuffer_storage(
+      WebGl2RenderingContext::RENDERBUFFER,
+      renderbuffer.iformat,
+      size[0] as i32,
+      size[1] as i32,
+    );
+    state
+      .ctx
+      .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, None);
+
+    Ok(())
+  }
+
+  unsafe fn attach_color_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    state.ctx.framebuffer_renderbuffer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32,
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer.handle),
+    );
+
+    Ok(())
+  }
+
+  unsafe fn attach_depth_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+  ) -> Result<(), FramebufferError> {
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    // stencil-only renderbuffers are attached to the stencil attachment point
+    let attachment = if P::pixel_format().is_stencil_pixel() {
+      WebGl2RenderingContext::STENCIL_ATTACHMENT
+    } else {
+      WebGl2RenderingContext::DEPTH_ATTACHMENT
+    };
+
+    state.ctx.framebuffer_renderbuffer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      attachment,
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer.handle),
+    );
+
+    Ok(())
+  }
+}
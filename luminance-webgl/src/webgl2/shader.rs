@@ -4,8 +4,8 @@ use luminance::backend::shader::{Shader, Uniformable};
 use luminance::pipeline::{BufferBinding, TextureBinding};
 use luminance::pixel::{SamplerType, Type as PixelType};
 use luminance::shader::{
-  ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType, UniformWarning,
-  VertexAttribWarning,
+  ActiveAttribute, ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType,
+  UniformWarning, VertexAttribWarning,
 };
 use luminance::texture::{Dim, Dimensionable};
 use luminance::vertex::Semantics;
@@ -259,6 +259,42 @@ unsafe impl Shader for WebGL2 {
     Ok(warnings)
   }
 
+  unsafe fn active_attributes(program: &Self::ProgramRepr) -> Vec<ActiveAttribute> {
+    let state = program.state.borrow();
+    let count = state
+      .ctx
+      .get_program_parameter(&program.handle, WebGl2RenderingContext::ACTIVE_ATTRIBUTES)
+      .as_f64()
+      .map_or(0, |count| count as u32);
+
+    let mut attributes = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+      let info = match state.ctx.get_active_attrib(&program.handle, index) {
+        Some(info) => info,
+        None => continue,
+      };
+      let name = info.name();
+
+      // built-in attributes can’t be bound by vertex buffers
+      if name.starts_with("gl_") {
+        continue;
+      }
+
+      let location = state.ctx.get_attrib_location(&program.handle, &name);
+
+      if let (Some(ty), true) = (attrib_type_from_glenum(info.type_()), location >= 0) {
+        attributes.push(ActiveAttribute {
+          name,
+          location: location as usize,
+          ty,
+        });
+      }
+    }
+
+    attributes
+  }
+
   unsafe fn new_uniform_builder(
     program: &mut Self::ProgramRepr,
   ) -> Result<Self::UniformBuilderRepr, ProgramError> {
@@ -273,15 +309,26 @@ unsafe impl Shader for WebGL2 {
     T: Uniformable<Self>,
   {
     let ty = T::ty();
-    let uniform = match ty {
+    let uniform: Uniform<T> = match ty {
       UniformType::BufferBinding => uniform_builder.ask_uniform_block(name)?,
       _ => uniform_builder.ask_uniform(name)?,
     };
 
     let state = uniform_builder.state.borrow();
-    uniform_type_match(&state, &uniform_builder.handle, name, ty)?;
+    let size = uniform_type_match(&state, &uniform_builder.handle, name, ty)?;
 
-    Ok(uniform)
+    Ok(Uniform::new_array(uniform.index(), size))
+  }
+
+  unsafe fn ask_uniform_at<T>(
+    _: &mut Self::UniformBuilderRepr,
+    location: u32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    T: Uniformable<Self>,
+  {
+    // GLSL ES 3.00 has no explicit uniform locations
+    Err(UniformWarning::unsupported_explicit_location(location))
   }
 
   unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
@@ -310,12 +357,34 @@ fn patch_shader_src(src: &str) -> String {
   pragma
 }
 
+/// Map a WebGL vertex attribute type to a [`UniformType`], if representable.
+fn attrib_type_from_glenum(glty: u32) -> Option<UniformType> {
+  match glty {
+    WebGl2RenderingContext::INT => Some(UniformType::Int),
+    WebGl2RenderingContext::UNSIGNED_INT => Some(UniformType::UInt),
+    WebGl2RenderingContext::FLOAT => Some(UniformType::Float),
+    WebGl2RenderingContext::INT_VEC2 => Some(UniformType::IVec2),
+    WebGl2RenderingContext::INT_VEC3 => Some(UniformType::IVec3),
+    WebGl2RenderingContext::INT_VEC4 => Some(UniformType::IVec4),
+    WebGl2RenderingContext::UNSIGNED_INT_VEC2 => Some(UniformType::UIVec2),
+    WebGl2RenderingContext::UNSIGNED_INT_VEC3 => Some(UniformType::UIVec3),
+    WebGl2RenderingContext::UNSIGNED_INT_VEC4 => Some(UniformType::UIVec4),
+    WebGl2RenderingContext::FLOAT_VEC2 => Some(UniformType::Vec2),
+    WebGl2RenderingContext::FLOAT_VEC3 => Some(UniformType::Vec3),
+    WebGl2RenderingContext::FLOAT_VEC4 => Some(UniformType::Vec4),
+    WebGl2RenderingContext::FLOAT_MAT2 => Some(UniformType::M22),
+    WebGl2RenderingContext::FLOAT_MAT3 => Some(UniformType::M33),
+    WebGl2RenderingContext::FLOAT_MAT4 => Some(UniformType::M44),
+    _ => None,
+  }
+}
+
 fn uniform_type_match(
   state: &WebGL2State,
   program: &WebGlProgram,
   name: &str,
   ty: UniformType,
-) -> Result<(), UniformWarning> {
+) -> Result<usize, UniformWarning> {
   // create a 1-item array to hold the name of the uniform we’d like to get information from
   let name_array = js_sys::Array::new();
   name_array.push(&name.into()); // push the name as a JsValue
@@ -339,12 +408,9 @@ fn uniform_type_match(
     .get_active_uniform(program, index)
     .ok_or_else(|| UniformWarning::TypeMismatch("cannot retrieve active uniform".to_owned(), ty))?;
 
-  // early-return if array – we don’t support them yet
-  if info.size() != 1 {
-    return Ok(());
-  }
-
-  check_types_match(name, ty, info.type_())
+  // arrays report the type of their elements, which is the one of their Uniformable
+  // implementation
+  check_types_match(name, ty, info.type_()).map(|_| info.size() as usize)
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -359,6 +425,15 @@ fn check_types_match(name: &str, ty: UniformType, glty: u32) -> Result<(), Unifo
     }
   }
 
+  // shadow samplers are bound to depth textures with a depth comparison, which have the same type
+  // as their regular counterparts
+  match (ty, glty) {
+    (UniformType::Sampler2D, WebGl2RenderingContext::SAMPLER_2D_SHADOW)
+    | (UniformType::Sampler2DArray, WebGl2RenderingContext::SAMPLER_2D_ARRAY_SHADOW)
+    | (UniformType::Cubemap, WebGl2RenderingContext::SAMPLER_CUBE_SHADOW) => return Ok(()),
+    _ => (),
+  }
+
   milkcheck!(
     ty,
     // scalars
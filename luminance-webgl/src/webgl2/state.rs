@@ -1,9 +1,15 @@
 //! Graphics state.
 
-use js_sys::{Float32Array, Int32Array};
+use js_sys::Int32Array;
 use luminance::blending::{Equation, Factor};
+use luminance::capabilities::Capabilities;
+use luminance::color_mask::ColorMask;
 use luminance::depth_test::{DepthComparison, DepthWrite};
 use luminance::face_culling::{FaceCullingMode, FaceCullingOrder};
+use luminance::pipeline::FRAME_UNIFORM_BUFFER_BINDING;
+use luminance::polygon_offset::PolygonOffset;
+use luminance::scissor::ScissorRegion;
+use luminance::stencil::{StencilComparison, StencilOp, StencilOperations};
 use std::cell::RefCell;
 use std::fmt;
 use std::marker::PhantomData;
@@ -29,7 +35,8 @@ impl BindingStack {
     BindingStack {
       next_texture_unit: 0,
       free_texture_units: Vec::new(),
-      next_buffer_binding: 0,
+      // the first binding is reserved for the frame uniform buffer
+      next_buffer_binding: FRAME_UNIFORM_BUFFER_BINDING + 1,
       free_buffer_bindings: Vec::new(),
     }
   }
@@ -54,13 +61,15 @@ pub struct WebGL2State {
   // viewport
   viewport: [i32; 4],
 
-  // clear buffers
-  clear_color: [f32; 4],
-
   // blending
   blending_state: BlendingState,
   blending_equations: BlendingEquations,
   blending_funcs: BlendingFactors,
+  // set once the advanced blending fallback warning is logged
+  advanced_blending_warned: bool,
+
+  // color mask
+  color_mask: ColorMask,
 
   // depth test
   depth_test: DepthTest,
@@ -69,11 +78,33 @@ pub struct WebGL2State {
   // depth write
   depth_write: DepthWrite,
 
+  // stencil test
+  stencil_test: StencilTestState,
+  stencil_func: (StencilComparison, u8, u8),
+  stencil_operations: StencilOperations,
+  stencil_write_mask: u8,
+
   // face culling
   face_culling_state: FaceCullingState,
   face_culling_order: FaceCullingOrder,
   face_culling_mode: FaceCullingMode,
 
+  // rasterizer discard
+  rasterizer_discard: bool,
+
+  // scissor
+  scissor_state: ScissorState,
+  scissor_region: ScissorRegion,
+
+  // polygon offset
+  polygon_offset: Option<PolygonOffset>,
+
+  // set once the unsupported depth bounds test warning is logged
+  depth_bounds_warned: bool,
+
+  // hardware limits
+  capabilities: Capabilities,
+
   // texture
   current_texture_unit: u32,
   bound_textures: Vec<(u32, Option<WebGlTexture>)>,
@@ -136,16 +167,25 @@ impl WebGL2State {
 
     let binding_stack = BindingStack::new();
     let viewport = get_ctx_viewport(&mut ctx)?;
-    let clear_color = get_ctx_clear_color(&mut ctx)?;
     let blending_state = get_ctx_blending_state(&mut ctx);
     let blending_equations = get_ctx_blending_equations(&mut ctx)?;
     let blending_funcs = get_ctx_blending_factors(&mut ctx)?;
+    let color_mask = get_ctx_color_mask(&mut ctx)?;
     let depth_test = get_ctx_depth_test(&mut ctx);
     let depth_test_comparison = DepthComparison::Less;
-    let depth_write = get_ctx_depth_write(&mut ctx);
+    let depth_write = get_ctx_depth_write(&mut ctx)?;
+    let stencil_test = get_ctx_stencil_test(&mut ctx);
+    let stencil_func = (StencilComparison::Always, 0, 0xFF);
+    let stencil_operations = StencilOperations::default();
+    let stencil_write_mask = 0xFF;
     let face_culling_state = get_ctx_face_culling_state(&mut ctx);
     let face_culling_order = get_ctx_face_culling_order(&mut ctx)?;
     let face_culling_mode = get_ctx_face_culling_mode(&mut ctx)?;
+    let rasterizer_discard = ctx.is_enabled(WebGl2RenderingContext::RASTERIZER_DISCARD);
+    let scissor_state = get_ctx_scissor_state(&mut ctx);
+    let scissor_region = get_ctx_scissor_region(&mut ctx)?;
+    let polygon_offset = get_ctx_polygon_offset(&mut ctx)?;
+    let capabilities = get_ctx_capabilities(&mut ctx);
 
     let current_texture_unit = 0;
     let bound_textures = vec![(WebGl2RenderingContext::TEXTURE0, None); 48]; // 48 is the platform minimal requirement
@@ -164,16 +204,27 @@ impl WebGL2State {
       ctx,
       binding_stack,
       viewport,
-      clear_color,
       blending_state,
       blending_equations,
       blending_funcs,
+      advanced_blending_warned: false,
+      color_mask,
       depth_test,
       depth_test_comparison,
       depth_write,
+      stencil_test,
+      stencil_func,
+      stencil_operations,
+      stencil_write_mask,
       face_culling_state,
       face_culling_order,
       face_culling_mode,
+      rasterizer_discard,
+      scissor_state,
+      scissor_region,
+      polygon_offset,
+      depth_bounds_warned: false,
+      capabilities,
       current_texture_unit,
       bound_textures,
       texture_swimming_pool,
@@ -188,6 +239,10 @@ impl WebGL2State {
     })
   }
 
+  pub(crate) fn capabilities(&self) -> Capabilities {
+    self.capabilities
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -327,6 +382,24 @@ impl WebGL2State {
     }
   }
 
+  /// Unbind all the textures and uniform buffers.
+  pub(crate) fn unbind_pipeline_resources(&mut self) {
+    for unit in 0..self.bound_textures.len() {
+      if let (target, Some(_)) = self.bound_textures[unit] {
+        self.set_texture_unit(unit as u32);
+        self.bind_texture(target, None);
+      }
+    }
+
+    for (binding, handle) in self.bound_uniform_buffers.iter_mut().enumerate() {
+      if handle.take().is_some() {
+        self
+          .ctx
+          .bind_buffer_base(WebGl2RenderingContext::UNIFORM_BUFFER, binding as u32, None);
+      }
+    }
+  }
+
   pub(crate) fn create_framebuffer(&mut self) -> Option<WebGlFramebuffer> {
     self.ctx.create_framebuffer()
   }
@@ -373,18 +446,6 @@ impl WebGL2State {
     }
   }
 
-  pub(crate) fn set_clear_color(&mut self, clear_color: [f32; 4]) {
-    if self.clear_color != clear_color {
-      self.ctx.clear_color(
-        clear_color[0],
-        clear_color[1],
-        clear_color[2],
-        clear_color[3],
-      );
-      self.clear_color = clear_color;
-    }
-  }
-
   pub(crate) fn set_blending_state(&mut self, state: BlendingState) {
     if self.blending_state != state {
       match state {
@@ -397,6 +458,7 @@ impl WebGL2State {
   }
 
   pub(crate) fn set_blending_equation(&mut self, equation: Equation) {
+    let equation = self.supported_blending_equation(equation);
     let equations = BlendingEquations {
       rgb: equation,
       alpha: equation,
@@ -415,6 +477,8 @@ impl WebGL2State {
     equation_rgb: Equation,
     equation_alpha: Equation,
   ) {
+    let equation_rgb = self.supported_blending_equation(equation_rgb);
+    let equation_alpha = self.supported_blending_equation(equation_alpha);
     let equations = BlendingEquations {
       rgb: equation_rgb,
       alpha: equation_alpha,
@@ -430,6 +494,21 @@ impl WebGL2State {
     }
   }
 
+  /// Replace an advanced blending equation by [`Equation::Additive`], as WebGL2 doesn’t support
+  /// them.
+  fn supported_blending_equation(&mut self, equation: Equation) -> Equation {
+    if !equation.is_advanced() {
+      return equation;
+    }
+
+    if !self.advanced_blending_warned {
+      self.advanced_blending_warned = true;
+      log::warn!("advanced blending equations not supported; falling back to additive blending");
+    }
+
+    Equation::Additive
+  }
+
   pub(crate) fn set_blending_func(&mut self, src: Factor, dst: Factor) {
     let funcs = BlendingFactors {
       src_rgb: src,
@@ -506,6 +585,68 @@ impl WebGL2State {
     }
   }
 
+  pub(crate) fn set_color_mask(&mut self, color_mask: ColorMask) {
+    if self.color_mask != color_mask {
+      self.ctx.color_mask(
+        color_mask.red,
+        color_mask.green,
+        color_mask.blue,
+        color_mask.alpha,
+      );
+
+      self.color_mask = color_mask;
+    }
+  }
+
+  pub(crate) fn set_stencil_test(&mut self, state: StencilTestState) {
+    if self.stencil_test != state {
+      match state {
+        StencilTestState::On => self.ctx.enable(WebGl2RenderingContext::STENCIL_TEST),
+        StencilTestState::Off => self.ctx.disable(WebGl2RenderingContext::STENCIL_TEST),
+      }
+
+      self.stencil_test = state;
+    }
+  }
+
+  pub(crate) fn set_stencil_func(
+    &mut self,
+    comparison: StencilComparison,
+    reference: u8,
+    mask: u8,
+  ) {
+    let func = (comparison, reference, mask);
+
+    if self.stencil_func != func {
+      self.ctx.stencil_func(
+        stencil_comparison_to_webgl(comparison),
+        reference as i32,
+        mask as u32,
+      );
+
+      self.stencil_func = func;
+    }
+  }
+
+  pub(crate) fn set_stencil_operations(&mut self, operations: StencilOperations) {
+    if self.stencil_operations != operations {
+      self.ctx.stencil_op(
+        stencil_op_to_webgl(operations.stencil_fails),
+        stencil_op_to_webgl(operations.depth_fails),
+        stencil_op_to_webgl(operations.depth_passes),
+      );
+
+      self.stencil_operations = operations;
+    }
+  }
+
+  pub(crate) fn set_stencil_write_mask(&mut self, write_mask: u8) {
+    if self.stencil_write_mask != write_mask {
+      self.ctx.stencil_mask(write_mask as u32);
+      self.stencil_write_mask = write_mask;
+    }
+  }
+
   pub(crate) fn set_face_culling_state(&mut self, state: FaceCullingState) {
     if self.face_culling_state != state {
       match state {
@@ -539,6 +680,76 @@ impl WebGL2State {
       self.face_culling_mode = mode;
     }
   }
+
+  pub(crate) fn set_rasterizer_discard(&mut self, rasterizer_discard: bool) {
+    if self.rasterizer_discard != rasterizer_discard {
+      if rasterizer_discard {
+        self.ctx.enable(WebGl2RenderingContext::RASTERIZER_DISCARD);
+      } else {
+        self.ctx.disable(WebGl2RenderingContext::RASTERIZER_DISCARD);
+      }
+
+      self.rasterizer_discard = rasterizer_discard;
+    }
+  }
+
+  /// Reset the states honored by clear commands, which might have been changed by a previous render
+  /// state, so that clears affect the whole buffers.
+  pub(crate) fn prepare_clear(&mut self) {
+    self.set_scissor_state(ScissorState::Off);
+    self.set_rasterizer_discard(false);
+    self.set_color_mask(ColorMask::all());
+    self.set_depth_write(DepthWrite::On);
+    self.set_stencil_write_mask(0xFF);
+  }
+
+  pub(crate) fn set_scissor_state(&mut self, state: ScissorState) {
+    if self.scissor_state != state {
+      match state {
+        ScissorState::On => self.ctx.enable(WebGl2RenderingContext::SCISSOR_TEST),
+        ScissorState::Off => self.ctx.disable(WebGl2RenderingContext::SCISSOR_TEST),
+      }
+
+      self.scissor_state = state;
+    }
+  }
+
+  pub(crate) fn set_scissor_region(&mut self, region: &ScissorRegion) {
+    if self.scissor_region != *region {
+      self.ctx.scissor(
+        region.x as i32,
+        region.y as i32,
+        region.width as i32,
+        region.height as i32,
+      );
+
+      self.scissor_region = *region;
+    }
+  }
+
+  pub(crate) fn set_depth_bounds(&mut self, depth_bounds: Option<(f32, f32)>) {
+    // WebGL2 has no depth bounds test
+    if depth_bounds.is_some() && !self.depth_bounds_warned {
+      self.depth_bounds_warned = true;
+      log::warn!("depth bounds test not supported; ignoring it");
+    }
+  }
+
+  pub(crate) fn set_polygon_offset(&mut self, polygon_offset: Option<PolygonOffset>) {
+    if self.polygon_offset != polygon_offset {
+      match polygon_offset {
+        Some(offset) => {
+          self.ctx.enable(WebGl2RenderingContext::POLYGON_OFFSET_FILL);
+          self.ctx.polygon_offset(offset.factor, offset.units);
+        }
+        None => self
+          .ctx
+          .disable(WebGl2RenderingContext::POLYGON_OFFSET_FILL),
+      }
+
+      self.polygon_offset = polygon_offset;
+    }
+  }
 }
 
 impl Drop for WebGL2State {
@@ -563,8 +774,14 @@ pub enum StateQueryError {
   UnknownArrayBufferInitialState,
   /// Unknown viewport initial state.
   UnknownViewportInitialState,
-  /// Unknown clear color initial state.
-  UnknownClearColorInitialState,
+  /// Unknown scissor region initial state.
+  UnknownScissorRegionInitialState,
+  /// Unknown color mask initial state.
+  UnknownColorMaskInitialState,
+  /// Unknown depth write initial state.
+  UnknownDepthWriteInitialState,
+  /// Unknown polygon offset initial state.
+  UnknownPolygonOffsetInitialState,
   /// Corrupted blending equation.
   UnknownBlendingEquation(u32),
   /// RGB blending equation couldn’t be retrieved when initializing the WebGL2 state.
@@ -606,8 +823,20 @@ impl fmt::Display for StateQueryError {
 
       StateQueryError::UnknownViewportInitialState => write!(f, "unknown viewport initial state"),
 
-      StateQueryError::UnknownClearColorInitialState => {
-        write!(f, "unknown clear color initial state")
+      StateQueryError::UnknownScissorRegionInitialState => {
+        write!(f, "unknown scissor region initial state")
+      }
+
+      StateQueryError::UnknownColorMaskInitialState => {
+        write!(f, "unknown color mask initial state")
+      }
+
+      StateQueryError::UnknownDepthWriteInitialState => {
+        write!(f, "unknown depth write initial state")
+      }
+
+      StateQueryError::UnknownPolygonOffsetInitialState => {
+        write!(f, "unknown polygon offset initial state")
       }
 
       StateQueryError::UnknownBlendingEquation(ref e) => {
@@ -685,22 +914,6 @@ fn get_ctx_viewport(ctx: &mut WebGl2RenderingContext) -> Result<[i32; 4], StateQ
   Ok(viewport)
 }
 
-fn get_ctx_clear_color(ctx: &mut WebGl2RenderingContext) -> Result<[f32; 4], StateQueryError> {
-  let parameter = ctx
-    .get_parameter(WebGl2RenderingContext::COLOR_CLEAR_VALUE)
-    .map_err(|_| StateQueryError::UnknownClearColorInitialState)?;
-  let array: Float32Array = parameter.into();
-
-  if array.length() != 4 {
-    return Err(StateQueryError::UnknownClearColorInitialState);
-  }
-
-  let mut color = [0.0; 4];
-  array.copy_to(&mut color); // safe thanks to the test above on array.length() above
-
-  Ok(color)
-}
-
 fn get_ctx_blending_state(ctx: &mut WebGl2RenderingContext) -> BlendingState {
   if ctx.is_enabled(WebGl2RenderingContext::BLEND) {
     BlendingState::On
@@ -783,6 +996,59 @@ fn from_gl_blending_factor(factor: u32) -> Result<Factor, u32> {
   }
 }
 
+fn get_ctx_scissor_state(ctx: &mut WebGl2RenderingContext) -> ScissorState {
+  let enabled = ctx.is_enabled(WebGl2RenderingContext::SCISSOR_TEST);
+
+  if enabled {
+    ScissorState::On
+  } else {
+    ScissorState::Off
+  }
+}
+
+fn get_ctx_scissor_region(
+  ctx: &mut WebGl2RenderingContext,
+) -> Result<ScissorRegion, StateQueryError> {
+  let parameter = ctx
+    .get_parameter(WebGl2RenderingContext::SCISSOR_BOX)
+    .map_err(|_| StateQueryError::UnknownScissorRegionInitialState)?;
+  let array: Int32Array = parameter.into();
+
+  if array.length() != 4 {
+    return Err(StateQueryError::UnknownScissorRegionInitialState);
+  }
+
+  let mut region = [0; 4];
+  array.copy_to(&mut region); // safe thanks to the test above on array.length() above
+
+  Ok(ScissorRegion {
+    x: region[0] as u32,
+    y: region[1] as u32,
+    width: region[2] as u32,
+    height: region[3] as u32,
+  })
+}
+
+fn get_ctx_polygon_offset(
+  ctx: &mut WebGl2RenderingContext,
+) -> Result<Option<PolygonOffset>, StateQueryError> {
+  if !ctx.is_enabled(WebGl2RenderingContext::POLYGON_OFFSET_FILL) {
+    return Ok(None);
+  }
+
+  let get_float = |pname| {
+    ctx
+      .get_parameter(pname)
+      .ok()
+      .and_then(|parameter| parameter.as_f64())
+      .ok_or(StateQueryError::UnknownPolygonOffsetInitialState)
+  };
+  let factor = get_float(WebGl2RenderingContext::POLYGON_OFFSET_FACTOR)?;
+  let units = get_float(WebGl2RenderingContext::POLYGON_OFFSET_UNITS)?;
+
+  Ok(Some(PolygonOffset::new(factor as f32, units as f32)))
+}
+
 fn get_ctx_depth_test(ctx: &mut WebGl2RenderingContext) -> DepthTest {
   let enabled = ctx.is_enabled(WebGl2RenderingContext::DEPTH_TEST);
 
@@ -793,13 +1059,53 @@ fn get_ctx_depth_test(ctx: &mut WebGl2RenderingContext) -> DepthTest {
   }
 }
 
-fn get_ctx_depth_write(ctx: &mut WebGl2RenderingContext) -> DepthWrite {
-  let enabled = ctx.is_enabled(WebGl2RenderingContext::DEPTH_WRITEMASK);
+fn get_ctx_color_mask(ctx: &mut WebGl2RenderingContext) -> Result<ColorMask, StateQueryError> {
+  let parameter = ctx
+    .get_parameter(WebGl2RenderingContext::COLOR_WRITEMASK)
+    .map_err(|_| StateQueryError::UnknownColorMaskInitialState)?;
+  let array: js_sys::Array = parameter.into();
+
+  if array.length() != 4 {
+    return Err(StateQueryError::UnknownColorMaskInitialState);
+  }
+
+  let channel = |i| {
+    array
+      .get(i)
+      .as_bool()
+      .ok_or(StateQueryError::UnknownColorMaskInitialState)
+  };
+
+  Ok(ColorMask::new(
+    channel(0)?,
+    channel(1)?,
+    channel(2)?,
+    channel(3)?,
+  ))
+}
+
+fn get_ctx_depth_write(ctx: &mut WebGl2RenderingContext) -> Result<DepthWrite, StateQueryError> {
+  // the depth write mask is not a capability and cannot be queried with isEnabled
+  let enabled = ctx
+    .get_parameter(WebGl2RenderingContext::DEPTH_WRITEMASK)
+    .map_err(|_| StateQueryError::UnknownDepthWriteInitialState)?
+    .as_bool()
+    .ok_or(StateQueryError::UnknownDepthWriteInitialState)?;
 
   if enabled {
-    DepthWrite::On
+    Ok(DepthWrite::On)
   } else {
-    DepthWrite::Off
+    Ok(DepthWrite::Off)
+  }
+}
+
+fn get_ctx_stencil_test(ctx: &mut WebGl2RenderingContext) -> StencilTestState {
+  let enabled = ctx.is_enabled(WebGl2RenderingContext::STENCIL_TEST);
+
+  if enabled {
+    StencilTestState::On
+  } else {
+    StencilTestState::Off
   }
 }
 
@@ -840,6 +1146,34 @@ fn get_ctx_face_culling_mode(
   }
 }
 
+// Not exposed by web-sys, as it comes from the EXT_texture_filter_anisotropic extension.
+const MAX_TEXTURE_MAX_ANISOTROPY_EXT: u32 = 0x84FF;
+
+fn get_ctx_capabilities(ctx: &mut WebGl2RenderingContext) -> Capabilities {
+  let max_anisotropy = match ctx.get_extension("EXT_texture_filter_anisotropic") {
+    Ok(Some(_)) => ctx
+      .get_parameter(MAX_TEXTURE_MAX_ANISOTROPY_EXT)
+      .ok()
+      .and_then(|x| x.as_f64())
+      .map(|x| x as f32),
+    _ => None,
+  };
+
+  let limit = |ctx: &mut WebGl2RenderingContext, param| {
+    get_webgl_param(ctx, param)
+      .filter(|&limit| limit > 0)
+      .map(|limit| limit as usize)
+  };
+
+  Capabilities::new()
+    .set_max_texture_size(limit(ctx, WebGl2RenderingContext::MAX_TEXTURE_SIZE))
+    .set_max_3d_texture_size(limit(ctx, WebGl2RenderingContext::MAX_3D_TEXTURE_SIZE))
+    .set_max_array_layers(limit(ctx, WebGl2RenderingContext::MAX_ARRAY_TEXTURE_LAYERS))
+    .set_max_samples(limit(ctx, WebGl2RenderingContext::MAX_SAMPLES))
+    .set_max_anisotropy(max_anisotropy)
+    .set_max_color_attachments(limit(ctx, WebGl2RenderingContext::MAX_COLOR_ATTACHMENTS))
+}
+
 fn load_webgl2_extensions(ctx: &mut WebGl2RenderingContext) -> Result<(), StateQueryError> {
   let required_extensions = ["OES_texture_float_linear", "EXT_color_buffer_float"];
 
@@ -912,6 +1246,24 @@ pub(crate) enum FaceCullingState {
   Off,
 }
 
+/// Should the stencil test be enabled?
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum StencilTestState {
+  /// Enable the stencil test.
+  On,
+  /// Disable the stencil test.
+  Off,
+}
+
+/// Should the scissor test be enabled?
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ScissorState {
+  /// Enable the scissor test.
+  On,
+  /// Disable the scissor test.
+  Off,
+}
+
 #[inline]
 fn depth_comparison_to_webgl(dc: DepthComparison) -> u32 {
   match dc {
@@ -926,6 +1278,34 @@ fn depth_comparison_to_webgl(dc: DepthComparison) -> u32 {
   }
 }
 
+#[inline]
+fn stencil_comparison_to_webgl(sc: StencilComparison) -> u32 {
+  match sc {
+    StencilComparison::Never => WebGl2RenderingContext::NEVER,
+    StencilComparison::Always => WebGl2RenderingContext::ALWAYS,
+    StencilComparison::Equal => WebGl2RenderingContext::EQUAL,
+    StencilComparison::NotEqual => WebGl2RenderingContext::NOTEQUAL,
+    StencilComparison::Less => WebGl2RenderingContext::LESS,
+    StencilComparison::LessOrEqual => WebGl2RenderingContext::LEQUAL,
+    StencilComparison::Greater => WebGl2RenderingContext::GREATER,
+    StencilComparison::GreaterOrEqual => WebGl2RenderingContext::GEQUAL,
+  }
+}
+
+#[inline]
+fn stencil_op_to_webgl(op: StencilOp) -> u32 {
+  match op {
+    StencilOp::Keep => WebGl2RenderingContext::KEEP,
+    StencilOp::Zero => WebGl2RenderingContext::ZERO,
+    StencilOp::Replace => WebGl2RenderingContext::REPLACE,
+    StencilOp::Increment => WebGl2RenderingContext::INCR,
+    StencilOp::IncrementWrap => WebGl2RenderingContext::INCR_WRAP,
+    StencilOp::Decrement => WebGl2RenderingContext::DECR,
+    StencilOp::DecrementWrap => WebGl2RenderingContext::DECR_WRAP,
+    StencilOp::Invert => WebGl2RenderingContext::INVERT,
+  }
+}
+
 #[inline]
 fn blending_equation_to_webgl(equation: Equation) -> u32 {
   match equation {
@@ -934,6 +1314,8 @@ fn blending_equation_to_webgl(equation: Equation) -> u32 {
     Equation::ReverseSubtract => WebGl2RenderingContext::FUNC_REVERSE_SUBTRACT,
     Equation::Min => WebGl2RenderingContext::MIN,
     Equation::Max => WebGl2RenderingContext::MAX,
+    // advanced equations are replaced before reaching WebGL2
+    _ => WebGl2RenderingContext::FUNC_ADD,
   }
 }
 
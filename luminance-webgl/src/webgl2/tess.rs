@@ -5,6 +5,7 @@ use luminance::backend::tess::{
   IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
   VertexSlice as VertexSliceBackend,
 };
+use luminance::buffer::BufferUsage;
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
   TessMapError, TessVertexData,
@@ -452,7 +453,12 @@ where
       let vb = if vertices.is_empty() {
         None
       } else {
-        let vb = Buffer::from_vec(webgl2, vertices, WebGl2RenderingContext::ARRAY_BUFFER)?;
+        let vb = Buffer::from_vec(
+          webgl2,
+          vertices,
+          WebGl2RenderingContext::ARRAY_BUFFER,
+          BufferUsage::default(),
+        )?;
 
         // force binding as it’s meaningful when a vao is bound
         webgl2
@@ -488,6 +494,7 @@ where
             webgl2,
             attribute.into_vec(),
             WebGl2RenderingContext::ARRAY_BUFFER,
+            BufferUsage::default(),
           )?;
 
           // force binding as it’s meaningful when a vao is bound
@@ -512,7 +519,12 @@ where
   I: TessIndex,
 {
   let ib = if !data.is_empty() {
-    let ib = Buffer::from_vec(webgl2, data, WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER)?;
+    let ib = Buffer::from_vec(
+      webgl2,
+      data,
+      WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER,
+      BufferUsage::default(),
+    )?;
 
     // force binding as it’s meaningful when a vao is bound
     webgl2
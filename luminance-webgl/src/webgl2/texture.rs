@@ -19,6 +19,9 @@ pub struct Texture {
   pub(crate) handle: WebGlTexture,
   pub(crate) target: u32, // “type” of the texture; used for bindings
   mipmaps: usize,
+  // kept around to be able to re-create the texture when resizing it
+  sampler: Sampler,
+  mipmap_levels: (usize, usize),
   state: Rc<RefCell<WebGL2State>>,
 }
 
@@ -81,6 +84,8 @@ where
       handle,
       target,
       mipmaps,
+      sampler,
+      mipmap_levels: (0, mipmaps - 1),
       state: self.state.clone(),
     };
 
@@ -91,6 +96,47 @@ where
     texture.mipmaps
   }
 
+  unsafe fn resize(texture: &mut Self::TextureRepr, size: D::Size) -> Result<(), TextureError> {
+    let mut state = texture.state.borrow_mut();
+
+    // WebGL2 textures have an immutable storage, so we need to create a brand new texture
+    let handle = state.create_texture().ok_or_else(|| {
+      TextureError::TextureStorageCreationFailed("cannot create texture".to_owned())
+    })?;
+    state.bind_texture(texture.target, Some(&handle));
+
+    setup_texture::<D>(
+      &mut state,
+      texture.target,
+      size,
+      texture.mipmaps,
+      P::pixel_format(),
+      texture.sampler,
+    )?;
+
+    let (base_level, max_level) = texture.mipmap_levels;
+    set_mipmap_levels(&mut state, texture.target, base_level, max_level);
+
+    state.ctx.delete_texture(Some(&texture.handle));
+    texture.handle = handle;
+
+    Ok(())
+  }
+
+  unsafe fn set_mipmap_levels(
+    texture: &mut Self::TextureRepr,
+    base_level: usize,
+    max_level: usize,
+  ) -> Result<(), TextureError> {
+    let mut state = texture.state.borrow_mut();
+
+    state.bind_texture(texture.target, Some(&texture.handle));
+    set_mipmap_levels(&mut state, texture.target, base_level, max_level);
+    texture.mipmap_levels = (base_level, max_level);
+
+    Ok(())
+  }
+
   unsafe fn clear_part(
     texture: &mut Self::TextureRepr,
     gen_mipmaps: GenMipmaps,
@@ -180,6 +226,20 @@ where
     )
   }
 
+  unsafe fn copy_part(
+    _: &Self::TextureRepr,
+    _: usize,
+    _: D::Offset,
+    _: &mut Self::TextureRepr,
+    _: usize,
+    _: D::Offset,
+    _: D::Size,
+  ) -> Result<(), TextureError> {
+    Err(TextureError::cannot_copy_texels(
+      "copying texels between textures is not supported by WebGL2",
+    ))
+  }
+
   unsafe fn get_raw_texels(
     texture: &Self::TextureRepr,
     size: D::Size,
@@ -199,8 +259,10 @@ where
     let w = D::width(size);
     let h = D::height(size);
 
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.size() * w as usize) % 8;
+    // set the packing alignment based on the number of bytes to skip; texels are retrieved with
+    // their raw encoding, which can be bigger than the size of the pixel format
+    let pf_size = pf.canals_len() * mem::size_of::<P::RawEncoding>();
+    let skip_bytes = (pf_size * w as usize) % 8;
     set_pack_alignment(&mut gfx_state, skip_bytes);
 
     // We need a workaround to get the texel data, because WebGL2 doesn’t support the glGetTexImage
@@ -288,14 +350,19 @@ where
 }
 
 fn set_texture_levels(state: &mut WebGL2State, target: u32, mipmaps: usize) {
-  state
-    .ctx
-    .tex_parameteri(target, WebGl2RenderingContext::TEXTURE_BASE_LEVEL, 0);
+  set_mipmap_levels(state, target, 0, mipmaps - 1);
+}
 
+fn set_mipmap_levels(state: &mut WebGL2State, target: u32, base_level: usize, max_level: usize) {
+  state.ctx.tex_parameteri(
+    target,
+    WebGl2RenderingContext::TEXTURE_BASE_LEVEL,
+    base_level as i32,
+  );
   state.ctx.tex_parameteri(
     target,
     WebGl2RenderingContext::TEXTURE_MAX_LEVEL,
-    mipmaps as i32 - 1,
+    max_level as i32,
   );
 }
 
@@ -348,6 +415,17 @@ fn apply_sampler_to_texture(state: &mut WebGL2State, target: u32, sampler: Sampl
       );
     }
   }
+
+  state.ctx.tex_parameterf(
+    target,
+    WebGl2RenderingContext::TEXTURE_MIN_LOD,
+    sampler.min_lod,
+  );
+  state.ctx.tex_parameterf(
+    target,
+    WebGl2RenderingContext::TEXTURE_MAX_LOD,
+    sampler.max_lod,
+  );
 }
 
 fn webgl_wrap(wrap: Wrap) -> u32 {
@@ -517,8 +595,8 @@ fn create_cubemap_storage(
 fn set_unpack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
   let unpack_alignment = match skip_bytes {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
   } as i32;
 
@@ -531,8 +609,8 @@ fn set_unpack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
 fn set_pack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
   let pack_alignment = match skip_bytes {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
   } as i32;
 
@@ -556,7 +634,9 @@ where
   // number of bytes in the input texels argument
   let input_bytes = texels.len() * mem::size_of::<T>();
   let pf = P::pixel_format();
-  let pf_size = pf.format.size();
+  // size of a pixel as sent by the client, which can be bigger than the size of the pixel format
+  // — e.g. 16-bit floating formats are sent as 32-bit floating values
+  let pf_size = pf.canals_len() * mem::size_of::<P::RawEncoding>();
   let expected_bytes = D::count(size) * pf_size;
 
   if input_bytes < expected_bytes {
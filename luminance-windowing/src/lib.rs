@@ -55,6 +55,12 @@ pub struct WindowOpt {
   ///
   /// `None` means no multisampling.
   pub num_samples: Option<u32>,
+  /// Whether a debug context should be requested.
+  ///
+  /// Debug contexts report errors, warnings and performance hints; the surface routes them to
+  /// the `log` crate. Only the surfaces created from a [`WindowOpt`] (e.g. with `luminance-glfw`)
+  /// honor that option; the other ones have their own way to request a debug context.
+  pub debug: bool,
 }
 
 impl Default for WindowOpt {
@@ -63,6 +69,7 @@ impl Default for WindowOpt {
   /// - `dim`: set to WindowDim::Windowed { width: 960, 540 }`.
   /// - `cursor_mode` set to `CursorMode::Visible`.
   /// - `num_samples` set to `None`.
+  /// - `debug` set to `false`.
   fn default() -> Self {
     WindowOpt {
       dim: WindowDim::Windowed {
@@ -71,6 +78,7 @@ impl Default for WindowOpt {
       },
       cursor_mode: CursorMode::Visible,
       num_samples: None,
+      debug: false,
     }
   }
 }
@@ -122,4 +130,16 @@ impl WindowOpt {
   pub fn num_samples(&self) -> &Option<u32> {
     &self.num_samples
   }
+
+  /// Request a debug context.
+  #[inline]
+  pub fn set_debug(self, debug: bool) -> Self {
+    WindowOpt { debug, ..self }
+  }
+
+  /// Whether a debug context is requested.
+  #[inline]
+  pub fn debug(&self) -> bool {
+    self.debug
+  }
 }
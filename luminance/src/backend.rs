@@ -69,11 +69,14 @@
 #![allow(missing_docs)]
 
 pub mod buffer;
+pub mod buffer_texture;
+pub mod capabilities;
 pub mod color_slot;
 pub mod depth_slot;
 pub mod framebuffer;
 pub mod pipeline;
 pub mod render_gate;
+pub mod renderbuffer;
 pub mod shader;
 pub mod shading_gate;
 pub mod tess;
@@ -2,7 +2,7 @@
 //!
 //! This interface defines the low-level API buffers must implement to be usable.
 
-use crate::buffer::BufferError;
+use crate::buffer::{BufferError, BufferUsage};
 
 pub unsafe trait Buffer<T>
 where
@@ -12,15 +12,28 @@ where
   type BufferRepr;
 
   /// Create a new buffer with a given number of uninitialized elements.
-  unsafe fn new_buffer(&mut self, len: usize) -> Result<Self::BufferRepr, BufferError>
+  unsafe fn new_buffer(
+    &mut self,
+    len: usize,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>
   where
     T: Default;
 
   unsafe fn len(buffer: &Self::BufferRepr) -> usize;
 
-  unsafe fn from_vec(&mut self, vec: Vec<T>) -> Result<Self::BufferRepr, BufferError>;
+  unsafe fn from_vec(
+    &mut self,
+    vec: Vec<T>,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>;
 
-  unsafe fn repeat(&mut self, len: usize, value: T) -> Result<Self::BufferRepr, BufferError>;
+  unsafe fn repeat(
+    &mut self,
+    len: usize,
+    value: T,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>;
 
   unsafe fn at(buffer: &Self::BufferRepr, i: usize) -> Option<T>;
 
@@ -30,7 +43,20 @@ where
 
   unsafe fn write_whole(buffer: &mut Self::BufferRepr, values: &[T]) -> Result<(), BufferError>;
 
+  unsafe fn write_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    values: &[T],
+  ) -> Result<(), BufferError>;
+
   unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError>;
+
+  unsafe fn clear_part(
+    buffer: &mut Self::BufferRepr,
+    offset: usize,
+    len: usize,
+    x: T,
+  ) -> Result<(), BufferError>;
 }
 
 pub unsafe trait BufferSlice<T>: Buffer<T>
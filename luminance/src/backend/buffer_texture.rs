@@ -0,0 +1,19 @@
+//! Buffer texture backend interface.
+//!
+//! This interface defines the low-level API buffer textures must implement to be usable.
+
+use crate::backend::buffer::Buffer;
+use crate::pixel::Pixel;
+use crate::texture::TextureError;
+
+pub unsafe trait BufferTexture<P>: Buffer<P::Encoding>
+where
+  P: Pixel,
+{
+  type BufferTextureRepr;
+
+  unsafe fn new_buffer_texture(
+    &mut self,
+    buffer: &Self::BufferRepr,
+  ) -> Result<Self::BufferTextureRepr, TextureError>;
+}
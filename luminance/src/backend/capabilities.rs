@@ -0,0 +1,12 @@
+//! Capabilities backend interface.
+//!
+//! This interface defines the low-level API backends must implement to expose hardware limits.
+
+use crate::capabilities::{Capabilities as CapabilitiesInfo, FormatUsage};
+use crate::pixel::PixelFormat;
+
+pub unsafe trait Capabilities {
+  unsafe fn capabilities(&mut self) -> CapabilitiesInfo;
+
+  unsafe fn supports_pixel_format(&mut self, pf: PixelFormat, usage: FormatUsage) -> bool;
+}
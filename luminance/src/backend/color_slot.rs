@@ -3,12 +3,14 @@
 //! This interface defines the low-level API color slots must implement to be usable.
 
 use crate::backend::framebuffer::Framebuffer;
+use crate::backend::renderbuffer::Renderbuffer as RenderbufferBackend;
 use crate::backend::texture::Texture as TextureBackend;
 use crate::context::GraphicsContext;
 use crate::framebuffer::FramebufferError;
 use crate::pixel::{ColorPixel, PixelFormat, RenderablePixel};
-use crate::texture::{Dimensionable, Sampler};
+use crate::texture::{Dim2, Dimensionable, Sampler};
 
+use crate::renderbuffer::Renderbuffer;
 use crate::texture::Texture;
 
 pub trait ColorSlot<B, D>
@@ -31,6 +33,13 @@ where
   ) -> Result<Self::ColorTextures, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  fn resize_color_textures(
+    textures: &mut Self::ColorTextures,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> ColorSlot<B, D> for ()
@@ -58,6 +67,15 @@ where
   {
     Ok(())
   }
+
+  fn resize_color_textures(
+    _: &mut Self::ColorTextures,
+    _: D::Size,
+    _: &mut B::FramebufferRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 impl<B, D, P> ColorSlot<B, D> for P
@@ -89,6 +107,55 @@ where
 
     Ok(texture)
   }
+
+  fn resize_color_textures(
+    texture: &mut Self::ColorTextures,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    texture.resize(size)?;
+    unsafe { B::attach_color_texture(framebuffer, &texture.repr, attachment_index) }
+  }
+}
+
+impl<B, P> ColorSlot<B, Dim2> for Renderbuffer<B, P>
+where
+  B: ?Sized + RenderbufferBackend<P>,
+  P: ColorPixel + RenderablePixel,
+{
+  type ColorTextures = Self;
+
+  fn color_formats() -> Vec<PixelFormat> {
+    vec![P::pixel_format()]
+  }
+
+  fn reify_color_textures<C>(
+    ctx: &mut C,
+    size: <Dim2 as Dimensionable>::Size,
+    _: usize,
+    _: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<Self::ColorTextures, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let renderbuffer = Renderbuffer::new(ctx, size)?;
+    unsafe { B::attach_color_renderbuffer(framebuffer, &renderbuffer.repr, attachment_index)? };
+
+    Ok(renderbuffer)
+  }
+
+  fn resize_color_textures(
+    renderbuffer: &mut Self::ColorTextures,
+    size: <Dim2 as Dimensionable>::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    renderbuffer.resize(size)?;
+    unsafe { B::attach_color_renderbuffer(framebuffer, &renderbuffer.repr, attachment_index) }
+  }
 }
 
 macro_rules! impl_color_slot_tuple {
@@ -114,7 +181,8 @@ macro_rules! impl_color_slot_tuple {
   }
 }
 
-// A small helper macro to implement reify_color_textures in impl_color_slot_tuple!.
+// A small helper macro to implement reify_color_textures and resize_color_textures in
+// impl_color_slot_tuple!.
 //
 // We need this macro so that we can implement the increment logic without having to do weird
 // arithmetic at runtime or have dead code.
@@ -159,6 +227,32 @@ macro_rules! impl_reify_color_textures {
 
       Ok(textures)
     }
+
+    #[allow(non_snake_case)]
+    fn resize_color_textures(
+      textures: &mut Self::ColorTextures,
+      size: D::Size,
+      framebuffer: &mut B::FramebufferRepr,
+      mut attachment_index: usize,
+    ) -> Result<(), FramebufferError> {
+      let (ref mut $pf, $(ref mut $pfr),*) = *textures;
+
+      // first element of the tuple
+      <$pf as ColorSlot<B, D>>::resize_color_textures($pf, size, framebuffer, attachment_index)?;
+
+      // rest of the tuple
+      $(
+        attachment_index += 1;
+        <$pfr as ColorSlot<B, D>>::resize_color_textures(
+          $pfr,
+          size,
+          framebuffer,
+          attachment_index,
+        )?;
+      )*
+
+      Ok(())
+    }
   }
 }
 
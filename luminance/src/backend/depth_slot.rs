@@ -3,12 +3,14 @@
 //! This interface defines the low-level API depth slots must implement to be usable.
 
 use crate::backend::framebuffer::Framebuffer;
+use crate::backend::renderbuffer::Renderbuffer as RenderbufferBackend;
 use crate::backend::texture::Texture as TextureBackend;
 use crate::context::GraphicsContext;
 use crate::framebuffer::FramebufferError;
-use crate::pixel::{DepthPixel, PixelFormat};
-use crate::texture::{Dimensionable, Sampler};
+use crate::pixel::{DepthPixel, Pixel, PixelFormat, Stencil8};
+use crate::texture::{Dim2, Dimensionable, Sampler};
 
+use crate::renderbuffer::Renderbuffer;
 use crate::texture::Texture;
 
 pub trait DepthSlot<B, D>
@@ -33,6 +35,13 @@ where
   ) -> Result<Self::DepthTexture, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  /// Resize a depth slot and re-attach it.
+  fn resize_depth_texture(
+    texture: &mut Self::DepthTexture,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> DepthSlot<B, D> for ()
@@ -59,6 +68,14 @@ where
   {
     Ok(())
   }
+
+  fn resize_depth_texture(
+    _: &mut Self::DepthTexture,
+    _: D::Size,
+    _: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 impl<B, D, P> DepthSlot<B, D> for P
@@ -89,4 +106,86 @@ where
 
     Ok(texture)
   }
+
+  fn resize_depth_texture(
+    texture: &mut Self::DepthTexture,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    texture.resize(size)?;
+    unsafe { B::attach_depth_texture(framebuffer, &texture.repr) }
+  }
+}
+
+impl<B, P> DepthSlot<B, Dim2> for Renderbuffer<B, P>
+where
+  B: ?Sized + RenderbufferBackend<P>,
+  P: DepthPixel,
+{
+  type DepthTexture = Self;
+
+  fn depth_format() -> Option<PixelFormat> {
+    Some(P::pixel_format())
+  }
+
+  fn reify_depth_texture<C>(
+    ctx: &mut C,
+    size: <Dim2 as Dimensionable>::Size,
+    _: usize,
+    _: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<Self::DepthTexture, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let renderbuffer = Renderbuffer::new(ctx, size)?;
+    unsafe { B::attach_depth_renderbuffer(framebuffer, &renderbuffer.repr)? };
+
+    Ok(renderbuffer)
+  }
+
+  fn resize_depth_texture(
+    renderbuffer: &mut Self::DepthTexture,
+    size: <Dim2 as Dimensionable>::Size,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    renderbuffer.resize(size)?;
+    unsafe { B::attach_depth_renderbuffer(framebuffer, &renderbuffer.repr) }
+  }
+}
+
+impl<B> DepthSlot<B, Dim2> for Renderbuffer<B, Stencil8>
+where
+  B: ?Sized + RenderbufferBackend<Stencil8>,
+{
+  type DepthTexture = Self;
+
+  fn depth_format() -> Option<PixelFormat> {
+    Some(Stencil8::pixel_format())
+  }
+
+  fn reify_depth_texture<C>(
+    ctx: &mut C,
+    size: <Dim2 as Dimensionable>::Size,
+    _: usize,
+    _: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<Self::DepthTexture, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let renderbuffer = Renderbuffer::new(ctx, size)?;
+    unsafe { B::attach_depth_renderbuffer(framebuffer, &renderbuffer.repr)? };
+
+    Ok(renderbuffer)
+  }
+
+  fn resize_depth_texture(
+    renderbuffer: &mut Self::DepthTexture,
+    size: <Dim2 as Dimensionable>::Size,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    renderbuffer.resize(size)?;
+    unsafe { B::attach_depth_renderbuffer(framebuffer, &renderbuffer.repr) }
+  }
 }
@@ -5,7 +5,8 @@
 use crate::backend::color_slot::ColorSlot;
 use crate::backend::depth_slot::DepthSlot;
 use crate::backend::texture::TextureBase;
-use crate::framebuffer::FramebufferError;
+use crate::framebuffer::{Attachment, FramebufferError};
+use crate::pipeline::ClearColor;
 use crate::texture::{Dim2, Dimensionable, Sampler};
 
 pub unsafe trait Framebuffer<D>: TextureBase
@@ -40,6 +41,29 @@ where
   ) -> Result<Self::FramebufferRepr, FramebufferError>;
 
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size;
+
+  /// Resize the internal storage of the framebuffer (i.e. not the color and depth textures) and
+  /// check its completeness.
+  ///
+  /// This is called once all the textures have been resized and re-attached.
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError>;
+
+  /// Hint that the content of the given attachments is not needed anymore.
+  ///
+  /// Backends that don’t support invalidation should do nothing.
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  );
+
+  unsafe fn clear_color_attachment(
+    framebuffer: &mut Self::FramebufferRepr,
+    index: usize,
+    clear_color: ClearColor,
+  );
 }
 
 pub unsafe trait FramebufferBackBuffer: Framebuffer<Dim2> {
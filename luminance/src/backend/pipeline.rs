@@ -3,9 +3,11 @@
 //! This interface defines the low-level API pipelines must implement to be usable.
 
 use crate::backend::buffer::Buffer;
+use crate::backend::buffer_texture::BufferTexture;
 use crate::backend::framebuffer::Framebuffer as FramebufferBackend;
 use crate::backend::shading_gate::ShadingGate as ShadingGateBackend;
 use crate::backend::texture::{Texture, TextureBase};
+use crate::buffer::BufferError;
 use crate::pipeline::{PipelineError, PipelineState};
 use crate::pixel::Pixel;
 use crate::texture::Dimensionable;
@@ -14,6 +16,10 @@ pub unsafe trait PipelineBase: ShadingGateBackend + TextureBase {
   type PipelineRepr;
 
   unsafe fn new_pipeline(&mut self) -> Result<Self::PipelineRepr, PipelineError>;
+
+  unsafe fn finish(&mut self);
+
+  unsafe fn flush(&mut self);
 }
 
 pub unsafe trait Pipeline<D>: PipelineBase + FramebufferBackend<D>
@@ -38,9 +44,28 @@ where
     buffer: &Self::BufferRepr,
   ) -> Result<Self::BoundBufferRepr, PipelineError>;
 
+  /// Bind a buffer at [`FRAME_UNIFORM_BUFFER_BINDING`], which must never be used by
+  /// [`PipelineBuffer::bind_buffer`].
+  ///
+  /// [`FRAME_UNIFORM_BUFFER_BINDING`]: crate::pipeline::FRAME_UNIFORM_BUFFER_BINDING
+  unsafe fn bind_frame_uniform_buffer(
+    pipeline: &Self::PipelineRepr,
+    buffer: &Self::BufferRepr,
+  ) -> Result<Self::BoundBufferRepr, PipelineError>;
+
   unsafe fn buffer_binding(bound: &Self::BoundBufferRepr) -> u32;
 }
 
+pub unsafe trait PipelineAtomicCounterBuffer: PipelineBase + Buffer<u32> {
+  unsafe fn bind_atomic_counter_buffer(
+    pipeline: &Self::PipelineRepr,
+    buffer: &Self::BufferRepr,
+    binding: u32,
+  ) -> Result<(), PipelineError>;
+
+  unsafe fn atomic_counters(buffer: &mut Self::BufferRepr) -> Result<Vec<u32>, BufferError>;
+}
+
 pub unsafe trait PipelineTexture<D, P>: PipelineBase + Texture<D, P>
 where
   D: Dimensionable,
@@ -58,3 +83,17 @@ where
 
   unsafe fn texture_binding(bound: &Self::BoundTextureRepr) -> u32;
 }
+
+pub unsafe trait PipelineBufferTexture<P>: PipelineBase + BufferTexture<P>
+where
+  P: Pixel,
+{
+  type BoundBufferTextureRepr;
+
+  unsafe fn bind_buffer_texture(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::BufferTextureRepr,
+  ) -> Result<Self::BoundBufferTextureRepr, PipelineError>;
+
+  unsafe fn buffer_texture_binding(bound: &Self::BoundBufferTextureRepr) -> u32;
+}
@@ -6,4 +6,8 @@ use crate::render_state::RenderState;
 
 pub unsafe trait RenderGate {
   unsafe fn enter_render_state(&mut self, rdr_st: &RenderState);
+
+  unsafe fn push_debug_group(&mut self, label: &str);
+
+  unsafe fn pop_debug_group(&mut self);
 }
@@ -0,0 +1,36 @@
+//! Renderbuffer backend interface.
+//!
+//! This interface defines the low-level API renderbuffers must implement to be usable.
+
+use crate::backend::framebuffer::Framebuffer;
+use crate::framebuffer::FramebufferError;
+use crate::pixel::Pixel;
+use crate::texture::{Dim2, Dimensionable};
+
+pub unsafe trait Renderbuffer<P>: Framebuffer<Dim2>
+where
+  P: Pixel,
+{
+  type RenderbufferRepr;
+
+  unsafe fn new_renderbuffer(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<Self::RenderbufferRepr, FramebufferError>;
+
+  unsafe fn resize_renderbuffer(
+    renderbuffer: &mut Self::RenderbufferRepr,
+    size: <Dim2 as Dimensionable>::Size,
+  ) -> Result<(), FramebufferError>;
+
+  unsafe fn attach_color_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError>;
+
+  unsafe fn attach_depth_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    renderbuffer: &Self::RenderbufferRepr,
+  ) -> Result<(), FramebufferError>;
+}
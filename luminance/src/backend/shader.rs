@@ -3,8 +3,8 @@
 //! This interface defines the low-level API shaders must implement to be usable.
 
 use crate::shader::{
-  ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType, UniformWarning,
-  VertexAttribWarning,
+  ActiveAttribute, ProgramError, StageError, StageType, TessellationStages, Uniform, UniformType,
+  UniformWarning, VertexAttribWarning,
 };
 use crate::vertex::Semantics;
 
@@ -40,6 +40,8 @@ pub unsafe trait Shader {
   where
     Sem: Semantics;
 
+  unsafe fn active_attributes(program: &Self::ProgramRepr) -> Vec<ActiveAttribute>;
+
   unsafe fn new_uniform_builder(
     program: &mut Self::ProgramRepr,
   ) -> Result<Self::UniformBuilderRepr, ProgramError>;
@@ -51,6 +53,13 @@ pub unsafe trait Shader {
   where
     T: Uniformable<Self>;
 
+  unsafe fn ask_uniform_at<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    location: u32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    T: Uniformable<Self>;
+
   unsafe fn unbound<T>(uniform_builder: &mut Self::UniformBuilderRepr) -> Uniform<T>
   where
     T: Uniformable<Self>;
@@ -3,7 +3,7 @@
 //! This interface defines the low-level API tessellation gates must implement to be usable.
 
 use crate::backend::tess::Tess;
-use crate::tess::{TessIndex, TessVertexData};
+use crate::tess::{TessError, TessIndex, TessVertexData};
 
 pub unsafe trait TessGate<V, I, W, S>: Tess<V, I, W, S>
 where
@@ -18,5 +18,5 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  );
+  ) -> Result<(), TessError>;
 }
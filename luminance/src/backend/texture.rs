@@ -24,6 +24,14 @@ where
 
   unsafe fn mipmaps(texture: &Self::TextureRepr) -> usize;
 
+  unsafe fn resize(texture: &mut Self::TextureRepr, size: D::Size) -> Result<(), TextureError>;
+
+  unsafe fn set_mipmap_levels(
+    texture: &mut Self::TextureRepr,
+    base_level: usize,
+    max_level: usize,
+  ) -> Result<(), TextureError>;
+
   unsafe fn clear_part(
     texture: &mut Self::TextureRepr,
     gen_mipmaps: GenMipmaps,
@@ -69,6 +77,17 @@ where
     texels: &[P::RawEncoding],
   ) -> Result<(), TextureError>;
 
+  #[allow(clippy::too_many_arguments)]
+  unsafe fn copy_part(
+    src: &Self::TextureRepr,
+    src_level: usize,
+    src_offset: D::Offset,
+    dst: &mut Self::TextureRepr,
+    dst_level: usize,
+    dst_offset: D::Offset,
+    size: D::Size,
+  ) -> Result<(), TextureError>;
+
   unsafe fn get_raw_texels(
     texture: &Self::TextureRepr,
     size: D::Size,
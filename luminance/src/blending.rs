@@ -9,7 +9,26 @@
 //!
 //! The factors are encoded with [`Factor`].
 //!
+//! # Advanced equations
+//!
+//! Besides the fixed-function equations, [`Equation`] has _advanced_ equations, starting with
+//! [`Equation::Multiply`]: the blend modes of image editors, such as multiply, screen or overlay.
+//! They are supported by OpenGL backends with the `GL_KHR_blend_equation_advanced` extension, and
+//! come with a few rules:
+//!
+//! - They ignore the blending factors.
+//! - The fragment shader must declare the advanced equations it’s rendered with — e.g.
+//!   `layout (blend_support_multiply) out;` — and write a single color output.
+//! - They can only be used with combined blending ([`BlendingMode::Combined`]).
+//!
+//! When an advanced equation is not supported — because the extension is missing, with WebGL2, or
+//! with separate blending — the backend logs a warning and blends with [`Equation::Additive`] and
+//! the blending factors instead: pick factors giving an acceptable fallback.
+//!
 //! [`Equation`]: crate::blending::Equation
+//! [`Equation::Multiply`]: crate::blending::Equation::Multiply
+//! [`Equation::Additive`]: crate::blending::Equation::Additive
+//! [`BlendingMode::Combined`]: crate::blending::BlendingMode::Combined
 //! [`Factor`]: crate::blending::Factor
 
 /// Blending equation. Used to state how blending factors and pixel data should be blended.
@@ -36,6 +55,71 @@ pub enum Equation {
   ///
   /// > `blended = max(src, dst)`
   Max,
+  /// `Multiply` represents the following advanced blending equation:
+  ///
+  /// > `blended = src * dst`
+  Multiply,
+  /// `Screen` represents the following advanced blending equation:
+  ///
+  /// > `blended = src + dst - src * dst`
+  Screen,
+  /// `Overlay` represents the advanced blending equation [`Equation::HardLight`] with `src` and
+  /// `dst` swapped.
+  Overlay,
+  /// `Darken` represents the following advanced blending equation:
+  ///
+  /// > `blended = min(src, dst)`
+  Darken,
+  /// `Lighten` represents the following advanced blending equation:
+  ///
+  /// > `blended = max(src, dst)`
+  Lighten,
+  /// `ColorDodge` represents the advanced blending equation brightening `dst` to reflect `src`.
+  ColorDodge,
+  /// `ColorBurn` represents the advanced blending equation darkening `dst` to reflect `src`.
+  ColorBurn,
+  /// `HardLight` represents the advanced blending equation [`Equation::Multiply`] for dark `src`
+  /// and [`Equation::Screen`] for bright `src`.
+  HardLight,
+  /// `SoftLight` represents the advanced blending equation darkening or lightening `dst`,
+  /// depending on `src`.
+  SoftLight,
+  /// `Difference` represents the following advanced blending equation:
+  ///
+  /// > `blended = abs(dst - src)`
+  Difference,
+  /// `Exclusion` represents the following advanced blending equation:
+  ///
+  /// > `blended = src + dst - 2 * src * dst`
+  Exclusion,
+  /// `HslHue` represents the advanced blending equation taking the hue of `src` and the
+  /// saturation and luminosity of `dst`.
+  HslHue,
+  /// `HslSaturation` represents the advanced blending equation taking the saturation of `src` and
+  /// the hue and luminosity of `dst`.
+  HslSaturation,
+  /// `HslColor` represents the advanced blending equation taking the hue and saturation of `src`
+  /// and the luminosity of `dst`.
+  HslColor,
+  /// `HslLuminosity` represents the advanced blending equation taking the luminosity of `src` and
+  /// the hue and saturation of `dst`.
+  HslLuminosity,
+}
+
+impl Equation {
+  /// Whether the equation is an advanced one.
+  ///
+  /// See the [module documentation](crate::blending#advanced-equations) for further details.
+  pub fn is_advanced(self) -> bool {
+    !matches!(
+      self,
+      Equation::Additive
+        | Equation::Subtract
+        | Equation::ReverseSubtract
+        | Equation::Min
+        | Equation::Max
+    )
+  }
 }
 
 /// Blending factors. Pixel data are multiplied by these factors to achieve several effects driven
@@ -77,6 +161,39 @@ pub struct Blending {
   pub dst: Factor,
 }
 
+impl Blending {
+  /// Create a new [`Blending`].
+  pub fn new(equation: Equation, src: Factor, dst: Factor) -> Self {
+    Blending { equation, src, dst }
+  }
+
+  /// Regular alpha blending — i.e. `src * srcA + dst * (1 - srcA)`.
+  ///
+  /// This is the blending to use for non-premultiplied transparent objects.
+  pub fn alpha() -> Self {
+    Blending::new(
+      Equation::Additive,
+      Factor::SrcAlpha,
+      Factor::SrcAlphaComplement,
+    )
+  }
+
+  /// Additive blending — i.e. `src + dst`.
+  ///
+  /// This is the blending to use for lights, particles and glows accumulating onto the scene.
+  pub fn additive() -> Self {
+    Blending::new(Equation::Additive, Factor::One, Factor::One)
+  }
+
+  /// Premultiplied alpha blending — i.e. `src + dst * (1 - srcA)`.
+  ///
+  /// This is the blending to use when the color of the source has already been multiplied by its
+  /// alpha.
+  pub fn premultiplied_alpha() -> Self {
+    Blending::new(Equation::Additive, Factor::One, Factor::SrcAlphaComplement)
+  }
+}
+
 /// Blending configuration to represent combined or separate options.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum BlendingMode {
@@ -0,0 +1,208 @@
+//! Memory layouts of uniform and storage blocks.
+//!
+//! Shader blocks — uniform buffers and shader storage buffers — are laid out in memory following
+//! either the `std140` or `std430` rules. Those rules are different from the ones Rust uses to lay
+//! out a `#[repr(C)]` struct, which is the source of subtle bugs: a `vec3` is aligned on 16 bytes,
+//! the elements of an array of scalars are 16 bytes apart in `std140`, etc.
+//!
+//! The [`Std140`] and [`Std430`] traits describe the base alignment and size of a type in each
+//! layout. They are implemented for scalars, vectors and matrices, for [`Aligned16`] and
+//! [`Array`], and can be derived for your own `#[repr(C)]` structs with
+//! `luminance_derive::{Std140, Std430}`. The derived implementations check, at compile time, that
+//! every field of the struct lies at the offset mandated by the layout rules, that its Rust size
+//! matches the layout size and that so does the size of the whole struct. If it’s not the case,
+//! compilation fails and you have to add explicit padding fields to your struct, including at its
+//! end:
+//!
+//! ```ignore
+//! #[derive(Clone, Copy, Std140)]
+//! #[repr(C)]
+//! struct Light {
+//!   position: [f32; 3],
+//!   intensity: f32, // packs into the last 4 bytes of position
+//!   color: [f32; 3],
+//!   _pad: f32, // required, since the struct is 16-byte aligned
+//! }
+//! ```
+//!
+//! Arrays are represented with [`Array`]. In `std140`, the stride of an array is always rounded
+//! up to 16 bytes, so that an array of scalars must be stored as an array of [`Aligned16`].
+//!
+//! # Scalars, vectors and matrices
+//!
+//! - `f32`, `i32` and `u32` map to `float`, `int` and `uint`. GLSL `bool` must be represented with
+//!   `u32`, as Rust’s `bool` is a single byte.
+//! - `[T; 2]`, `[T; 3]` and `[T; 4]` map to the corresponding vectors.
+//! - `[[f32; R]; C]` maps to a matrix of `C` columns of `R` rows, stored column-major.
+//!
+//! As columns are laid out like array elements, a `mat3` in both layouts — or a `mat2` in
+//! `std140` — has padded columns: represent it with `[Aligned16<[f32; 3]>; 3]` (respectively
+//! `[Aligned16<[f32; 2]>; 2]`) or with `[[f32; 4]; 3]` (respectively `[[f32; 4]; 2]`), ignoring
+//! the last components of each column. `[[f32; 3]; C]` and — in `std140` — `[[f32; 2]; C]` don’t
+//! implement the layout traits, since their Rust columns are not padded.
+
+/// Types that can be used in a block laid out with the `std140` rules.
+///
+/// # Safety
+///
+/// [`Std140::ALIGNMENT`] and [`Std140::SIZE`] must be the ones defined by the `std140` rules, and
+/// the Rust memory representation of the type must match that layout.
+pub unsafe trait Std140 {
+  /// Base alignment of the type, in bytes.
+  const ALIGNMENT: usize;
+
+  /// Size of the type, in bytes.
+  const SIZE: usize;
+}
+
+/// Types that can be used in a block laid out with the `std430` rules.
+///
+/// # Safety
+///
+/// [`Std430::ALIGNMENT`] and [`Std430::SIZE`] must be the ones defined by the `std430` rules, and
+/// the Rust memory representation of the type must match that layout.
+pub unsafe trait Std430 {
+  /// Base alignment of the type, in bytes.
+  const ALIGNMENT: usize;
+
+  /// Size of the type, in bytes.
+  const SIZE: usize;
+}
+
+/// A value aligned on (and padded to) 16 bytes.
+///
+/// This is mostly useful to build `std140` arrays of scalars or of 3D vectors, whose stride must
+/// be 16 bytes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C, align(16))]
+pub struct Aligned16<T>(pub T);
+
+/// An array of `N` elements of type `T` in a block.
+///
+/// The Rust stride of `T` must match the stride of the array in the layout; otherwise, evaluating
+/// its [`Std140::SIZE`] or [`Std430::SIZE`] — as the derived implementation of the block using it
+/// does — fails to compile. For instance, `Array<f32, N>` is fine in `std430` but must be
+/// `Array<Aligned16<f32>, N>` in `std140`:
+///
+/// ```compile_fail
+/// # use luminance::block_layout::{Array, Std140};
+/// // scalars are 16 bytes apart in std140 arrays
+/// const SIZE: usize = <Array<f32, 4> as Std140>::SIZE;
+/// ```
+///
+/// ```
+/// # use luminance::block_layout::{Aligned16, Array, Std140};
+/// const SIZE: usize = <Array<Aligned16<f32>, 4> as Std140>::SIZE;
+/// assert_eq!(SIZE, 64);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct Array<T, const N: usize>(pub [T; N]);
+
+/// Round `offset` up to the next multiple of `alignment`, which must be a power of two.
+#[doc(hidden)]
+pub const fn align_offset(offset: usize, alignment: usize) -> usize {
+  (offset + alignment - 1) & !(alignment - 1)
+}
+
+/// Maximum of two alignments.
+#[doc(hidden)]
+pub const fn max_alignment(a: usize, b: usize) -> usize {
+  if a > b {
+    a
+  } else {
+    b
+  }
+}
+
+macro_rules! impl_scalar_vector {
+  ($t:ty) => {
+    impl_layout!($t, 4, 4, 4, 4);
+    impl_layout!([$t; 2], 8, 8, 8, 8);
+    impl_layout!([$t; 3], 16, 12, 16, 12);
+    impl_layout!([$t; 4], 16, 16, 16, 16);
+  };
+}
+
+macro_rules! impl_layout {
+  ($t:ty, $std140_align:expr, $std140_size:expr, $std430_align:expr, $std430_size:expr) => {
+    unsafe impl Std140 for $t {
+      const ALIGNMENT: usize = $std140_align;
+      const SIZE: usize = $std140_size;
+    }
+
+    unsafe impl Std430 for $t {
+      const ALIGNMENT: usize = $std430_align;
+      const SIZE: usize = $std430_size;
+    }
+  };
+}
+
+impl_scalar_vector!(f32);
+impl_scalar_vector!(i32);
+impl_scalar_vector!(u32);
+
+// matrices are laid out as arrays of column vectors; only the representations whose Rust column
+// stride matches the layout one are implemented
+macro_rules! impl_matrix {
+  ($layout:ident, $col:ty, $alignment:expr, $stride:expr) => {
+    impl_matrix!($layout, $col, $alignment, $stride; 2, 3, 4);
+  };
+
+  ($layout:ident, $col:ty, $alignment:expr, $stride:expr; $($cols:expr),*) => {
+    $(
+      unsafe impl $layout for [$col; $cols] {
+        const ALIGNMENT: usize = $alignment;
+        const SIZE: usize = $stride * $cols;
+      }
+    )*
+  };
+}
+
+impl_matrix!(Std140, [f32; 4], 16, 16);
+impl_matrix!(Std140, Aligned16<[f32; 2]>, 16, 16);
+impl_matrix!(Std140, Aligned16<[f32; 3]>, 16, 16);
+impl_matrix!(Std430, [f32; 2], 8, 8);
+impl_matrix!(Std430, [f32; 4], 16, 16);
+impl_matrix!(Std430, Aligned16<[f32; 3]>, 16, 16);
+
+unsafe impl<T> Std140 for Aligned16<T>
+where
+  T: Std140,
+{
+  const ALIGNMENT: usize = max_alignment(T::ALIGNMENT, 16);
+  const SIZE: usize = align_offset(T::SIZE, 16);
+}
+
+unsafe impl<T> Std430 for Aligned16<T>
+where
+  T: Std430,
+{
+  const ALIGNMENT: usize = max_alignment(T::ALIGNMENT, 16);
+  const SIZE: usize = align_offset(T::SIZE, 16);
+}
+
+// the layout stride of an array must match the Rust one; this is checked when the size is used
+const fn array_size<T>(stride: usize, n: usize) -> usize {
+  assert!(
+    std::mem::size_of::<T>() == stride,
+    "the Rust stride of the array elements doesn’t match the layout one; use Aligned16"
+  );
+  stride * n
+}
+
+unsafe impl<T, const N: usize> Std140 for Array<T, N>
+where
+  T: Std140,
+{
+  const ALIGNMENT: usize = align_offset(T::ALIGNMENT, 16);
+  const SIZE: usize = array_size::<T>(align_offset(T::SIZE, 16), N);
+}
+
+unsafe impl<T, const N: usize> Std430 for Array<T, N>
+where
+  T: Std430,
+{
+  const ALIGNMENT: usize = T::ALIGNMENT;
+  const SIZE: usize = array_size::<T>(align_offset(T::SIZE, T::ALIGNMENT), N);
+}
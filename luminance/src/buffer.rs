@@ -19,6 +19,9 @@
 //! Buffers are created with the [`Buffer::new`], [`Buffer::from_vec`] and [`Buffer::repeat`]
 //! methods. All these methods are fallible — they might fail with [`BufferError`].
 //!
+//! Each of these methods has a `*_with_usage` variant accepting a [`BufferUsage`], which gives a
+//! hint to the backend about how you intend to use the buffer.
+//!
 //! Once you have a [`Buffer`], you can read from it and write to it.
 //! Writing is done with [`Buffer::set`] — which allows to set a value at a given index in the
 //! buffer, [`Buffer::write_whole`] — which writes a whole slice to the buffer — and
@@ -50,6 +53,43 @@ use std::error;
 use std::fmt;
 use std::marker::PhantomData;
 
+/// Usage hint of a [`Buffer`].
+///
+/// A usage is made of two parts: the frequency at which the data is going to be modified
+/// (_static_, _dynamic_ or _stream_) and the nature of the access (_draw_, _read_ or _copy_).
+///
+/// This is only a _hint_ given to the backend, not a guarantee: it allows drivers to choose a
+/// better placement for the memory, but using a buffer in a way that doesn’t match its usage is
+/// still valid — just potentially slower.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BufferUsage {
+  /// Data is set once and used many times as a source for drawing.
+  StaticDraw,
+  /// Data is set once and read back many times.
+  StaticRead,
+  /// Data is set once and used many times as a source for GPU copies.
+  StaticCopy,
+  /// Data is modified repeatedly and used many times as a source for drawing.
+  DynamicDraw,
+  /// Data is modified repeatedly and read back many times.
+  DynamicRead,
+  /// Data is modified repeatedly and used many times as a source for GPU copies.
+  DynamicCopy,
+  /// Data is set once and used at most a few times as a source for drawing.
+  StreamDraw,
+  /// Data is set once and read back at most a few times.
+  StreamRead,
+  /// Data is set once and used at most a few times as a source for GPU copies.
+  StreamCopy,
+}
+
+impl Default for BufferUsage {
+  /// [`BufferUsage::StreamDraw`].
+  fn default() -> Self {
+    BufferUsage::StreamDraw
+  }
+}
+
 /// A GPU buffer.
 ///
 /// # Parametricity
@@ -97,7 +137,18 @@ where
     C: GraphicsContext<Backend = B>,
     T: Default,
   {
-    let repr = unsafe { ctx.backend().new_buffer(len)? };
+    Self::new_with_usage(ctx, len, BufferUsage::default())
+  }
+
+  /// Create a new buffer with a given length and a [`BufferUsage`] hint.
+  ///
+  /// See the documentation of [`Buffer::new`] for further details.
+  pub fn new_with_usage<C>(ctx: &mut C, len: usize, usage: BufferUsage) -> Result<Self, BufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+    T: Default,
+  {
+    let repr = unsafe { ctx.backend().new_buffer(len, usage)? };
 
     Ok(Buffer {
       repr,
@@ -125,7 +176,22 @@ where
     C: GraphicsContext<Backend = B>,
     X: Into<Vec<T>>,
   {
-    let repr = unsafe { ctx.backend().from_vec(vec.into())? };
+    Self::from_vec_with_usage(ctx, vec, BufferUsage::default())
+  }
+
+  /// Create a new buffer from a slice of items and a [`BufferUsage`] hint.
+  ///
+  /// See the documentation of [`Buffer::from_vec`] for further details.
+  pub fn from_vec_with_usage<C, X>(
+    ctx: &mut C,
+    vec: X,
+    usage: BufferUsage,
+  ) -> Result<Self, BufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+    X: Into<Vec<T>>,
+  {
+    let repr = unsafe { ctx.backend().from_vec(vec.into(), usage)? };
 
     Ok(Buffer {
       repr,
@@ -151,7 +217,22 @@ where
   where
     C: GraphicsContext<Backend = B>,
   {
-    let repr = unsafe { ctx.backend().repeat(len, value)? };
+    Self::repeat_with_usage(ctx, len, value, BufferUsage::default())
+  }
+
+  /// Create a new buffer by repeating `len` times a `value`, with a [`BufferUsage`] hint.
+  ///
+  /// See the documentation of [`Buffer::repeat`] for further details.
+  pub fn repeat_with_usage<C>(
+    ctx: &mut C,
+    len: usize,
+    value: T,
+    usage: BufferUsage,
+  ) -> Result<Self, BufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe { ctx.backend().repeat(len, value, usage)? };
 
     Ok(Buffer {
       repr,
@@ -192,11 +273,41 @@ where
     unsafe { B::write_whole(&mut self.repr, values) }
   }
 
+  /// Set part of the content of the buffer by copying a slice at the element offset `offset`.
+  ///
+  /// Only the `values.len()` items starting at `offset` are updated; the rest of the buffer is
+  /// left untouched, which is handy to update a small region of a large buffer.
+  ///
+  /// # Errors
+  ///
+  /// [`BufferError::OutOfBounds`] is returned if `offset + values.len()` is bigger than the length
+  /// of the buffer.
+  pub fn write_part(&mut self, offset: usize, values: &[T]) -> Result<(), BufferError> {
+    unsafe { B::write_part(&mut self.repr, offset, values) }
+  }
+
   /// Clear the content of the buffer by copying the same value everywhere.
+  ///
+  /// No data is uploaded when the backend can fill the buffer on the GPU side — for instance,
+  /// with `glClearBufferData` on OpenGL 4.3. That is the fastest way to reset large buffers, such
+  /// as atomic counters, every frame.
   pub fn clear(&mut self, x: T) -> Result<(), BufferError> {
     unsafe { B::clear(&mut self.repr, x) }
   }
 
+  /// Clear `len` items of the buffer, starting at the element offset `offset`, by copying the
+  /// same value.
+  ///
+  /// See [`Buffer::clear`] for further details.
+  ///
+  /// # Errors
+  ///
+  /// [`BufferError::OutOfBounds`] is returned if `offset + len` is bigger than the length of the
+  /// buffer.
+  pub fn clear_part(&mut self, offset: usize, len: usize, x: T) -> Result<(), BufferError> {
+    unsafe { B::clear_part(&mut self.repr, offset, len, x) }
+  }
+
   /// Return the length of the buffer (i.e. the number of elements).
   #[inline(always)]
   pub fn len(&self) -> usize {
@@ -290,6 +401,18 @@ pub enum BufferError {
     buffer_len: usize,
   },
 
+  /// A partial write doesn’t fit in a buffer.
+  ///
+  /// Contains the offset and the number of passed values, and the size of the buffer.
+  OutOfBounds {
+    /// Offset of the write.
+    offset: usize,
+    /// Length of the provided data.
+    provided_len: usize,
+    /// Actual buffer length.
+    buffer_len: usize,
+  },
+
   /// Buffer mapping failed.
   MapFailed,
 }
@@ -321,6 +444,15 @@ impl BufferError {
     }
   }
 
+  /// A partial write doesn’t fit in a buffer.
+  pub fn out_of_bounds(offset: usize, provided_len: usize, buffer_len: usize) -> Self {
+    BufferError::OutOfBounds {
+      offset,
+      provided_len,
+      buffer_len,
+    }
+  }
+
   /// Buffer mapping failed.
   pub fn map_failed() -> Self {
     BufferError::MapFailed
@@ -356,6 +488,16 @@ impl fmt::Display for BufferError {
         provided_len, buffer_len
       ),
 
+      BufferError::OutOfBounds {
+        offset,
+        provided_len,
+        buffer_len,
+      } => write!(
+        f,
+        "partial write out of the buffer bounds (offset = {}, nb = {}, size = {})",
+        offset, provided_len, buffer_len
+      ),
+
       BufferError::MapFailed => f.write_str("buffer mapping failed"),
     }
   }
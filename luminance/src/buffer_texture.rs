@@ -0,0 +1,95 @@
+//! Buffer textures.
+//!
+//! A [`BufferTexture`] is a one-dimensional texture whose texels are stored in a [`Buffer`].
+//! Contrary to regular textures, buffer textures don’t support filtering nor mipmaps, but they
+//! can be as large as the biggest buffer the GPU can allocate, which is usually way bigger than
+//! both the maximum size of 1D textures or of uniform blocks. That makes them a good fit for
+//! large linear data, such as animation data or lookup tables, indexed with `texelFetch` in
+//! shaders:
+//!
+//! ```glsl
+//! uniform samplerBuffer animation_data;
+//!
+//! // …
+//! vec4 frame = texelFetch(animation_data, gl_InstanceID);
+//! ```
+//!
+//! A [`BufferTexture`] takes ownership of the [`Buffer`] holding its texels. The buffer can still
+//! be updated with [`BufferTexture::buffer_mut`]; changes are visible from shaders without having
+//! to re-create the texture.
+//!
+//! Buffer textures are bound to pipelines as regular textures, with
+//! [`Pipeline::bind_buffer_texture`].
+//!
+//! > Note: buffer textures are not available in WebGL2.
+//!
+//! [`Pipeline::bind_buffer_texture`]: crate::pipeline::Pipeline::bind_buffer_texture
+
+use crate::backend::buffer_texture::BufferTexture as BufferTextureBackend;
+use crate::buffer::Buffer;
+use crate::context::GraphicsContext;
+use crate::pixel::Pixel;
+use crate::texture::TextureError;
+
+/// A texture which texels are stored in a [`Buffer`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [backend::buffer_texture::BufferTexture].
+/// - `P` is the pixel format of the texels. It must implement [`Pixel`].
+///
+/// [backend::buffer_texture::BufferTexture]: crate::backend::buffer_texture::BufferTexture
+pub struct BufferTexture<B, P>
+where
+  B: ?Sized + BufferTextureBackend<P>,
+  P: Pixel,
+{
+  pub(crate) repr: B::BufferTextureRepr,
+  buffer: Buffer<B, P::Encoding>,
+}
+
+impl<B, P> BufferTexture<B, P>
+where
+  B: ?Sized + BufferTextureBackend<P>,
+  P: Pixel,
+{
+  /// Create a new [`BufferTexture`] viewing the texels of `buffer`.
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::UnsupportedPixelFormat`] is returned if the pixel format cannot be used with
+  /// buffer textures — not all formats are supported by backends.
+  pub fn new<C>(ctx: &mut C, buffer: Buffer<B, P::Encoding>) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe { ctx.backend().new_buffer_texture(&buffer.repr)? };
+
+    Ok(BufferTexture { repr, buffer })
+  }
+
+  /// Number of texels in the texture.
+  pub fn len(&self) -> usize {
+    self.buffer.len()
+  }
+
+  /// Check whether the texture is empty (i.e. it has no texels).
+  pub fn is_empty(&self) -> bool {
+    self.buffer.is_empty()
+  }
+
+  /// Access the [`Buffer`] holding the texels.
+  pub fn buffer(&self) -> &Buffer<B, P::Encoding> {
+    &self.buffer
+  }
+
+  /// Access the [`Buffer`] holding the texels mutably, so that they can be updated.
+  pub fn buffer_mut(&mut self) -> &mut Buffer<B, P::Encoding> {
+    &mut self.buffer
+  }
+
+  /// Consume the texture and return the [`Buffer`] holding the texels.
+  pub fn into_buffer(self) -> Buffer<B, P::Encoding> {
+    self.buffer
+  }
+}
@@ -0,0 +1,180 @@
+//! Hardware capabilities and limits.
+//!
+//! Graphics hardware comes with limits — the maximum size of a texture, the maximum number of
+//! samples of a multisampled framebuffer, etc. Those limits are queried once by the backend when
+//! it’s created and are available via [`GraphicsContext::capabilities`], which returns
+//! a [`Capabilities`] object. They can be used to validate the resources you want to create, or
+//! to pick formats and sizes that fit the hardware.
+//!
+//! All the limits are optional: a limit that cannot be queried with the backend (for instance,
+//! because the feature is not supported by the current context) is `None`. Optional features are
+//! reported as `bool`s, `false` meaning that the feature is not available.
+//!
+//! Support of pixel formats depends on how the textures are used and is queried on demand with
+//! [`GraphicsContext::supports_pixel_format`], so that renderers can pick a fallback format — for
+//! instance, when HDR formats cannot be rendered to.
+//!
+//! [`GraphicsContext::capabilities`]: crate::context::GraphicsContext::capabilities
+//! [`GraphicsContext::supports_pixel_format`]: crate::context::GraphicsContext::supports_pixel_format
+
+/// Hardware capabilities and limits.
+///
+/// See the [module documentation](crate::capabilities) for further details.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Capabilities {
+  max_texture_size: Option<usize>,
+  max_3d_texture_size: Option<usize>,
+  max_array_layers: Option<usize>,
+  max_samples: Option<usize>,
+  max_anisotropy: Option<f32>,
+  max_color_attachments: Option<usize>,
+  framebuffer_fetch: bool,
+}
+
+impl Capabilities {
+  /// Create a new [`Capabilities`] object with no known limit.
+  ///
+  /// This is mostly useful to backends, which set the limits they could query with the `set_*`
+  /// methods.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Maximum width and height of 1D and 2D textures, and of cubemap faces.
+  pub fn max_texture_size(&self) -> Option<usize> {
+    self.max_texture_size
+  }
+
+  /// Set the maximum width and height of 1D and 2D textures.
+  pub fn set_max_texture_size<L>(self, max_texture_size: L) -> Self
+  where
+    L: Into<Option<usize>>,
+  {
+    Capabilities {
+      max_texture_size: max_texture_size.into(),
+      ..self
+    }
+  }
+
+  /// Maximum width, height and depth of 3D textures.
+  pub fn max_3d_texture_size(&self) -> Option<usize> {
+    self.max_3d_texture_size
+  }
+
+  /// Set the maximum width, height and depth of 3D textures.
+  pub fn set_max_3d_texture_size<L>(self, max_3d_texture_size: L) -> Self
+  where
+    L: Into<Option<usize>>,
+  {
+    Capabilities {
+      max_3d_texture_size: max_3d_texture_size.into(),
+      ..self
+    }
+  }
+
+  /// Maximum number of layers of array textures.
+  pub fn max_array_layers(&self) -> Option<usize> {
+    self.max_array_layers
+  }
+
+  /// Set the maximum number of layers of array textures.
+  pub fn set_max_array_layers<L>(self, max_array_layers: L) -> Self
+  where
+    L: Into<Option<usize>>,
+  {
+    Capabilities {
+      max_array_layers: max_array_layers.into(),
+      ..self
+    }
+  }
+
+  /// Maximum number of samples of multisampled textures and renderbuffers.
+  pub fn max_samples(&self) -> Option<usize> {
+    self.max_samples
+  }
+
+  /// Set the maximum number of samples of multisampled textures and renderbuffers.
+  pub fn set_max_samples<L>(self, max_samples: L) -> Self
+  where
+    L: Into<Option<usize>>,
+  {
+    Capabilities {
+      max_samples: max_samples.into(),
+      ..self
+    }
+  }
+
+  /// Maximum degree of anisotropic filtering.
+  ///
+  /// `None` if anisotropic filtering is not supported.
+  pub fn max_anisotropy(&self) -> Option<f32> {
+    self.max_anisotropy
+  }
+
+  /// Set the maximum degree of anisotropic filtering.
+  pub fn set_max_anisotropy<L>(self, max_anisotropy: L) -> Self
+  where
+    L: Into<Option<f32>>,
+  {
+    Capabilities {
+      max_anisotropy: max_anisotropy.into(),
+      ..self
+    }
+  }
+
+  /// Maximum number of color attachments of a framebuffer.
+  pub fn max_color_attachments(&self) -> Option<usize> {
+    self.max_color_attachments
+  }
+
+  /// Set the maximum number of color attachments of a framebuffer.
+  pub fn set_max_color_attachments<L>(self, max_color_attachments: L) -> Self
+  where
+    L: Into<Option<usize>>,
+  {
+    Capabilities {
+      max_color_attachments: max_color_attachments.into(),
+      ..self
+    }
+  }
+
+  /// Whether fragment shaders can read the current color of the framebuffer at their own pixel.
+  ///
+  /// With OpenGL, that is the `GL_EXT_shader_framebuffer_fetch` extension: a fragment shader
+  /// enabling it and declaring its output as `inout` reads the color already stored in the
+  /// framebuffer before writing the new one, which allows custom blending and in-place effects in
+  /// a single pass:
+  ///
+  /// ```glsl
+  /// #extension GL_EXT_shader_framebuffer_fetch : require
+  ///
+  /// inout vec4 frag_color;
+  ///
+  /// void main() {
+  ///   frag_color = vec4(1.) - frag_color; // invert the current color
+  /// }
+  /// ```
+  ///
+  /// When it’s not available, such effects must ping-pong between two framebuffers (see the
+  /// [framebuffer module documentation](crate::framebuffer)).
+  pub fn framebuffer_fetch(&self) -> bool {
+    self.framebuffer_fetch
+  }
+
+  /// Set whether fragment shaders can read the current color of the framebuffer.
+  pub fn set_framebuffer_fetch(self, framebuffer_fetch: bool) -> Self {
+    Capabilities {
+      framebuffer_fetch,
+      ..self
+    }
+  }
+}
+
+/// How a pixel format is meant to be used, to check whether it’s supported.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FormatUsage {
+  /// Textures with that format are sampled in shaders.
+  Sampled,
+  /// Textures or renderbuffers with that format are attached to framebuffers and rendered to.
+  Rendered,
+}
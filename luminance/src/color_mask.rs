@@ -0,0 +1,54 @@
+//! Color write mask.
+//!
+//! The color mask selects which channels of the color attachments are written to when rendering.
+//! Masking all channels is typical of depth-only passes, such as shadow maps and depth pre-passes,
+//! where only the depth buffer matters.
+
+/// Channels of the color attachments that are written to when rendering.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ColorMask {
+  /// Whether the red channel is written to.
+  pub red: bool,
+  /// Whether the green channel is written to.
+  pub green: bool,
+  /// Whether the blue channel is written to.
+  pub blue: bool,
+  /// Whether the alpha channel is written to.
+  pub alpha: bool,
+}
+
+impl ColorMask {
+  /// Create a new [`ColorMask`].
+  pub fn new(red: bool, green: bool, blue: bool, alpha: bool) -> Self {
+    ColorMask {
+      red,
+      green,
+      blue,
+      alpha,
+    }
+  }
+
+  /// Write all channels.
+  pub fn all() -> Self {
+    ColorMask::new(true, true, true, true)
+  }
+
+  /// Don’t write any channel.
+  pub fn none() -> Self {
+    ColorMask::new(false, false, false, false)
+  }
+
+  /// Whether no channel is written to.
+  pub fn is_none(&self) -> bool {
+    !(self.red || self.green || self.blue || self.alpha)
+  }
+}
+
+/// Default implementation of [`ColorMask`].
+///
+/// All channels are written to.
+impl Default for ColorMask {
+  fn default() -> Self {
+    ColorMask::all()
+  }
+}
@@ -44,6 +44,7 @@
 //! ```
 
 use crate::backend::buffer::Buffer as BufferBackend;
+use crate::backend::capabilities::Capabilities as CapabilitiesBackend;
 use crate::backend::color_slot::ColorSlot;
 use crate::backend::depth_slot::DepthSlot;
 use crate::backend::framebuffer::Framebuffer as FramebufferBackend;
@@ -51,9 +52,10 @@ use crate::backend::shader::Shader;
 use crate::backend::tess::Tess as TessBackend;
 use crate::backend::texture::Texture as TextureBackend;
 use crate::buffer::{Buffer, BufferError};
+use crate::capabilities::{Capabilities, FormatUsage};
 use crate::framebuffer::{Framebuffer, FramebufferError};
 use crate::pipeline::PipelineGate;
-use crate::pixel::Pixel;
+use crate::pixel::{Pixel, PixelFormat};
 use crate::shader::{ProgramBuilder, Stage, StageError, StageType};
 use crate::tess::{Deinterleaved, Interleaved, TessBuilder, TessVertexData};
 use crate::texture::{Dimensionable, Sampler, Texture, TextureError};
@@ -71,6 +73,28 @@ pub unsafe trait GraphicsContext: Sized {
   /// Access the underlying backend.
   fn backend(&mut self) -> &mut Self::Backend;
 
+  /// Get the hardware [`Capabilities`] and limits.
+  ///
+  /// Those are queried once when the backend is created, so calling this function is cheap.
+  fn capabilities(&mut self) -> Capabilities
+  where
+    Self::Backend: CapabilitiesBackend,
+  {
+    unsafe { self.backend().capabilities() }
+  }
+
+  /// Check whether a pixel format is supported for a given usage.
+  ///
+  /// Contrary to [`GraphicsContext::capabilities`], this function queries the backend on every
+  /// call. When the backend cannot query format support, the formats it knows are assumed to be
+  /// supported.
+  fn supports_pixel_format(&mut self, pf: PixelFormat, usage: FormatUsage) -> bool
+  where
+    Self::Backend: CapabilitiesBackend,
+  {
+    unsafe { self.backend().supports_pixel_format(pf, usage) }
+  }
+
   /// Create a new pipeline gate
   fn new_pipeline_gate(&mut self) -> PipelineGate<Self::Backend> {
     PipelineGate::new(self)
@@ -2,6 +2,9 @@
 
 /// Depth comparison to perform while depth test. `a` is the incoming fragment’s depth and b is the
 /// fragment’s depth that is already stored.
+///
+/// [`DepthComparison::Greater`] and [`DepthComparison::GreaterOrEqual`] are the comparisons to use
+/// with a _reverse-Z_ depth buffer, where the near plane maps to `1.0` and the far plane to `0.0`.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum DepthComparison {
   /// Depth test never succeeds.
@@ -1,5 +1,22 @@
 //! Face culling is the operation of removing triangles if they’re facing the screen in a specific
 //! direction with a specific mode.
+//!
+//! # glTF
+//!
+//! glTF defines the front face of a triangle as the one whose vertices wind counter-clockwise and
+//! expects back faces to be culled, which is what [`FaceCulling::gltf_default`] (and the default
+//! face culling) does. Materials marked `doubleSided` must show both sides: culling is then
+//! disabled, which is what [`FaceCulling::double_sided`] represents:
+//!
+//! ```ignore
+//! let face_culling = if material.double_sided() {
+//!   FaceCulling::double_sided()
+//! } else {
+//!   Some(FaceCulling::gltf_default())
+//! };
+//!
+//! let render_st = RenderState::default().set_face_culling(face_culling);
+//! ```
 
 /// Face culling setup.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -15,6 +32,21 @@ impl FaceCulling {
   pub fn new(order: FaceCullingOrder, mode: FaceCullingMode) -> Self {
     FaceCulling { order, mode }
   }
+
+  /// Face culling expected by glTF: counter-clockwise front faces and back faces culled.
+  pub fn gltf_default() -> Self {
+    FaceCulling::new(FaceCullingOrder::CCW, FaceCullingMode::Back)
+  }
+
+  /// No face culling, for double-sided materials.
+  ///
+  /// Disabling face culling is expressed with [`None`], so that this can be passed directly to
+  /// [`RenderState::set_face_culling`].
+  ///
+  /// [`RenderState::set_face_culling`]: crate::render_state::RenderState::set_face_culling
+  pub fn double_sided() -> Option<Self> {
+    None
+  }
 }
 
 /// Default implementation of [`FaceCulling`].
@@ -42,6 +42,77 @@
 //! slot via [`Framebuffer::depth_slot`]. Once you get textures from the color slots, you can use
 //! them as regular textures as input of next renders, for instance.
 //!
+//! ## Renderbuffer slots
+//!
+//! If you don’t need to sample a slot — e.g. the depth buffer of a depth-only pre-pass or a
+//! transient color target — you can wrap its pixel format in a [`Renderbuffer`]. The attachment
+//! is then backed by a renderbuffer instead of a texture, which is usually cheaper, and the
+//! associated slot type is the [`Renderbuffer`] itself. Renderbuffer slots are only available
+//! with [`Dim2`] framebuffers.
+//!
+//! ## Depth-only framebuffers
+//!
+//! Shadow maps and depth pre-passes only need a depth attachment: use `()` as color slot. The
+//! framebuffer then doesn’t reference any color buffer at all, and you can render to it with
+//! [`RenderState::depth_only`] and a fragment shader doing nothing, which saves all the fragment
+//! color work.
+//!
+//! The depth texture can then be bound and sampled in a later pipeline. Create the framebuffer with
+//! a [`Sampler`] comparing depths — see [`Sampler::depth_comparison`] — to fetch it with a shadow
+//! sampler (e.g. `sampler2DShadow` in GLSL), which is bound as a regular floating texture binding.
+//!
+//! ## Stencil-only framebuffers
+//!
+//! Masking techniques might only need a stencil buffer: use a [`Renderbuffer`] of [`Stencil8`] as
+//! depth slot. The framebuffer then has a stencil buffer but no depth buffer, so that the depth
+//! test always passes and clearing depth has no effect.
+//!
+//! ## Layered framebuffers
+//!
+//! Framebuffers created with a layered dimension — such as [`Dim2Array`] or [`Cubemap`] — have
+//! all the layers of their textures attached at once: no layer is selected by the pipeline. A
+//! geometry shader picks the layer each primitive is rendered to by writing `gl_Layer`, which
+//! allows to render all the faces of a cubemap or all the cascades of a shadow map in a single
+//! pass. The viewport covers the size of a single layer.
+//!
+//! > Note: layered rendering requires geometry shaders, which WebGL2 doesn’t support.
+//!
+//! ## Sampling attachments and feedback loops
+//!
+//! Sampling a texture while it’s attached to the framebuffer being rendered to is a _feedback
+//! loop_, whose result is undefined. That situation cannot happen with luminance: a pipeline
+//! borrows its [`Framebuffer`] for the whole render, while binding a texture requires a mutable
+//! reference to it, which can only be obtained with [`Framebuffer::depth_slot`] or
+//! [`Framebuffer::color_slot`] — the borrow checker rejects the code.
+//!
+//! Multi-pass rendering reading back a previous pass’ depth is then done with two framebuffers:
+//! the first pass writes depth to a framebuffer with a depth texture slot, and the second pass
+//! renders to another framebuffer, binding the first one’s depth texture. If that second pass
+//! doesn’t need a depth buffer of its own, render it with the depth test disabled
+//! ([`RenderState::set_depth_test`] with `None`) and [`DepthWrite::Off`].
+//!
+//! ```ignore
+//! let mut depth_fb = ctx.new_framebuffer::<Dim2, (), Depth32F>(size, 0, Sampler::default())?;
+//! let color_fb = ctx.new_framebuffer::<Dim2, RGBA32F, ()>(size, 0, Sampler::default())?;
+//!
+//! // pass A: writes depth_fb’s depth
+//! pipeline_gate.pipeline(&depth_fb, &PipelineState::default(), |_, mut shd_gate| { /* … */ })?;
+//!
+//! // pass B: samples depth_fb’s depth while rendering to color_fb
+//! let depth_map = depth_fb.depth_slot();
+//! pipeline_gate.pipeline(&color_fb, &PipelineState::default(), |pipeline, mut shd_gate| {
+//!   let depth_map = pipeline.bind_texture(depth_map).expect("depth map binding");
+//!   /* … */
+//! })?;
+//! ```
+//!
+//! Effects that would read and write the same attachment — e.g. in-place tonemapping — are done
+//! the same way, by _ping-ponging_ between two framebuffers: each pass samples the color slot of
+//! the framebuffer written by the previous pass and renders to the other one. No texture barrier
+//! is then needed. On hardware supporting framebuffer fetch (see
+//! [`Capabilities::framebuffer_fetch`]), such effects can also read the current color directly in
+//! the fragment shader, in a single pass.
+//!
 //! ## Note on type generation
 //!
 //! Because framebuffers are highly subject to refinement typing, types are transformed at
@@ -59,6 +130,14 @@
 //! [backend::color_slot]: crate::backend::color_slot
 //! [backend::depth_slot]: crate::backend::depth_slot
 //! [`PipelineGate`]: crate::pipeline::PipelineGate
+//! [`Renderbuffer`]: crate::renderbuffer::Renderbuffer
+//! [`Stencil8`]: crate::pixel::Stencil8
+//! [`Dim2Array`]: crate::texture::Dim2Array
+//! [`Cubemap`]: crate::texture::Cubemap
+//! [`Capabilities::framebuffer_fetch`]: crate::capabilities::Capabilities::framebuffer_fetch
+//! [`RenderState::depth_only`]: crate::render_state::RenderState::depth_only
+//! [`RenderState::set_depth_test`]: crate::render_state::RenderState::set_depth_test
+//! [`DepthWrite::Off`]: crate::depth_test::DepthWrite::Off
 
 use std::error;
 use std::fmt;
@@ -67,6 +146,8 @@ use crate::backend::color_slot::ColorSlot;
 use crate::backend::depth_slot::DepthSlot;
 use crate::backend::framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer};
 use crate::context::GraphicsContext;
+use crate::pipeline::ClearColor;
+use crate::pixel::PixelFormat;
 use crate::texture::{Dim2, Dimensionable, Sampler, TextureError};
 
 /// Typed framebuffers.
@@ -146,6 +227,83 @@ where
     unsafe { B::framebuffer_size(&self.repr) }
   }
 
+  /// Resize the framebuffer.
+  ///
+  /// All the attachments (color and depth slots) get their storage reallocated to the new `size`,
+  /// keeping the same textures and attachment points. The completeness of the framebuffer is
+  /// checked again afterwards.
+  ///
+  /// The content of the attachments is lost.
+  pub fn resize(&mut self, size: D::Size) -> Result<(), FramebufferError> {
+    CS::resize_color_textures(&mut self.color_slot, size, &mut self.repr, 0)?;
+    DS::resize_depth_texture(&mut self.depth_slot, size, &mut self.repr)?;
+
+    unsafe { B::resize_framebuffer(&mut self.repr, size) }
+  }
+
+  /// Number of color attachments of the framebuffer.
+  ///
+  /// This is the number of fragment shader outputs the framebuffer can receive. The back buffer
+  /// doesn’t have any color attachment and returns `0`.
+  pub fn color_attachment_count(&self) -> usize {
+    CS::color_formats().len()
+  }
+
+  /// Pixel format of the color attachment at `index`, if any.
+  ///
+  /// Attachments are indexed in the same order as in the [`ColorSlot`] type — i.e. index `0` is
+  /// the first texture of the tuple.
+  pub fn color_format(&self, index: usize) -> Option<PixelFormat> {
+    CS::color_formats().get(index).copied()
+  }
+
+  /// Pixel format of the depth attachment, if any.
+  pub fn depth_format(&self) -> Option<PixelFormat> {
+    DS::depth_format()
+  }
+
+  /// Hint that the content of some attachments is not needed anymore.
+  ///
+  /// This is typically called once a pass is done with a transient depth / stencil buffer, or once
+  /// a multisampled framebuffer has been resolved. Tiled GPUs (mostly found on mobile) can then
+  /// skip writing the content of those attachments back to memory, saving a lot of bandwidth.
+  ///
+  /// The content of the invalidated attachments is undefined afterwards. This is only a hint: it
+  /// has no effect on backends or hardware that don’t support it, so it’s always safe to call.
+  pub fn invalidate(&mut self, attachments: &[Attachment]) {
+    unsafe { B::invalidate_framebuffer(&mut self.repr, attachments) }
+  }
+
+  /// Clear the color attachment at `index` with `clear_color`, leaving the other attachments
+  /// untouched.
+  ///
+  /// This is useful when attachments are cleared on different frames — e.g. an accumulation
+  /// buffer reset while the history buffer is kept. Pipelines clear all the color attachments at
+  /// once; disable that with [`PipelineState::enable_clear_color`] and clear the attachments you
+  /// want with this function before running the pipeline. `clear_color` is used according to the
+  /// pixel encoding of the attachment, as with [`PipelineState::clear_color`].
+  ///
+  /// [`FramebufferError::InvalidColorAttachment`] is returned if `index` is not lower than
+  /// [`Framebuffer::color_attachment_count`].
+  ///
+  /// [`PipelineState::enable_clear_color`]: crate::pipeline::PipelineState::enable_clear_color
+  /// [`PipelineState::clear_color`]: crate::pipeline::PipelineState::clear_color
+  pub fn clear_color_attachment<C>(
+    &mut self,
+    index: usize,
+    clear_color: C,
+  ) -> Result<(), FramebufferError>
+  where
+    C: Into<ClearColor>,
+  {
+    if index >= self.color_attachment_count() {
+      return Err(FramebufferError::invalid_color_attachment(index));
+    }
+
+    unsafe { B::clear_color_attachment(&mut self.repr, index, clear_color.into()) };
+    Ok(())
+  }
+
   /// Access the carried [`ColorSlot`].
   pub fn color_slot(&mut self) -> &mut CS::ColorTextures {
     &mut self.color_slot
@@ -208,6 +366,8 @@ pub enum FramebufferError {
   Incomplete(IncompleteReason),
   /// Cannot attach something to a framebuffer.
   UnsupportedAttachment,
+  /// No color attachment at the given index.
+  InvalidColorAttachment(usize),
 }
 
 impl FramebufferError {
@@ -230,6 +390,11 @@ impl FramebufferError {
   pub fn unsupported_attachment() -> Self {
     FramebufferError::UnsupportedAttachment
   }
+
+  /// No color attachment at the given index.
+  pub fn invalid_color_attachment(index: usize) -> Self {
+    FramebufferError::InvalidColorAttachment(index)
+  }
 }
 
 impl fmt::Display for FramebufferError {
@@ -244,6 +409,10 @@ impl fmt::Display for FramebufferError {
       FramebufferError::Incomplete(ref e) => write!(f, "incomplete framebuffer: {}", e),
 
       FramebufferError::UnsupportedAttachment => f.write_str("unsupported framebuffer attachment"),
+
+      FramebufferError::InvalidColorAttachment(ref index) => {
+        write!(f, "no color attachment at index {}", index)
+      }
     }
   }
 }
@@ -255,6 +424,7 @@ impl std::error::Error for FramebufferError {
       FramebufferError::TextureError(e) => Some(e),
       FramebufferError::Incomplete(e) => Some(e),
       FramebufferError::UnsupportedAttachment => None,
+      FramebufferError::InvalidColorAttachment(_) => None,
     }
   }
 }
@@ -271,6 +441,21 @@ impl From<IncompleteReason> for FramebufferError {
   }
 }
 
+/// Framebuffer attachment.
+///
+/// Used to designate the attachments of a [`Framebuffer`], e.g. with [`Framebuffer::invalidate`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Attachment {
+  /// Color attachment at the given index, in the same order as in the [`ColorSlot`] type.
+  ///
+  /// For the back buffer, any index designates its (only) color buffer.
+  Color(usize),
+  /// Depth attachment.
+  Depth,
+  /// Stencil attachment.
+  Stencil,
+}
+
 /// Reason a framebuffer is incomplete.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IncompleteReason {
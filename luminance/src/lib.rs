@@ -183,17 +183,28 @@
 
 pub mod backend;
 pub mod blending;
+pub mod block_layout;
 pub mod buffer;
+pub mod buffer_texture;
+pub mod capabilities;
+pub mod color_mask;
 pub mod context;
 pub mod depth_test;
 pub mod face_culling;
 pub mod framebuffer;
+pub mod loader;
+pub mod palette;
 pub mod pipeline;
 pub mod pixel;
+pub mod polygon_offset;
+pub mod provoking_vertex;
 pub mod render_gate;
 pub mod render_state;
+pub mod renderbuffer;
+pub mod scissor;
 pub mod shader;
 pub mod shading_gate;
+pub mod stencil;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
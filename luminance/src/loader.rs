@@ -0,0 +1,122 @@
+//! Deferred resource creation.
+//!
+//! Graphics contexts are bound to a single thread — the _render thread_ — so that GPU resources
+//! can only be created there. Loading assets (reading files, decoding images, building meshes,
+//! etc.) is however better done on worker threads, not to stall rendering.
+//!
+//! A [`ResourceLoader`] bridges both worlds. It lives on the render thread and hands out
+//! [`ResourceSender`]s, which can be sent to any thread. A worker thread prepares the data of a
+//! resource and submits a _creation request_ — a closure owning that data and creating the
+//! resource from a graphics context. Requests are queued until the render thread calls
+//! [`ResourceLoader::flush`], which runs them with its context and returns the created resources:
+//!
+//! ```ignore
+//! let mut loader = ResourceLoader::new();
+//! let sender = loader.sender();
+//!
+//! std::thread::spawn(move || {
+//!   let (size, texels) = decode_image("albedo.png");
+//!
+//!   sender.submit(move |ctx: &mut MyContext| {
+//!     let mut tex = ctx.new_texture(size, 0, Sampler::default())?;
+//!     tex.upload_raw(GenMipmaps::Yes, &texels)?;
+//!     Ok(("albedo", tex))
+//!   });
+//! });
+//!
+//! // once per frame, on the render thread
+//! for resource in loader.flush(&mut ctx) {
+//!   // store the resource, report the error, etc.
+//! }
+//! ```
+//!
+//! Because GPU resources cannot leave the render thread, the created resources are returned by
+//! [`ResourceLoader::flush`] rather than sent back to the workers. Identify them by returning a
+//! key along with them, as above.
+
+use crate::context::GraphicsContext;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// A creation request, run on the render thread.
+type Request<C, R> = Box<dyn FnOnce(&mut C) -> R + Send>;
+
+/// Queue of resource creation requests, flushed on the render thread.
+///
+/// # Parametricity
+///
+/// - `C` is the graphics context the requests are run with.
+/// - `R` is the type returned by the requests — typically a [`Result`] of the created resource
+///   and a key identifying it.
+pub struct ResourceLoader<C, R> {
+  sender: Sender<Request<C, R>>,
+  receiver: Receiver<Request<C, R>>,
+}
+
+impl<C, R> ResourceLoader<C, R>
+where
+  C: GraphicsContext,
+{
+  /// Create a new, empty resource loader.
+  pub fn new() -> Self {
+    let (sender, receiver) = channel();
+    ResourceLoader { sender, receiver }
+  }
+
+  /// Create a [`ResourceSender`] submitting requests to this loader.
+  ///
+  /// Senders can be cloned and sent to other threads.
+  pub fn sender(&self) -> ResourceSender<C, R> {
+    ResourceSender {
+      sender: self.sender.clone(),
+    }
+  }
+
+  /// Run all the pending creation requests with `ctx`, in submission order, and return their
+  /// results.
+  ///
+  /// Requests submitted while flushing are run by the next call.
+  pub fn flush(&mut self, ctx: &mut C) -> Vec<R> {
+    self
+      .receiver
+      .try_iter()
+      .collect::<Vec<_>>()
+      .into_iter()
+      .map(|request| request(ctx))
+      .collect()
+  }
+}
+
+impl<C, R> Default for ResourceLoader<C, R>
+where
+  C: GraphicsContext,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Submit resource creation requests to a [`ResourceLoader`] from any thread.
+pub struct ResourceSender<C, R> {
+  sender: Sender<Request<C, R>>,
+}
+
+impl<C, R> ResourceSender<C, R> {
+  /// Submit a creation request.
+  ///
+  /// The request will be run with the graphics context on the next [`ResourceLoader::flush`].
+  /// If the loader was dropped, the request is discarded and `false` is returned.
+  pub fn submit<F>(&self, request: F) -> bool
+  where
+    F: 'static + FnOnce(&mut C) -> R + Send,
+  {
+    self.sender.send(Box::new(request)).is_ok()
+  }
+}
+
+impl<C, R> Clone for ResourceSender<C, R> {
+  fn clone(&self) -> Self {
+    ResourceSender {
+      sender: self.sender.clone(),
+    }
+  }
+}
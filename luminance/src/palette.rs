@@ -0,0 +1,97 @@
+//! Matrix palettes.
+//!
+//! A matrix palette is a uniform buffer of 4×4 matrices, typically used to store the bone
+//! transforms of a skeleton for skeletal animation. On the shader side, it maps to a uniform
+//! block containing an array of `mat4`:
+//!
+//! ```glsl
+//! layout (std140) uniform Bones {
+//!   mat4 bones[64];
+//! };
+//! ```
+//!
+//! With the `std140` layout, a `mat4` is stored as four `vec4` columns of 16 bytes each, which
+//! is exactly the layout of a column-major `[[f32; 4]; 4]`. The palette stores such matrices
+//! contiguously, so that the whole array maps byte-for-byte to the shader block.
+//!
+//! Update the palette with [`MatrixPalette::update`] each frame, bind it in a pipeline with
+//! [`MatrixPalette::bind`] and pass the resulting [`BufferBinding`] to the uniform block.
+//!
+//! [`BufferBinding`]: crate::pipeline::BufferBinding
+
+use crate::backend::buffer::Buffer as BufferBackend;
+use crate::backend::pipeline::{PipelineBase, PipelineBuffer};
+use crate::buffer::{Buffer, BufferError, BufferUsage};
+use crate::context::GraphicsContext;
+use crate::pipeline::{BoundBuffer, Pipeline, PipelineError};
+
+/// A 4×4 matrix, stored column-major.
+pub type M44 = [[f32; 4]; 4];
+
+/// A buffer of [`M44`] matrices laid out to be used as a `std140` uniform block.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`backend::buffer::Buffer`].
+///
+/// [`backend::buffer::Buffer`]: crate::backend::buffer::Buffer
+pub struct MatrixPalette<B>
+where
+  B: BufferBackend<M44>,
+{
+  buffer: Buffer<B, M44>,
+}
+
+impl<B> MatrixPalette<B>
+where
+  B: BufferBackend<M44>,
+{
+  /// Create a new [`MatrixPalette`] able to hold `len` matrices, all initialized to identity.
+  pub fn new<C>(ctx: &mut C, len: usize) -> Result<Self, BufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let identity = [
+      [1., 0., 0., 0.],
+      [0., 1., 0., 0.],
+      [0., 0., 1., 0.],
+      [0., 0., 0., 1.],
+    ];
+    let buffer = Buffer::from_vec_with_usage(ctx, vec![identity; len], BufferUsage::DynamicDraw)?;
+
+    Ok(MatrixPalette { buffer })
+  }
+
+  /// Number of matrices in the palette.
+  pub fn len(&self) -> usize {
+    self.buffer.len()
+  }
+
+  /// Check whether the palette is empty (i.e. it has no matrices).
+  pub fn is_empty(&self) -> bool {
+    self.buffer.is_empty()
+  }
+
+  /// Update the first `matrices.len()` matrices of the palette.
+  ///
+  /// Only the updated region is uploaded, so it’s fine to pass fewer matrices than the palette
+  /// holds — for instance when a skeleton has fewer bones than the palette.
+  ///
+  /// # Errors
+  ///
+  /// [`BufferError::OutOfBounds`] is returned if more matrices are passed than the palette holds.
+  pub fn update(&mut self, matrices: &[M44]) -> Result<(), BufferError> {
+    self.buffer.write_part(0, matrices)
+  }
+
+  /// Bind the palette in a [`Pipeline`], so that it can be passed to a uniform block.
+  pub fn bind<'a>(
+    &'a mut self,
+    pipeline: &'a Pipeline<'a, B>,
+  ) -> Result<BoundBuffer<'a, B, M44>, PipelineError>
+  where
+    B: PipelineBase + PipelineBuffer<M44>,
+  {
+    pipeline.bind_buffer(&mut self.buffer)
+  }
+}
@@ -166,6 +166,33 @@
 //! Creating a [`PipelineGate`] requires two resources: a [`Framebuffer`] to render to, and a
 //! [`PipelineState`], allowing to customize how the pipeline will perform renders at runtime.
 //!
+//! ## Chaining passes
+//!
+//! A [`PipelineGate`] can run as many pipelines as you want, each rendering to its own
+//! [`Framebuffer`]. Pipelines are run in the order [`PipelineGate::pipeline`] is called, which
+//! makes a fixed pass graph — e.g. shadow → G-buffer → lighting → post-process — a plain sequence
+//! of calls. A later pass reads the outputs of earlier ones by binding their color or depth slots
+//! with [`Pipeline::bind_texture`]:
+//!
+//! ```ignore
+//! pipeline_gate.pipeline(&shadow_fb, &PipelineState::default(), |_, mut shd_gate| { /* … */ })?;
+//! pipeline_gate.pipeline(&gbuffer_fb, &PipelineState::default(), |_, mut shd_gate| { /* … */ })?;
+//!
+//! let shadow_map = shadow_fb.depth_slot();
+//! let (albedo, normal) = gbuffer_fb.color_slot();
+//! pipeline_gate.pipeline(&lighting_fb, &PipelineState::default(), |pipeline, mut shd_gate| {
+//!   let shadow_map = pipeline.bind_texture(shadow_map).expect("shadow map binding");
+//!   let albedo = pipeline.bind_texture(albedo).expect("albedo binding");
+//!   let normal = pipeline.bind_texture(normal).expect("normal binding");
+//!   /* … */
+//! })?;
+//! ```
+//!
+//! Passes don’t need any explicit barrier nor state restore between them: commands are executed
+//! in order by the GPU, every pipeline applies its own [`PipelineState`] and every render node
+//! its own [`RenderState`]. Texture bindings only live for the duration of the pipeline they were
+//! made in.
+//!
 //! # ShadingGate
 //!
 //! When you create a pipeline, you’re also handed a [`ShadingGate`]. A [`ShadingGate`] is an object
@@ -226,9 +253,11 @@ use crate::backend::color_slot::ColorSlot;
 use crate::backend::depth_slot::DepthSlot;
 use crate::backend::framebuffer::Framebuffer as FramebufferBackend;
 use crate::backend::pipeline::{
-  Pipeline as PipelineBackend, PipelineBase, PipelineBuffer, PipelineTexture,
+  Pipeline as PipelineBackend, PipelineAtomicCounterBuffer, PipelineBase, PipelineBuffer,
+  PipelineBufferTexture, PipelineTexture,
 };
-use crate::buffer::Buffer;
+use crate::buffer::{Buffer, BufferError};
+use crate::buffer_texture::BufferTexture;
 use crate::context::GraphicsContext;
 use crate::framebuffer::Framebuffer;
 use crate::pixel::Pixel;
@@ -253,6 +282,11 @@ impl fmt::Display for PipelineError {
 
 impl error::Error for PipelineError {}
 
+/// Binding point reserved for the frame uniform buffer.
+///
+/// See [`Pipeline::bind_frame_uniform_buffer`] for further details.
+pub const FRAME_UNIFORM_BUFFER_BINDING: u32 = 0;
+
 /// The viewport being part of the [`PipelineState`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Viewport {
@@ -272,19 +306,120 @@ pub enum Viewport {
   },
 }
 
+/// Eye rendered by a stereo pipeline.
+///
+/// See [`PipelineGate::stereo_pipeline`] for further details.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Eye {
+  /// Left eye, rendered to the left half of the viewport.
+  Left,
+  /// Right eye, rendered to the right half of the viewport.
+  Right,
+}
+
+/// Value to clear color attachments with.
+///
+/// Color attachments are cleared with the command matching their pixel encoding, which receives
+/// the value unchanged if the variant matches the encoding: [`ClearColor::Int`] for integral
+/// attachments, [`ClearColor::UInt`] for unsigned attachments and [`ClearColor::Float`] for the
+/// other ones. That is required to clear to exact integer values, such as a `u32::MAX` sentinel
+/// in a [`R32UI`] picking attachment, which a `f32` cannot represent.
+///
+/// If the variant doesn’t match the encoding of an attachment, the value is converted with `as`
+/// casts — e.g. `[42., 0., 0., 0.]` clears an unsigned attachment with `42`.
+///
+/// [`R32UI`]: crate::pixel::R32UI
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClearColor {
+  /// Floating-point color, for normalized and floating-point attachments.
+  Float([f32; 4]),
+  /// Signed integer color, for integral attachments.
+  Int([i32; 4]),
+  /// Unsigned integer color, for unsigned attachments.
+  UInt([u32; 4]),
+}
+
+impl ClearColor {
+  /// Get the color as floating-point components, casting them if needed.
+  pub fn as_float(self) -> [f32; 4] {
+    match self {
+      ClearColor::Float(c) => c,
+      ClearColor::Int(c) => [c[0] as f32, c[1] as f32, c[2] as f32, c[3] as f32],
+      ClearColor::UInt(c) => [c[0] as f32, c[1] as f32, c[2] as f32, c[3] as f32],
+    }
+  }
+
+  /// Get the color as signed integer components, casting them if needed.
+  pub fn as_int(self) -> [i32; 4] {
+    match self {
+      ClearColor::Float(c) => [c[0] as i32, c[1] as i32, c[2] as i32, c[3] as i32],
+      ClearColor::Int(c) => c,
+      ClearColor::UInt(c) => [c[0] as i32, c[1] as i32, c[2] as i32, c[3] as i32],
+    }
+  }
+
+  /// Get the color as unsigned integer components, casting them if needed.
+  pub fn as_uint(self) -> [u32; 4] {
+    match self {
+      ClearColor::Float(c) => [c[0] as u32, c[1] as u32, c[2] as u32, c[3] as u32],
+      ClearColor::Int(c) => [c[0] as u32, c[1] as u32, c[2] as u32, c[3] as u32],
+      ClearColor::UInt(c) => c,
+    }
+  }
+}
+
+impl From<[f32; 4]> for ClearColor {
+  fn from(color: [f32; 4]) -> Self {
+    ClearColor::Float(color)
+  }
+}
+
+impl From<[i32; 4]> for ClearColor {
+  fn from(color: [i32; 4]) -> Self {
+    ClearColor::Int(color)
+  }
+}
+
+impl From<[u32; 4]> for ClearColor {
+  fn from(color: [u32; 4]) -> Self {
+    ClearColor::UInt(color)
+  }
+}
+
 /// Various customization options for pipelines.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
 pub struct PipelineState {
   /// Color to use when clearing buffers.
-  pub clear_color: [f32; 4],
+  ///
+  /// Color attachments are cleared according to their pixel encoding; see [`ClearColor`] for
+  /// further details.
+  ///
+  /// When [sRGB](PipelineState::srgb_enabled) is enabled, the clear color of sRGB attachments is
+  /// treated as linear and encoded to sRGB by the hardware, exactly like fragment outputs. If the
+  /// color was picked in the sRGB colorspace, convert it with [`srgb_to_linear`] first; otherwise,
+  /// the cleared color will look too bright.
+  ///
+  /// [`srgb_to_linear`]: crate::pixel::srgb_to_linear
+  pub clear_color: ClearColor,
   /// Whether clearing color buffers.
   pub clear_color_enabled: bool,
+  /// Depth value to use when clearing buffers.
+  ///
+  /// Use `0.` with reverse-Z depth buffers.
+  pub clear_depth: f32,
   /// Whether clearing depth buffers.
   pub clear_depth_enabled: bool,
+  /// Stencil value to use when clearing buffers.
+  pub clear_stencil: i32,
+  /// Whether clearing stencil buffers.
+  pub clear_stencil_enabled: bool,
   /// Viewport to use when rendering.
   pub viewport: Viewport,
   /// Whether [sRGB](https://en.wikipedia.org/wiki/SRGB) should be enabled.
+  ///
+  /// When enabled, the linear colors written to sRGB attachments — by shaders or when clearing —
+  /// are encoded to sRGB by the hardware. It doesn’t have any effect on non-sRGB attachments.
   pub srgb_enabled: bool,
 }
 
@@ -293,14 +428,20 @@ impl Default for PipelineState {
   ///
   /// - Clear color: `[0, 0, 0, 1]`.
   /// - Color is always cleared.
+  /// - Clear depth: `1`.
   /// - Depth is always cleared.
+  /// - Clear stencil: `0`.
+  /// - Stencil is never cleared.
   /// - The viewport uses the whole framebuffer’s.
   /// - sRGB encoding is disabled.
   fn default() -> Self {
     PipelineState {
-      clear_color: [0., 0., 0., 1.],
+      clear_color: ClearColor::Float([0., 0., 0., 1.]),
       clear_color_enabled: true,
+      clear_depth: 1.,
       clear_depth_enabled: true,
+      clear_stencil: 0,
+      clear_stencil_enabled: false,
       viewport: Viewport::Whole,
       srgb_enabled: false,
     }
@@ -316,14 +457,20 @@ impl PipelineState {
   }
 
   /// Get the clear color.
-  pub fn clear_color(&self) -> [f32; 4] {
+  pub fn clear_color(&self) -> ClearColor {
     self.clear_color
   }
 
   /// Set the clear color.
-  pub fn set_clear_color(self, clear_color: [f32; 4]) -> Self {
+  ///
+  /// Pass a `[f32; 4]` for a floating-point color, or a `[i32; 4]` / `[u32; 4]` to clear integral
+  /// / unsigned attachments with exact integer values.
+  pub fn set_clear_color<C>(self, clear_color: C) -> Self
+  where
+    C: Into<ClearColor>,
+  {
     Self {
-      clear_color,
+      clear_color: clear_color.into(),
       ..self
     }
   }
@@ -341,6 +488,19 @@ impl PipelineState {
     }
   }
 
+  /// Get the clear depth.
+  pub fn clear_depth(&self) -> f32 {
+    self.clear_depth
+  }
+
+  /// Set the clear depth.
+  pub fn set_clear_depth(self, clear_depth: f32) -> Self {
+    Self {
+      clear_depth,
+      ..self
+    }
+  }
+
   /// Check whether the pipeline’s framebuffer’s depth buffer will be cleared.
   pub fn is_clear_depth_enabled(&self) -> bool {
     self.clear_depth_enabled
@@ -354,6 +514,50 @@ impl PipelineState {
     }
   }
 
+  /// Get the clear stencil.
+  pub fn clear_stencil(&self) -> i32 {
+    self.clear_stencil
+  }
+
+  /// Set the clear stencil.
+  pub fn set_clear_stencil(self, clear_stencil: i32) -> Self {
+    Self {
+      clear_stencil,
+      ..self
+    }
+  }
+
+  /// Check whether the pipeline’s framebuffer’s stencil buffer will be cleared.
+  pub fn is_clear_stencil_enabled(&self) -> bool {
+    self.clear_stencil_enabled
+  }
+
+  /// Enable clearing stencil buffers.
+  pub fn enable_clear_stencil(self, clear_stencil_enabled: bool) -> Self {
+    Self {
+      clear_stencil_enabled,
+      ..self
+    }
+  }
+
+  /// Enable or disable clearing all buffers — color, depth and stencil — at once.
+  ///
+  /// This is handy when several pipelines render to the same framebuffer in a frame: only the
+  /// first one should clear, and the next ones accumulate on top of its render.
+  ///
+  /// ```ignore
+  /// let first_pass = PipelineState::default().enable_clear_stencil(true);
+  /// let next_passes = first_pass.clone().enable_clear(false);
+  /// ```
+  pub fn enable_clear(self, clear_enabled: bool) -> Self {
+    Self {
+      clear_color_enabled: clear_enabled,
+      clear_depth_enabled: clear_enabled,
+      clear_stencil_enabled: clear_enabled,
+      ..self
+    }
+  }
+
   /// Get the viewport.
   pub fn viewport(&self) -> Viewport {
     self.viewport
@@ -420,6 +624,61 @@ where
     }
   }
 
+  /// Bind a buffer as the _frame uniform buffer_.
+  ///
+  /// The frame uniform buffer holds data shared by every draw of the pipeline — camera matrices,
+  /// time, etc. Contrary to [`Pipeline::bind_buffer`], which binds buffers at binding points
+  /// chosen dynamically, it is always bound at the reserved [`FRAME_UNIFORM_BUFFER_BINDING`]
+  /// point, which no other buffer ever uses. It is then bound once for the whole pipeline and
+  /// every shader program declaring the block sees it without any per-command work:
+  ///
+  /// - If the shaders can set the binding point of the block (e.g. with
+  ///   `layout (std140, binding = 0) uniform FrameData { … };`), nothing else has to be done.
+  /// - Otherwise, the [`BufferBinding`] of the returned [`BoundBuffer`] has to be set on the
+  ///   uniform interface of each program. Because the binding point never changes, that value is
+  ///   the same across frames.
+  ///
+  /// Once the buffer is bound, the [`BoundBuffer`] object has to be dropped / die in order to
+  /// bind the buffer again.
+  pub fn bind_frame_uniform_buffer<T>(
+    &'a self,
+    buffer: &'a mut Buffer<B, T>,
+  ) -> Result<BoundBuffer<'a, B, T>, PipelineError>
+  where
+    B: PipelineBuffer<T>,
+    T: Copy,
+  {
+    unsafe {
+      B::bind_frame_uniform_buffer(&self.repr, &buffer.repr).map(|repr| BoundBuffer {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Bind a buffer of atomic counters at a given binding point.
+  ///
+  /// Each item of the [`Buffer`] is an atomic counter. Contrary to [`Pipeline::bind_buffer`], the
+  /// binding point is chosen by you, as atomic counter bindings must be set in the shader sources
+  /// (e.g. `layout (binding = 0) uniform atomic_uint count;`).
+  ///
+  /// Once the buffer is bound, the [`BoundAtomicCounterBuffer`] object has to be dropped / die in
+  /// order to bind the buffer again.
+  pub fn bind_atomic_counter_buffer(
+    &'a self,
+    buffer: &'a mut Buffer<B, u32>,
+    binding: u32,
+  ) -> Result<BoundAtomicCounterBuffer<'a, B>, PipelineError>
+  where
+    B: PipelineAtomicCounterBuffer,
+  {
+    unsafe {
+      B::bind_atomic_counter_buffer(&self.repr, &buffer.repr, binding)?;
+    }
+
+    Ok(BoundAtomicCounterBuffer { buffer, binding })
+  }
+
   /// Bind a texture.
   ///
   /// Once the texture is bound, the [`BoundTexture`] object has to be dropped / die in order to
@@ -440,6 +699,26 @@ where
       })
     }
   }
+
+  /// Bind a buffer texture.
+  ///
+  /// Once the buffer texture is bound, the [`BoundBufferTexture`] object has to be dropped / die
+  /// in order to bind the buffer texture again.
+  pub fn bind_buffer_texture<P>(
+    &'a self,
+    texture: &'a mut BufferTexture<B, P>,
+  ) -> Result<BoundBufferTexture<'a, B, P>, PipelineError>
+  where
+    B: PipelineBufferTexture<P>,
+    P: Pixel,
+  {
+    unsafe {
+      B::bind_buffer_texture(&self.repr, &texture.repr).map(|repr| BoundBufferTexture {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
 }
 
 /// Top-most node in a graphics pipeline.
@@ -478,6 +757,10 @@ where
   /// [`PipelineState`] and a closure that allows to go deeper in the pipeline (i.e. resource
   /// graph). The closure is passed a [`Pipeline`] for you to dynamically alter the pipeline and a
   /// [`ShadingGate`] to enter shading nodes.
+  ///
+  /// Once the [`Pipeline`] is dropped — at the latest when the closure returns — the textures and
+  /// buffers bound with it are unbound, so that no stale binding is left behind for the next
+  /// pipelines or for external code sharing the context.
   pub fn pipeline<D, CS, DS, F>(
     &mut self,
     framebuffer: &Framebuffer<B, D, CS, DS>,
@@ -510,6 +793,92 @@ where
     f(pipeline, shading_gate);
     Ok(())
   }
+
+  /// Enter a pipeline node twice, once per [`Eye`], rendering them side by side.
+  ///
+  /// The viewport of `pipeline_state` — the whole framebuffer for [`Viewport::Whole`] — is split
+  /// in two halves: the left eye is rendered to the left half and the right eye to the right one.
+  /// The closure is called for each eye, in that order, with a fresh [`Pipeline`] and
+  /// [`ShadingGate`]; use the [`Eye`] to pick the matching view and projection matrices. Only
+  /// the left eye pass clears the framebuffer, so that the right eye doesn’t erase it.
+  ///
+  /// This is the way to render stereo images (e.g. for VR) on hardware without multiview
+  /// support.
+  pub fn stereo_pipeline<D, CS, DS, F>(
+    &mut self,
+    framebuffer: &Framebuffer<B, D, CS, DS>,
+    pipeline_state: &PipelineState,
+    mut f: F,
+  ) -> Result<(), PipelineError>
+  where
+    B: FramebufferBackend<D> + PipelineBackend<D>,
+    D: Dimensionable,
+    CS: ColorSlot<B, D>,
+    DS: DepthSlot<B, D>,
+    F: for<'b> FnMut(Eye, Pipeline<'b, B>, ShadingGate<'b, B>),
+  {
+    let (x, y, width, height) = match pipeline_state.viewport {
+      Viewport::Whole => {
+        let size = framebuffer.size();
+        (0, 0, D::width(size), D::height(size))
+      }
+
+      Viewport::Specific {
+        x,
+        y,
+        width,
+        height,
+      } => (x, y, width, height),
+    };
+    let left_width = width / 2;
+
+    let left_state = pipeline_state.clone().set_viewport(Viewport::Specific {
+      x,
+      y,
+      width: left_width,
+      height,
+    });
+    let right_state = pipeline_state
+      .clone()
+      .set_viewport(Viewport::Specific {
+        x: x + left_width,
+        y,
+        width: width - left_width,
+        height,
+      })
+      .enable_clear(false);
+
+    self.pipeline(framebuffer, &left_state, |pipeline, shd_gate| {
+      f(Eye::Left, pipeline, shd_gate)
+    })?;
+    self.pipeline(framebuffer, &right_state, |pipeline, shd_gate| {
+      f(Eye::Right, pipeline, shd_gate)
+    })
+  }
+
+  /// Block until all the commands issued so far have been completely executed by the GPU.
+  ///
+  /// This stalls the CPU until the GPU is idle, which kills any CPU / GPU parallelism: don’t call
+  /// it every frame in production code. It is however useful for benchmarking and deterministic
+  /// frame captures.
+  pub fn finish(&mut self)
+  where
+    B: PipelineBase,
+  {
+    unsafe { self.backend.finish() }
+  }
+
+  /// Force all the commands issued so far to be sent to the GPU, without waiting for their
+  /// completion.
+  ///
+  /// This is much cheaper than [`PipelineGate::finish`], as it doesn’t block, and is required by
+  /// some interop scenarios where another context or API must see the issued commands.
+  pub fn flush(&mut self)
+  where
+    B: PipelineBase,
+  {
+    unsafe { self.backend.flush() }
+  }
 }
 
 /// Opaque buffer binding.
@@ -591,6 +960,47 @@ where
   }
 }
 
+/// A _bound_ [`Buffer`] of atomic counters.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineAtomicCounterBuffer`].
+///
+/// # Notes
+///
+/// The counters can be reset with [`BoundAtomicCounterBuffer::reset`] before rendering and read
+/// back with [`BoundAtomicCounterBuffer::counters`] once the renders using them are done — for
+/// instance to get allocation counts.
+pub struct BoundAtomicCounterBuffer<'a, B>
+where
+  B: ?Sized + PipelineAtomicCounterBuffer,
+{
+  buffer: &'a mut Buffer<B, u32>,
+  binding: u32,
+}
+
+impl<'a, B> BoundAtomicCounterBuffer<'a, B>
+where
+  B: ?Sized + PipelineAtomicCounterBuffer,
+{
+  /// Binding point the atomic counters are bound at.
+  pub fn binding(&self) -> u32 {
+    self.binding
+  }
+
+  /// Reset all the atomic counters to zero.
+  pub fn reset(&mut self) -> Result<(), BufferError> {
+    self.buffer.clear(0)
+  }
+
+  /// Read back the current values of the atomic counters.
+  ///
+  /// The values are fetched from the GPU, so that increments performed by shaders are visible.
+  pub fn counters(&mut self) -> Result<Vec<u32>, BufferError> {
+    unsafe { B::atomic_counters(&mut self.buffer.repr) }
+  }
+}
+
 /// Opaque texture binding.
 ///
 /// This type represents a bound [`Texture`] via [`BoundTexture`]. It can be used along with a
@@ -675,3 +1085,81 @@ where
     }
   }
 }
+
+/// Opaque buffer texture binding.
+///
+/// This type represents a bound [`BufferTexture`] via [`BoundBufferTexture`]. It can be used along
+/// with a [`Uniform`] to customize a shader’s behavior.
+///
+/// # Parametricity
+///
+/// - `S` is the sampler type. It must implement [`SamplerType`] in most useful methods.
+///
+/// # Notes
+///
+/// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+/// an opaque object.
+///
+/// [`Uniform`]: crate::shader::Uniform
+/// [`SamplerType`]: crate::pixel::SamplerType
+#[derive(Debug)]
+pub struct BufferTextureBinding<S> {
+  binding: u32,
+  _phantom: PhantomData<*const S>,
+}
+
+impl<S> BufferTextureBinding<S> {
+  /// Access the underlying binding value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn binding(self) -> u32 {
+    self.binding
+  }
+}
+
+/// A _bound_ [`BufferTexture`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineBufferTexture`].
+/// - `P` is the pixel type. It must implement [`Pixel`].
+///
+/// # Notes
+///
+/// Once a [`BufferTexture`] is bound, it can be used and passed around to shaders. In order to do
+/// so, you will need to pass a [`BufferTextureBinding`] to your [`ProgramInterface`]. That value is
+/// unique to each [`BoundBufferTexture`] and should always be asked — you shouldn’t cache them,
+/// for instance.
+///
+/// [`ProgramInterface`]: crate::shader::ProgramInterface
+pub struct BoundBufferTexture<'a, B, P>
+where
+  B: PipelineBufferTexture<P>,
+  P: Pixel,
+{
+  pub(crate) repr: B::BoundBufferTextureRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B, P> BoundBufferTexture<'a, B, P>
+where
+  B: PipelineBufferTexture<P>,
+  P: Pixel,
+{
+  /// Obtain a [`BufferTextureBinding`] object that can be used to refer to this bound buffer
+  /// texture in shader stages.
+  ///
+  /// # Notes
+  ///
+  /// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+  /// an opaque object.
+  pub fn binding(&self) -> BufferTextureBinding<P::SamplerType> {
+    let binding = unsafe { B::buffer_texture_binding(&self.repr) };
+    BufferTextureBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
@@ -59,14 +59,19 @@ impl PixelFormat {
   /// Does a [`PixelFormat`] represent a color?
   pub fn is_color_pixel(self) -> bool {
     match self.format {
-      Format::Depth(_) => false,
+      Format::Depth(_) | Format::Stencil(_) => false,
       _ => true,
     }
   }
 
   /// Does a [`PixelFormat`] represent depth information?
   pub fn is_depth_pixel(self) -> bool {
-    !self.is_color_pixel()
+    matches!(self.format, Format::Depth(_))
+  }
+
+  /// Does a [`PixelFormat`] represent stencil information?
+  pub fn is_stencil_pixel(self) -> bool {
+    matches!(self.format, Format::Stencil(_))
   }
 
   /// Return the number of canals.
@@ -79,6 +84,7 @@ impl PixelFormat {
       Format::SRGB(_, _, _) => 3,
       Format::SRGBA(_, _, _, _) => 4,
       Format::Depth(_) => 1,
+      Format::Stencil(_) => 1,
     }
   }
 }
@@ -126,6 +132,8 @@ pub enum Format {
   SRGBA(Size, Size, Size, Size),
   /// Holds a depth channel.
   Depth(Size),
+  /// Holds a stencil channel.
+  Stencil(Size),
 }
 
 impl Format {
@@ -139,6 +147,7 @@ impl Format {
       Format::SRGB(r, g, b) => r.bits() + g.bits() + b.bits(),
       Format::SRGBA(r, g, b, a) => r.bits() + g.bits() + b.bits() + a.bits(),
       Format::Depth(d) => d.bits(),
+      Format::Stencil(s) => s.bits(),
     };
 
     bits / 8
@@ -360,6 +369,17 @@ impl_Pixel!(
 impl_ColorPixel!(NormR32UI);
 impl_RenderablePixel!(NormR32UI);
 
+/// A red 16-bit floating pixel format.
+///
+/// Texels are passed to and retrieved from the GPU as 32-bit floating values and converted by the
+/// backend.
+#[derive(Clone, Copy, Debug)]
+pub struct R16F;
+
+impl_Pixel!(R16F, f32, f32, Floating, Format::R(Size::Sixteen));
+impl_ColorPixel!(R16F);
+impl_RenderablePixel!(R16F);
+
 /// A red 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct R32F;
@@ -536,6 +556,23 @@ impl_Pixel!(
 impl_ColorPixel!(NormRG32UI);
 impl_RenderablePixel!(NormRG32UI);
 
+/// A red and green 16-bit floating pixel format.
+///
+/// Texels are passed to and retrieved from the GPU as 32-bit floating values and converted by the
+/// backend.
+#[derive(Clone, Copy, Debug)]
+pub struct RG16F;
+
+impl_Pixel!(
+  RG16F,
+  (f32, f32),
+  f32,
+  Floating,
+  Format::RG(Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RG16F);
+impl_RenderablePixel!(RG16F);
+
 /// A red and green 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RG32F;
@@ -724,6 +761,23 @@ impl_Pixel!(
 impl_ColorPixel!(NormRGB32UI);
 impl_RenderablePixel!(NormRGB32UI);
 
+/// A red, green and blue 16-bit floating pixel format.
+///
+/// Texels are passed to and retrieved from the GPU as 32-bit floating values and converted by the
+/// backend.
+#[derive(Clone, Copy, Debug)]
+pub struct RGB16F;
+
+impl_Pixel!(
+  RGB16F,
+  (f32, f32, f32),
+  f32,
+  Floating,
+  Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RGB16F);
+impl_RenderablePixel!(RGB16F);
+
 /// A red, green and blue 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RGB32F;
@@ -932,6 +986,23 @@ impl_Pixel!(
 impl_ColorPixel!(NormRGBA32UI);
 impl_RenderablePixel!(NormRGBA32UI);
 
+/// A red, green, blue and alpha 16-bit floating pixel format.
+///
+/// This is the typical format of HDR render targets. Texels are passed to and retrieved from the
+/// GPU as 32-bit floating values and converted by the backend.
+#[derive(Clone, Copy, Debug)]
+pub struct RGBA16F;
+
+impl_Pixel!(
+  RGBA16F,
+  (f32, f32, f32, f32),
+  f32,
+  Floating,
+  Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RGBA16F);
+impl_RenderablePixel!(RGBA16F);
+
 /// A red, green, blue and alpha 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RGBA32F;
@@ -1003,3 +1074,120 @@ pub struct Depth32F;
 
 impl_Pixel!(Depth32F, f32, f32, Floating, Format::Depth(Size::ThirtyTwo));
 impl_DepthPixel!(Depth32F);
+
+/// A stencil 8-bit unsigned integral pixel format.
+///
+/// Stencil-only attachments must be [`Renderbuffer`]s: wrap that pixel format in a [`Renderbuffer`]
+/// to use it as the depth slot of a framebuffer, which then has a stencil buffer but no depth
+/// buffer. Creating a [`Texture`] with it fails.
+///
+/// [`Renderbuffer`]: crate::renderbuffer::Renderbuffer
+/// [`Texture`]: crate::texture::Texture
+#[derive(Clone, Copy, Debug)]
+pub struct Stencil8;
+
+impl_Pixel!(Stencil8, u8, u8, Unsigned, Format::Stencil(Size::Eight));
+
+/// Convert a color from the sRGB colorspace to the linear colorspace.
+///
+/// The alpha channel is linear in both colorspaces and is left untouched. Use this function to
+/// convert colors picked in an image editor — which are sRGB — before passing them to shaders or
+/// as clear color of a pipeline rendering with sRGB enabled.
+pub fn srgb_to_linear(color: [f32; 4]) -> [f32; 4] {
+  fn convert(c: f32) -> f32 {
+    if c <= 0.04045 {
+      c / 12.92
+    } else {
+      ((c + 0.055) / 1.055).powf(2.4)
+    }
+  }
+
+  [
+    convert(color[0]),
+    convert(color[1]),
+    convert(color[2]),
+    color[3],
+  ]
+}
+
+/// Convert a color from the linear colorspace to the sRGB colorspace.
+///
+/// The alpha channel is linear in both colorspaces and is left untouched. This is the inverse of
+/// [`srgb_to_linear`].
+pub fn linear_to_srgb(color: [f32; 4]) -> [f32; 4] {
+  fn convert(c: f32) -> f32 {
+    if c <= 0.0031308 {
+      c * 12.92
+    } else {
+      1.055 * c.powf(1. / 2.4) - 0.055
+    }
+  }
+
+  [
+    convert(color[0]),
+    convert(color[1]),
+    convert(color[2]),
+    color[3],
+  ]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EPSILON: f32 = 1e-5;
+
+  fn assert_close(a: [f32; 4], b: [f32; 4]) {
+    for (x, y) in a.iter().zip(&b) {
+      assert!((x - y).abs() <= EPSILON, "{:?} != {:?}", a, b);
+    }
+  }
+
+  #[test]
+  fn srgb_conversions_keep_ends() {
+    assert_eq!(srgb_to_linear([0., 0., 0., 1.]), [0., 0., 0., 1.]);
+    assert_close(srgb_to_linear([1., 1., 1., 1.]), [1., 1., 1., 1.]);
+    assert_eq!(linear_to_srgb([0., 0., 0., 1.]), [0., 0., 0., 1.]);
+    assert_close(linear_to_srgb([1., 1., 1., 1.]), [1., 1., 1., 1.]);
+  }
+
+  #[test]
+  fn srgb_conversions_are_continuous_at_knee() {
+    let below = 0.04045;
+    let above = 0.04045 + EPSILON;
+    assert_close(
+      srgb_to_linear([below; 4]),
+      [0.0031308, 0.0031308, 0.0031308, below],
+    );
+    assert_close(
+      srgb_to_linear([above; 4]),
+      [0.0031308, 0.0031308, 0.0031308, above],
+    );
+
+    let below = 0.0031308;
+    let above = 0.0031308 + EPSILON / 12.92;
+    assert_close(
+      linear_to_srgb([below; 4]),
+      [0.04045, 0.04045, 0.04045, below],
+    );
+    assert_close(
+      linear_to_srgb([above; 4]),
+      [0.04045, 0.04045, 0.04045, above],
+    );
+  }
+
+  #[test]
+  fn srgb_conversions_round_trip() {
+    for &c in &[0., 0.001, 0.04045, 0.2, 0.5, 0.8, 1.] {
+      let color = [c, c, c, 0.5];
+      assert_close(linear_to_srgb(srgb_to_linear(color)), color);
+      assert_close(srgb_to_linear(linear_to_srgb(color)), color);
+    }
+  }
+
+  #[test]
+  fn srgb_conversions_keep_alpha() {
+    assert_eq!(srgb_to_linear([0.5, 0.5, 0.5, 0.25])[3], 0.25);
+    assert_eq!(linear_to_srgb([0.5, 0.5, 0.5, 0.25])[3], 0.25);
+  }
+}
@@ -0,0 +1,57 @@
+//! Polygon offset.
+//!
+//! The polygon offset biases the depth of rasterized polygons before the depth test and depth
+//! write occur. It is mostly used when rendering shadow maps, in order to fight _shadow acne_ —
+//! the self-shadowing pattern appearing on lit surfaces because of the limited resolution and
+//! precision of the shadow map. Start with [`PolygonOffset::shadow_map`] and tune it from there:
+//!
+//! - Increase [`PolygonOffset::factor`] if acne remains on surfaces at grazing angles to the
+//!   light, as the offset it yields grows with the slope of the polygons.
+//! - Increase [`PolygonOffset::units`] if acne remains on surfaces facing the light.
+//! - Decrease both if shadows detach from the objects casting them (_peter panning_).
+//!
+//! Rendering only the back faces of closed meshes in shadow map passes — i.e. culling front faces
+//! with a [`FaceCulling`] — also reduces acne a lot, as the depth written is then the one of the
+//! surfaces not facing the light, which are in shadow anyway.
+//!
+//! [`FaceCulling`]: crate::face_culling::FaceCulling
+
+/// Depth offset applied to rasterized polygons.
+///
+/// The offset added to the depth of each fragment is `factor * dz + units * r`, where `dz` is the
+/// depth slope of the polygon and `r` is the smallest resolvable depth difference of the depth
+/// buffer. Positive values push polygons away from the viewer.
+///
+/// The offset only applies to polygons rasterized as filled triangles.
+///
+/// Polygon offsets are compared bit by bit, so that they implement [`Eq`]: `0.` and `-0.` are
+/// different, while `NaN` equals itself.
+#[derive(Clone, Copy, Debug)]
+pub struct PolygonOffset {
+  /// Offset scaling with the depth slope of polygons.
+  pub factor: f32,
+  /// Constant offset, in smallest resolvable depth differences.
+  pub units: f32,
+}
+
+impl PolygonOffset {
+  /// Create a new [`PolygonOffset`].
+  pub fn new(factor: f32, units: f32) -> Self {
+    PolygonOffset { factor, units }
+  }
+
+  /// Polygon offset reasonably fighting shadow acne in shadow map passes.
+  ///
+  /// That is a `factor` of `2` and `units` of `4`. See the [module documentation](self) to tune it.
+  pub fn shadow_map() -> Self {
+    PolygonOffset::new(2., 4.)
+  }
+}
+
+impl PartialEq for PolygonOffset {
+  fn eq(&self, rhs: &Self) -> bool {
+    self.factor.to_bits() == rhs.factor.to_bits() && self.units.to_bits() == rhs.units.to_bits()
+  }
+}
+
+impl Eq for PolygonOffset {}
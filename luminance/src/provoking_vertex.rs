@@ -0,0 +1,25 @@
+//! Provoking vertex.
+//!
+//! Vertex shader outputs declared `flat` are not interpolated across primitives: every fragment
+//! of a primitive gets the value output for a single vertex of that primitive, called the
+//! _provoking vertex_. Flat-shaded meshes usually store the face color or normal on either the
+//! first or the last vertex of each face, which must match the [`ProvokingVertex`] used to render
+//! them.
+
+/// Vertex of a primitive providing the values of `flat` vertex shader outputs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProvokingVertex {
+  /// The first vertex of each primitive is the provoking vertex.
+  First,
+  /// The last vertex of each primitive is the provoking vertex.
+  Last,
+}
+
+/// Default implementation of [`ProvokingVertex`].
+///
+/// The default provoking vertex is [`ProvokingVertex::Last`], which is the default of OpenGL.
+impl Default for ProvokingVertex {
+  fn default() -> Self {
+    ProvokingVertex::Last
+  }
+}
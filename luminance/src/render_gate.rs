@@ -6,8 +6,16 @@
 //! [`Tess`]: crate::tess::Tess
 
 use crate::backend::render_gate::RenderGate as RenderGateBackend;
+use crate::backend::tess_gate::TessGate as TessGateBackend;
 use crate::render_state::RenderState;
+use crate::tess::{TessError, TessIndex, TessVertexData, TessView};
 use crate::tess_gate::TessGate;
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// whether the blending without color channels warning was already logged
+#[cfg(debug_assertions)]
+static BLENDING_WITHOUT_COLOR_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// A render gate.
 ///
@@ -26,17 +34,71 @@ where
   B: ?Sized + RenderGateBackend,
 {
   /// Enter a [`RenderGate`] and go deeper in the pipeline.
-  pub fn render<'b, F>(&'b mut self, rdr_st: &RenderState, f: F)
+  ///
+  /// If the [`RenderState`] has a debug label (see [`RenderState::set_debug_label`]), everything
+  /// rendered in the closure is wrapped in a debug group with that label in debug builds.
+  ///
+  /// In debug builds, a warning is logged the first time a [`RenderState`] enables blending while
+  /// its color mask disables all the color channels, as nothing is then blended. It’s logged only
+  /// once, so that renders issued every frame don’t flood the logs.
+  pub fn render<F>(&mut self, rdr_st: &RenderState, f: F)
   where
-    F: FnOnce(TessGate<'b, B>),
+    F: for<'b> FnOnce(TessGate<'b, B>),
   {
+    #[cfg(debug_assertions)]
+    if rdr_st.blending().is_some()
+      && rdr_st.color_mask().is_none()
+      && !BLENDING_WITHOUT_COLOR_WARNED.swap(true, Ordering::Relaxed)
+    {
+      log::warn!(
+        "blending is enabled but the color mask disables all channels; nothing is blended"
+      );
+    }
+
+    #[cfg(debug_assertions)]
+    if let Some(label) = rdr_st.debug_label() {
+      unsafe { self.backend.push_debug_group(label) };
+    }
+
     unsafe {
       self.backend.enter_render_state(rdr_st);
     }
 
     let tess_gate = TessGate {
-      backend: self.backend,
+      backend: &mut *self.backend,
     };
     f(tess_gate);
+
+    #[cfg(debug_assertions)]
+    if rdr_st.debug_label().is_some() {
+      unsafe { self.backend.pop_debug_group() };
+    }
+  }
+
+  /// Render a single [`TessView`] with its own [`RenderState`].
+  ///
+  /// This is a shorthand for [`RenderGate::render`] with a closure rendering only `tess_view`,
+  /// handy for one-off draws — e.g. a debug wireframe overlay of some geometry. Render states are
+  /// applied through the backend’s state cache, so that draws following this one are not affected.
+  /// Instances are selected with the view — see [`TessView::inst_whole`] for instance.
+  ///
+  /// See [`TessGate::render`] for the errors rendering the view can fail with.
+  pub fn render_tess<'v, T, V, I, W, S>(
+    &mut self,
+    rdr_st: &RenderState,
+    tess_view: T,
+  ) -> Result<(), TessError>
+  where
+    B: TessGateBackend<V, I, W, S> + 'v,
+    T: Into<TessView<'v, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'v,
+    I: TessIndex + 'v,
+    W: TessVertexData<S> + 'v,
+    S: ?Sized + 'v,
+  {
+    let tess_view = tess_view.into();
+    let mut result = Ok(());
+    self.render(rdr_st, |mut tess_gate| result = tess_gate.render(tess_view));
+    result
   }
 }
@@ -1,29 +1,122 @@
 //! GPU render state.
 //!
 //! Such a state controls how the GPU must operate some fixed pipeline functionality, such as the
-//! blending, depth test or face culling operations.
+//! blending, color mask, depth test, stencil test, face culling, clip distances, provoking vertex,
+//! sample shading, rasterizer discard, scissor operations, polygon offset or depth bounds test.
 
 use crate::blending::{Blending, BlendingMode};
+use crate::color_mask::ColorMask;
 use crate::depth_test::{DepthComparison, DepthWrite};
 use crate::face_culling::FaceCulling;
+use crate::polygon_offset::PolygonOffset;
+use crate::provoking_vertex::ProvokingVertex;
+use crate::scissor::ScissorRegion;
+use crate::stencil::StencilTest;
+use std::fmt;
 
 /// GPU render state.
 ///
 /// You can get a default value with `RenderState::default` and set the operations you want with the
 /// various `RenderState::set_*` methods.
+///
+/// Render states implement [`Eq`], so that they can be used to sort or group draws: their
+/// floating-point settings are compared bit by bit.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RenderState {
   /// Blending configuration.
   blending: Option<BlendingMode>,
+  /// Color mask configuration.
+  color_mask: ColorMask,
   /// Depth test configuration.
   depth_test: Option<DepthComparison>,
   /// Depth write configuration.
   depth_write: DepthWrite,
+  /// Stencil test configuration.
+  stencil_test: Option<StencilTest>,
   /// Face culling configuration.
   face_culling: Option<FaceCulling>,
+  /// Number of enabled clip distances.
+  clip_distances: u8,
+  /// Provoking vertex configuration.
+  provoking_vertex: ProvokingVertex,
+  /// Minimum fraction of samples to shade per fragment.
+  min_sample_shading: Option<Bits>,
+  /// Whether primitives are discarded right before rasterization.
+  rasterizer_discard: bool,
+  /// Scissor region configuration.
+  scissor: Option<ScissorRegion>,
+  /// Polygon offset configuration.
+  polygon_offset: Option<PolygonOffset>,
+  /// Depth bounds test configuration.
+  depth_bounds: Option<(Bits, Bits)>,
+  /// Label of the debug group to wrap the render commands in.
+  debug_label: Option<String>,
 }
 
 impl RenderState {
+  /// Render state for depth-only passes, such as shadow maps and depth pre-passes.
+  ///
+  /// This is the default render state with a [`ColorMask::none`] color mask, so that no fragment
+  /// color is ever written. Such passes typically render to a [`Framebuffer`] with no color slot
+  /// (i.e. `()`), and can use a fragment shader with an empty `main` function.
+  ///
+  /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+  pub fn depth_only() -> Self {
+    RenderState::default().set_color_mask(ColorMask::none())
+  }
+
+  /// Render state for opaque objects.
+  ///
+  /// This is the default render state: depth test with [`DepthComparison::Less`], depth writes on
+  /// and no blending.
+  pub fn opaque() -> Self {
+    RenderState::default()
+  }
+
+  /// Render state for transparent objects.
+  ///
+  /// The depth test is kept so that transparent objects are hidden by opaque ones, but depth
+  /// writes are disabled so that transparent objects don’t hide each other, and [`Blending::alpha`]
+  /// is used. Transparent objects should still be rendered after the opaque ones, sorted back to
+  /// front.
+  pub fn transparent() -> Self {
+    RenderState::default()
+      .set_blending(Blending::alpha())
+      .set_depth_write(DepthWrite::Off)
+  }
+
+  /// Render state for additive effects, such as lights, particles and glows.
+  ///
+  /// Like [`RenderState::transparent`], but with [`Blending::additive`]. As additive blending is
+  /// commutative, such objects don’t need to be sorted.
+  pub fn additive() -> Self {
+    RenderState::default()
+      .set_blending(Blending::additive())
+      .set_depth_write(DepthWrite::Off)
+  }
+
+  /// Render state for user interfaces.
+  ///
+  /// This is [`RenderState::without_depth`] with [`Blending::alpha`]: elements are composited in
+  /// the order they’re rendered.
+  pub fn ui() -> Self {
+    RenderState::without_depth().set_blending(Blending::alpha())
+  }
+
+  /// Render state without any depth interaction, such as for 2D and UI rendering.
+  ///
+  /// This is the default render state with the depth test disabled and [`DepthWrite::Off`], so
+  /// that the depth buffer is neither read nor written. Backends only issue depth-related calls
+  /// when switching from a render state using depth. Clearing the depth buffer is configured on
+  /// the pipeline, with [`PipelineState::enable_clear_depth`].
+  ///
+  /// [`PipelineState::enable_clear_depth`]: crate::pipeline::PipelineState::enable_clear_depth
+  pub fn without_depth() -> Self {
+    RenderState::default()
+      .set_depth_test(None)
+      .set_depth_write(DepthWrite::Off)
+  }
+
   /// Override the blending configuration.
   pub fn set_blending<B>(self, blending: B) -> Self
   where
@@ -51,6 +144,16 @@ impl RenderState {
     self.blending
   }
 
+  /// Override the color mask configuration.
+  pub fn set_color_mask(self, color_mask: ColorMask) -> Self {
+    RenderState { color_mask, ..self }
+  }
+
+  /// Color mask configuration.
+  pub fn color_mask(&self) -> ColorMask {
+    self.color_mask
+  }
+
   /// Override the depth test configuration.
   pub fn set_depth_test<D>(self, depth_test: D) -> Self
   where
@@ -78,6 +181,24 @@ impl RenderState {
     self.depth_write
   }
 
+  /// Override the stencil test configuration.
+  ///
+  /// Passing a [`StencilTest`] enables the stencil test. Passing `None` disables it.
+  pub fn set_stencil_test<ST>(self, stencil_test: ST) -> Self
+  where
+    ST: Into<Option<StencilTest>>,
+  {
+    RenderState {
+      stencil_test: stencil_test.into(),
+      ..self
+    }
+  }
+
+  /// Stencil test configuration.
+  pub fn stencil_test(&self) -> Option<StencilTest> {
+    self.stencil_test
+  }
+
   /// Override the face culling configuration.
   pub fn set_face_culling<FC>(self, face_culling: FC) -> Self
   where
@@ -93,21 +214,367 @@ impl RenderState {
   pub fn face_culling(&self) -> Option<FaceCulling> {
     self.face_culling
   }
+
+  /// Override the number of enabled clip distances.
+  ///
+  /// Shaders writing to `gl_ClipDistance[i]` only clip primitives if the `i`-th clip distance is
+  /// enabled; otherwise, the output is silently ignored. Setting `n` enables the clip distances
+  /// `0` to `n - 1` and disables the other ones.
+  ///
+  /// The number of clip distances supported by backends is limited (at least `8` with OpenGL);
+  /// the count is clamped to that limit — debug builds log a warning the first time it happens.
+  /// Backends not supporting clip distances ignore it.
+  pub fn set_clip_distances(self, clip_distances: u8) -> Self {
+    RenderState {
+      clip_distances,
+      ..self
+    }
+  }
+
+  /// Number of enabled clip distances.
+  pub fn clip_distances(&self) -> u8 {
+    self.clip_distances
+  }
+
+  /// Override the provoking vertex configuration.
+  ///
+  /// Backends not supporting changing the provoking vertex — such as WebGL2 — always use
+  /// [`ProvokingVertex::Last`].
+  pub fn set_provoking_vertex(self, provoking_vertex: ProvokingVertex) -> Self {
+    RenderState {
+      provoking_vertex,
+      ..self
+    }
+  }
+
+  /// Provoking vertex configuration.
+  pub fn provoking_vertex(&self) -> ProvokingVertex {
+    self.provoking_vertex
+  }
+
+  /// Override the sample shading configuration.
+  ///
+  /// Passing a fraction in `[0; 1]` enables sample shading: the fragment shader is run for at
+  /// least that fraction of the samples covered by each fragment, instead of once per fragment.
+  /// `1.` runs it for every sample, which reduces the aliasing of high-frequency shading, such as
+  /// specular highlights, at the cost of more fragment shader invocations. Passing `None` disables
+  /// it.
+  ///
+  /// Sample shading only has an effect when rendering to a multisampled framebuffer — typically
+  /// the back buffer, when multisampling was asked for when creating the window. It’s ignored by
+  /// backends not supporting it, such as OpenGL before 4.0 and WebGL2.
+  pub fn set_min_sample_shading<S>(self, min_sample_shading: S) -> Self
+  where
+    S: Into<Option<f32>>,
+  {
+    RenderState {
+      min_sample_shading: min_sample_shading.into().map(Bits),
+      ..self
+    }
+  }
+
+  /// Sample shading configuration.
+  pub fn min_sample_shading(&self) -> Option<f32> {
+    self.min_sample_shading.map(|Bits(fraction)| fraction)
+  }
+
+  /// Override the rasterizer discard configuration.
+  ///
+  /// When enabled, primitives are discarded right before rasterization: no fragment is generated
+  /// and nothing is written to the framebuffer. That is useful when only the side effects of the
+  /// vertex processing stages are wanted, such as when running transform feedback.
+  pub fn set_rasterizer_discard(self, rasterizer_discard: bool) -> Self {
+    RenderState {
+      rasterizer_discard,
+      ..self
+    }
+  }
+
+  /// Rasterizer discard configuration.
+  pub fn rasterizer_discard(&self) -> bool {
+    self.rasterizer_discard
+  }
+
+  /// Override the scissor configuration.
+  ///
+  /// Passing a [`ScissorRegion`] enables the scissor test: fragments outside of the region are
+  /// discarded. Passing `None` disables it.
+  pub fn set_scissor<SR>(self, scissor: SR) -> Self
+  where
+    SR: Into<Option<ScissorRegion>>,
+  {
+    RenderState {
+      scissor: scissor.into(),
+      ..self
+    }
+  }
+
+  /// Scissor configuration.
+  pub fn scissor(&self) -> Option<ScissorRegion> {
+    self.scissor
+  }
+
+  /// Override the polygon offset configuration.
+  ///
+  /// Passing a [`PolygonOffset`] offsets the depth of filled polygons, which is typically used to
+  /// fight shadow acne in shadow map passes — see [`PolygonOffset::shadow_map`]. Passing `None`
+  /// disables it.
+  pub fn set_polygon_offset<PO>(self, polygon_offset: PO) -> Self
+  where
+    PO: Into<Option<PolygonOffset>>,
+  {
+    RenderState {
+      polygon_offset: polygon_offset.into(),
+      ..self
+    }
+  }
+
+  /// Polygon offset configuration.
+  pub fn polygon_offset(&self) -> Option<PolygonOffset> {
+    self.polygon_offset
+  }
+
+  /// Override the depth bounds test configuration.
+  ///
+  /// Passing `(min, max)` enables the depth bounds test: fragments are discarded when the depth
+  /// already stored in the depth buffer at their position is outside of `[min; max]`. Contrary to
+  /// the depth test, the depth of the fragment itself doesn’t matter, so that fragments can be
+  /// rejected before being shaded — which is typically used to skip the pixels outside of the
+  /// depth range of a light volume in deferred shading. Passing `None` disables it.
+  ///
+  /// The depth bounds test requires the `GL_EXT_depth_bounds_test` extension with OpenGL. The
+  /// backends not supporting it, such as WebGL2, log a warning and ignore it.
+  pub fn set_depth_bounds<DB>(self, depth_bounds: DB) -> Self
+  where
+    DB: Into<Option<(f32, f32)>>,
+  {
+    RenderState {
+      depth_bounds: depth_bounds.into().map(|(min, max)| (Bits(min), Bits(max))),
+      ..self
+    }
+  }
+
+  /// Depth bounds test configuration.
+  pub fn depth_bounds(&self) -> Option<(f32, f32)> {
+    self.depth_bounds.map(|(Bits(min), Bits(max))| (min, max))
+  }
+
+  /// Override the debug label.
+  ///
+  /// When set, the render commands issued with this render state are wrapped in a debug group
+  /// named after the label, so that they show up as such in GPU debuggers and frame captures
+  /// (e.g. RenderDoc), instead of as anonymous draws. Debug groups are only emitted in debug
+  /// builds, and are ignored by backends not supporting them, such as OpenGL before 4.3 and
+  /// WebGL2.
+  ///
+  /// The debug label is not part of the GPU state, so it’s never reported by
+  /// [`RenderState::diff`].
+  pub fn set_debug_label<L>(self, debug_label: L) -> Self
+  where
+    L: Into<Option<String>>,
+  {
+    RenderState {
+      debug_label: debug_label.into(),
+      ..self
+    }
+  }
+
+  /// Debug label.
+  pub fn debug_label(&self) -> Option<&str> {
+    self.debug_label.as_deref()
+  }
+
+  /// Compute the [`StateTransition`] required to go from `previous` to `self`.
+  ///
+  /// Only the aspects that differ between both render states are present in the returned
+  /// transition, carrying the value from `self`. If both render states are equal, the transition
+  /// is empty.
+  pub fn diff(&self, previous: &RenderState) -> StateTransition {
+    let mut changes = Vec::new();
+
+    if self.blending != previous.blending {
+      changes.push(StateChange::Blending(self.blending));
+    }
+
+    if self.color_mask != previous.color_mask {
+      changes.push(StateChange::ColorMask(self.color_mask));
+    }
+
+    if self.depth_test != previous.depth_test {
+      changes.push(StateChange::DepthTest(self.depth_test));
+    }
+
+    if self.depth_write != previous.depth_write {
+      changes.push(StateChange::DepthWrite(self.depth_write));
+    }
+
+    if self.stencil_test != previous.stencil_test {
+      changes.push(StateChange::StencilTest(self.stencil_test));
+    }
+
+    if self.face_culling != previous.face_culling {
+      changes.push(StateChange::FaceCulling(self.face_culling));
+    }
+
+    if self.clip_distances != previous.clip_distances {
+      changes.push(StateChange::ClipDistances(self.clip_distances));
+    }
+
+    if self.provoking_vertex != previous.provoking_vertex {
+      changes.push(StateChange::ProvokingVertex(self.provoking_vertex));
+    }
+
+    if self.min_sample_shading != previous.min_sample_shading {
+      changes.push(StateChange::MinSampleShading(self.min_sample_shading()));
+    }
+
+    if self.rasterizer_discard != previous.rasterizer_discard {
+      changes.push(StateChange::RasterizerDiscard(self.rasterizer_discard));
+    }
+
+    if self.scissor != previous.scissor {
+      changes.push(StateChange::Scissor(self.scissor));
+    }
+
+    if self.polygon_offset != previous.polygon_offset {
+      changes.push(StateChange::PolygonOffset(self.polygon_offset));
+    }
+
+    if self.depth_bounds != previous.depth_bounds {
+      changes.push(StateChange::DepthBounds(self.depth_bounds()));
+    }
+
+    StateTransition { changes }
+  }
+}
+
+/// A `f32` compared bit by bit, so that [`RenderState`] implements [`Eq`].
+#[derive(Clone, Copy)]
+struct Bits(f32);
+
+impl PartialEq for Bits {
+  fn eq(&self, rhs: &Self) -> bool {
+    self.0.to_bits() == rhs.0.to_bits()
+  }
+}
+
+impl Eq for Bits {}
+
+impl fmt::Debug for Bits {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt(f)
+  }
 }
 
 impl Default for RenderState {
   /// The default `RenderState`.
   ///
   ///   - `blending`: `None`
+  ///   - `color_mask`: `ColorMask::all()`
   ///   - `depth_test`: `Some(DepthComparison::Less)`
   ///   - `depth_write`: `DepthWrite::On`
+  ///   - `stencil_test`: `None`
   ///   - `face_culling`: `None`
+  ///   - `clip_distances`: `0`
+  ///   - `provoking_vertex`: `ProvokingVertex::Last`
+  ///   - `min_sample_shading`: `None`
+  ///   - `rasterizer_discard`: `false`
+  ///   - `scissor`: `None`
+  ///   - `polygon_offset`: `None`
+  ///   - `depth_bounds`: `None`
+  ///   - `debug_label`: `None`
   fn default() -> Self {
     RenderState {
       blending: None,
+      color_mask: ColorMask::all(),
       depth_test: Some(DepthComparison::Less),
       depth_write: DepthWrite::On,
+      stencil_test: None,
       face_culling: None,
+      clip_distances: 0,
+      provoking_vertex: ProvokingVertex::default(),
+      min_sample_shading: None,
+      rasterizer_discard: false,
+      scissor: None,
+      polygon_offset: None,
+      depth_bounds: None,
+      debug_label: None,
     }
   }
 }
+
+/// A single aspect of a [`RenderState`] that changed, carrying its new value.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StateChange {
+  /// The blending configuration changed.
+  Blending(Option<BlendingMode>),
+  /// The color mask configuration changed.
+  ColorMask(ColorMask),
+  /// The depth test configuration changed.
+  DepthTest(Option<DepthComparison>),
+  /// The depth write configuration changed.
+  DepthWrite(DepthWrite),
+  /// The stencil test configuration changed.
+  StencilTest(Option<StencilTest>),
+  /// The face culling configuration changed.
+  FaceCulling(Option<FaceCulling>),
+  /// The number of enabled clip distances changed.
+  ClipDistances(u8),
+  /// The provoking vertex configuration changed.
+  ProvokingVertex(ProvokingVertex),
+  /// The sample shading configuration changed.
+  MinSampleShading(Option<f32>),
+  /// The rasterizer discard configuration changed.
+  RasterizerDiscard(bool),
+  /// The scissor configuration changed.
+  Scissor(Option<ScissorRegion>),
+  /// The polygon offset configuration changed.
+  PolygonOffset(Option<PolygonOffset>),
+  /// The depth bounds test configuration changed.
+  DepthBounds(Option<(f32, f32)>),
+}
+
+/// Minimal set of changes to apply to go from a [`RenderState`] to another one.
+///
+/// You can get one with [`RenderState::diff`] and iterate over the [`StateChange`] it contains in
+/// order to apply only the deltas.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StateTransition {
+  changes: Vec<StateChange>,
+}
+
+impl StateTransition {
+  /// Whether the transition doesn’t change anything.
+  pub fn is_empty(&self) -> bool {
+    self.changes.is_empty()
+  }
+
+  /// Number of aspects that change.
+  pub fn len(&self) -> usize {
+    self.changes.len()
+  }
+
+  /// Iterate over the changes.
+  pub fn iter(&self) -> impl Iterator<Item = &StateChange> {
+    self.changes.iter()
+  }
+}
+
+impl IntoIterator for StateTransition {
+  type Item = StateChange;
+  type IntoIter = std::vec::IntoIter<StateChange>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.changes.into_iter()
+  }
+}
+
+impl<'a> IntoIterator for &'a StateTransition {
+  type Item = &'a StateChange;
+  type IntoIter = std::slice::Iter<'a, StateChange>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.changes.iter()
+  }
+}
@@ -0,0 +1,82 @@
+//! Renderbuffers, non-sampleable render targets.
+//!
+//! A [`Renderbuffer`] is an image a [`Framebuffer`] can render into, exactly like a texture, but
+//! that cannot be sampled in shaders. Because the GPU doesn’t have to make renderbuffers
+//! available for sampling, they are usually cheaper than textures — especially on tiled GPUs —
+//! and are a good fit for transient targets, such as the depth buffer of a depth-only pre-pass.
+//!
+//! Renderbuffers are never created directly: you use them as color or depth slots when creating
+//! a [`Framebuffer`], by wrapping the pixel format with [`Renderbuffer`]. For instance, a
+//! framebuffer with a sampleable color texture and a renderbuffer depth attachment has the type
+//! `Framebuffer<B, Dim2, RGBA32F, Renderbuffer<B, Depth32F>>`.
+//!
+//! [`Framebuffer`]: crate::framebuffer::Framebuffer
+
+use std::marker::PhantomData;
+
+use crate::backend::renderbuffer::Renderbuffer as RenderbufferBackend;
+use crate::context::GraphicsContext;
+use crate::framebuffer::FramebufferError;
+use crate::pixel::Pixel;
+use crate::texture::{Dim2, Dimensionable};
+
+/// A renderbuffer.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [backend::renderbuffer::Renderbuffer].
+/// - `P` is the pixel format of the renderbuffer. It must implement [`Pixel`].
+///
+/// [backend::renderbuffer::Renderbuffer]: crate::backend::renderbuffer::Renderbuffer
+pub struct Renderbuffer<B, P>
+where
+  B: ?Sized + RenderbufferBackend<P>,
+  P: Pixel,
+{
+  pub(crate) repr: B::RenderbufferRepr,
+  size: <Dim2 as Dimensionable>::Size,
+  _phantom: PhantomData<*const P>,
+}
+
+impl<B, P> Renderbuffer<B, P>
+where
+  B: ?Sized + RenderbufferBackend<P>,
+  P: Pixel,
+{
+  /// Create a new [`Renderbuffer`].
+  ///
+  /// You shouldn’t need to call this function, as renderbuffers are created along with the
+  /// [`Framebuffer`] using them.
+  ///
+  /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+  pub fn new<C>(ctx: &mut C, size: <Dim2 as Dimensionable>::Size) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe {
+      ctx
+        .backend()
+        .new_renderbuffer(size)
+        .map(|repr| Renderbuffer {
+          repr,
+          size,
+          _phantom: PhantomData,
+        })
+    }
+  }
+
+  /// Return the size of the renderbuffer.
+  pub fn size(&self) -> <Dim2 as Dimensionable>::Size {
+    self.size
+  }
+
+  /// Resize the renderbuffer.
+  ///
+  /// The storage of the renderbuffer is reallocated for the new `size`. The previous content of
+  /// the renderbuffer is lost.
+  pub fn resize(&mut self, size: <Dim2 as Dimensionable>::Size) -> Result<(), FramebufferError> {
+    unsafe { B::resize_renderbuffer(&mut self.repr, size)? };
+    self.size = size;
+    Ok(())
+  }
+}
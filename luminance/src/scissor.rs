@@ -0,0 +1,63 @@
+//! Scissor test and related types.
+//!
+//! The scissor test is a per-fragment test that discards any fragment lying outside of a given
+//! rectangular region of the framebuffer. It is typically used to clip the widgets of an
+//! immediate-mode UI — such as the ones generated by [egui] or [imgui] — to their clip rectangles.
+//!
+//! The scissor test is part of the [`RenderState`].
+//!
+//! # Immediate-mode UI overlays
+//!
+//! Such UI libraries hand out, every frame, a vertex and index stream along with a list of draw
+//! commands, each made of a texture, a clip rectangle and a range of indices. The overlay is
+//! rendered with the regular luminance objects, in a pipeline that doesn’t clear the framebuffer
+//! the scene was rendered to:
+//!
+//! - The vertex and index streams go in a [`Tess`], built again when the streams outgrow it and
+//!   otherwise rewritten by [mapping] its vertices and indices.
+//! - The texture of a command is bound with the [`Pipeline`] and passed to the shader as a
+//!   uniform.
+//! - Each command is rendered with [`RenderGate::render_tess`], using [`RenderState::ui`] — alpha
+//!   blending, no depth interaction — with the [`ScissorRegion`] of the clip rectangle, and a
+//!   [`TessView`] of its range of indices. UI libraries usually express clip rectangles with the
+//!   origin at the upper-left corner, so their `y` coordinate must be flipped.
+//!
+//! ```ignore
+//! for cmd in &ui_commands {
+//!   iface.set(&uni.texture, bound_textures[cmd.texture].binding());
+//!
+//!   let rdr_st = RenderState::ui().set_scissor(cmd.clip);
+//!   rdr_gate.render_tess(&rdr_st, ui_tess.view(cmd.start..cmd.end)?)?;
+//! }
+//! ```
+//!
+//! As render states are applied through the backend’s state cache, whatever is rendered after the
+//! overlay is not affected by it. UI backends issuing their own raw graphics API calls must
+//! instead run them through the interop integration point of the backend, if any — such as
+//! `GL33::interop` — so that the cache is invalidated afterwards.
+//!
+//! [egui]: https://crates.io/crates/egui
+//! [imgui]: https://crates.io/crates/imgui
+//! [`RenderState`]: crate::render_state::RenderState
+//! [`RenderState::ui`]: crate::render_state::RenderState::ui
+//! [`Tess`]: crate::tess::Tess
+//! [mapping]: crate::tess::Tess::vertices_mut
+//! [`Pipeline`]: crate::pipeline::Pipeline
+//! [`RenderGate::render_tess`]: crate::render_gate::RenderGate::render_tess
+//! [`TessView`]: crate::tess::TessView
+
+/// The region outside of which fragments will be discarded.
+///
+/// The coordinates are expressed in pixels, with the origin at the lower-left corner of the
+/// framebuffer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ScissorRegion {
+  /// The x screen position of the scissor region.
+  pub x: u32,
+  /// The y screen position of the scissor region.
+  pub y: u32,
+  /// The screen width of the scissor region.
+  pub width: u32,
+  /// The screen height of the scissor region.
+  pub height: u32,
+}
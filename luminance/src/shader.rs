@@ -147,7 +147,7 @@ use std::marker::PhantomData;
 
 use crate::backend::shader::{Shader, Uniformable};
 use crate::context::GraphicsContext;
-use crate::vertex::Semantics;
+use crate::vertex::{Normalized, Semantics, Vertex, VertexAttribType};
 
 /// A shader stage type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -344,6 +344,18 @@ pub enum UniformWarning {
   ///
   /// [`Uniform<T>`]: crate::shader::Uniform
   TypeMismatch(String, UniformType),
+  /// More values were passed to an array uniform than it declares in the shaders.
+  ArrayOverflow {
+    /// Length of the provided data.
+    provided_len: usize,
+    /// Array size declared in the shaders.
+    declared_len: usize,
+  },
+  /// Uniforms cannot be retrieved by explicit location.
+  ///
+  /// Either the backend doesn’t support explicit uniform locations or the requested type doesn’t
+  /// have a location (e.g. uniform blocks).
+  UnsupportedExplicitLocation(u32),
 }
 
 impl UniformWarning {
@@ -362,6 +374,19 @@ impl UniformWarning {
   {
     UniformWarning::TypeMismatch(name.into(), ty)
   }
+
+  /// Create an unsupported explicit location warning.
+  pub fn unsupported_explicit_location(location: u32) -> Self {
+    UniformWarning::UnsupportedExplicitLocation(location)
+  }
+
+  /// Create an array overflow.
+  pub fn array_overflow(provided_len: usize, declared_len: usize) -> Self {
+    UniformWarning::ArrayOverflow {
+      provided_len,
+      declared_len,
+    }
+  }
 }
 
 impl fmt::Display for UniformWarning {
@@ -372,6 +397,19 @@ impl fmt::Display for UniformWarning {
       UniformWarning::TypeMismatch(ref n, ref t) => {
         write!(f, "type mismatch for uniform {}: {}", n, t)
       }
+
+      UniformWarning::ArrayOverflow {
+        provided_len,
+        declared_len,
+      } => write!(
+        f,
+        "too many values passed to the array uniform (nb = {}, size = {})",
+        provided_len, declared_len
+      ),
+
+      UniformWarning::UnsupportedExplicitLocation(location) => {
+        write!(f, "unsupported explicit uniform location {}", location)
+      }
     }
   }
 }
@@ -390,6 +428,14 @@ impl error::Error for UniformWarning {}
 pub enum VertexAttribWarning {
   /// Inactive vertex attribute (not read).
   Inactive(String),
+  /// Vertex attribute read by the shader but not provided by any vertex type.
+  Unbound(String),
+  /// Type mismatch between the type of a vertex attribute in the shader and the type of the
+  /// vertex attribute provided by a vertex type.
+  ///
+  /// The [`String`] is the name of the attribute; the [`UniformType`] is the type declared in the
+  /// shader and the [`VertexAttribType`] is the type of the provided attribute.
+  TypeMismatch(String, UniformType, VertexAttribType),
 }
 
 impl VertexAttribWarning {
@@ -397,12 +443,38 @@ impl VertexAttribWarning {
   pub fn inactive(attrib: impl Into<String>) -> Self {
     VertexAttribWarning::Inactive(attrib.into())
   }
+
+  /// Vertex attribute read by the shader but not provided by any vertex type.
+  pub fn unbound(attrib: impl Into<String>) -> Self {
+    VertexAttribWarning::Unbound(attrib.into())
+  }
+
+  /// Type mismatch between the shader and the provided vertex attribute.
+  pub fn type_mismatch(
+    attrib: impl Into<String>,
+    shader_ty: UniformType,
+    vertex_ty: VertexAttribType,
+  ) -> Self {
+    VertexAttribWarning::TypeMismatch(attrib.into(), shader_ty, vertex_ty)
+  }
 }
 
 impl fmt::Display for VertexAttribWarning {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
     match *self {
       VertexAttribWarning::Inactive(ref s) => write!(f, "inactive {} vertex attribute", s),
+
+      VertexAttribWarning::Unbound(ref s) => write!(
+        f,
+        "{} vertex attribute is read by the shader but not provided by the vertex",
+        s
+      ),
+
+      VertexAttribWarning::TypeMismatch(ref s, ref shader_ty, ref vertex_ty) => write!(
+        f,
+        "{} vertex attribute type mismatch: the shader expects {} but the vertex provides {:?}",
+        s, shader_ty, vertex_ty
+      ),
     }
   }
 }
@@ -415,6 +487,56 @@ impl From<VertexAttribWarning> for ProgramWarning {
 
 impl error::Error for VertexAttribWarning {}
 
+/// An active vertex attribute of a [`Program`], as reflected by the backend.
+///
+/// Such attributes are obtained with [`Program::active_attributes`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveAttribute {
+  /// Name of the attribute, as declared in the vertex shader.
+  pub name: String,
+  /// Location of the attribute.
+  pub location: usize,
+  /// Type of the attribute, as declared in the vertex shader.
+  pub ty: UniformType,
+}
+
+/// Check whether a vertex attribute of type `vertex_ty` can be read by a shader vertex attribute
+/// of type `shader_ty`.
+///
+/// Only the kind of fetch is checked — floating-point, signed or unsigned integral. Dimensions
+/// are not checked, as shaders are allowed to read fewer or more components than provided.
+fn vertex_attrib_type_matches(shader_ty: UniformType, vertex_ty: VertexAttribType) -> bool {
+  match shader_ty {
+    UniformType::Float
+    | UniformType::Vec2
+    | UniformType::Vec3
+    | UniformType::Vec4
+    | UniformType::M22
+    | UniformType::M33
+    | UniformType::M44 => matches!(
+      vertex_ty,
+      VertexAttribType::Floating
+        | VertexAttribType::Integral(Normalized::Yes)
+        | VertexAttribType::Unsigned(Normalized::Yes)
+    ),
+
+    UniformType::Int | UniformType::IVec2 | UniformType::IVec3 | UniformType::IVec4 => matches!(
+      vertex_ty,
+      VertexAttribType::Integral(Normalized::No) | VertexAttribType::Boolean
+    ),
+
+    UniformType::UInt | UniformType::UIVec2 | UniformType::UIVec3 | UniformType::UIVec4 => {
+      matches!(
+        vertex_ty,
+        VertexAttribType::Unsigned(Normalized::No) | VertexAttribType::Boolean
+      )
+    }
+
+    // other types cannot be vertex attributes; let the backend complain
+    _ => true,
+  }
+}
+
 /// A GPU shader program environment variable.
 ///
 /// A uniform is a special variable that can be used to send data to a GPU. Several
@@ -438,6 +560,7 @@ where
   T: ?Sized,
 {
   index: i32,
+  size: usize,
   _t: PhantomData<*const T>,
 }
 
@@ -453,8 +576,20 @@ where
   /// then you’re doing something wrong. Read on [`UniformInterface`] for further
   /// information.
   pub unsafe fn new(index: i32) -> Self {
+    Self::new_array(index, 1)
+  }
+
+  /// Create a new [`Uniform`] representing an array of `size` elements.
+  ///
+  /// # Safety
+  ///
+  /// This method must be used **only** by backends. If you end up using it,
+  /// then you’re doing something wrong. Read on [`UniformInterface`] for further
+  /// information.
+  pub unsafe fn new_array(index: i32, size: usize) -> Self {
     Uniform {
       index,
+      size,
       _t: PhantomData,
     }
   }
@@ -466,6 +601,13 @@ where
   pub fn index(&self) -> i32 {
     self.index
   }
+
+  /// Number of elements declared in the shader for this uniform.
+  ///
+  /// This is `1` for uniforms that are not arrays.
+  pub fn size(&self) -> usize {
+    self.size
+  }
 }
 
 /// Type of a uniform.
@@ -556,6 +698,12 @@ pub enum UniformType {
   UICubemap,
   /// Floating-point cubemap sampler.
   Cubemap,
+  /// Signed buffer sampler.
+  ISamplerBuffer,
+  /// Unsigned buffer sampler.
+  UISamplerBuffer,
+  /// Floating-point buffer sampler.
+  SamplerBuffer,
 
   // buffer
   /// Buffer binding; used for UBOs.
@@ -602,6 +750,9 @@ impl fmt::Display for UniformType {
       UniformType::ICubemap => f.write_str("isamplerCube"),
       UniformType::UICubemap => f.write_str("usamplerCube"),
       UniformType::Cubemap => f.write_str("samplerCube"),
+      UniformType::ISamplerBuffer => f.write_str("isamplerBuffer"),
+      UniformType::UISamplerBuffer => f.write_str("usamplerBuffer"),
+      UniformType::SamplerBuffer => f.write_str("samplerBuffer"),
       UniformType::BufferBinding => f.write_str("buffer binding"),
     }
   }
@@ -674,6 +825,18 @@ where
   B: ?Sized + Shader,
 {
   /// Ask the creation of a [`Uniform`], identified by its `name`.
+  ///
+  /// The type `T` is checked against the type the uniform is declared with in the shaders, as
+  /// reported by the backend — for arrays, the type of their elements, as their length is only
+  /// known when setting them. Because the returned [`Uniform`] is typed, that check is done once
+  /// here and values set later with [`ProgramInterface::set`] are guaranteed to have the right
+  /// type.
+  ///
+  /// # Errors
+  ///
+  /// - [`UniformWarning::Inactive`] is returned if no active uniform has that name.
+  /// - [`UniformWarning::TypeMismatch`] is returned if `T` doesn’t match the type of the uniform
+  ///   declared in the shaders.
   pub fn ask<T, N>(&mut self, name: N) -> Result<Uniform<T>, UniformWarning>
   where
     N: AsRef<str>,
@@ -682,6 +845,26 @@ where
     unsafe { B::ask_uniform(&mut self.repr, name.as_ref()) }
   }
 
+  /// Ask the creation of a [`Uniform`], identified by its explicit `location`.
+  ///
+  /// That is the location set in the shaders with `layout (location = N) uniform`. No name lookup
+  /// is done, so that — contrary to [`UniformBuilder::ask`] — neither the existence of the
+  /// uniform nor its type can be checked: make sure `T` matches the type declared in the shaders.
+  /// With OpenGL, explicit uniform locations require OpenGL 4.3 or the
+  /// `GL_ARB_explicit_uniform_location` extension.
+  ///
+  /// # Errors
+  ///
+  /// [`UniformWarning::UnsupportedExplicitLocation`] is returned if the backend doesn’t support
+  /// explicit uniform locations — WebGL2 doesn’t — or if `T` is a uniform block binding, which
+  /// has no location.
+  pub fn ask_at<T>(&mut self, location: u32) -> Result<Uniform<T>, UniformWarning>
+  where
+    T: Uniformable<B>,
+  {
+    unsafe { B::ask_uniform_at(&mut self.repr, location) }
+  }
+
   /// Ask the creation of a [`Uniform`], identified by its `name`.
   ///
   /// If the name is not found, an _unbound_ [`Uniform`] is returned (i.e. a [`Uniform`]) that does
@@ -843,6 +1026,31 @@ where
     unsafe { T::update(value, self.program, uniform) };
   }
 
+  /// Set a slice of values on an array [`Uniform`], in a single call.
+  ///
+  /// The values are written starting at the first element of the array. Passing fewer values than
+  /// declared in the shaders only updates the first elements.
+  ///
+  /// # Errors
+  ///
+  /// [`UniformWarning::ArrayOverflow`] is returned if more values are passed than the array size
+  /// declared in the shaders; nothing is sent in that case.
+  pub fn set_array<'b, T>(
+    &mut self,
+    uniform: &Uniform<&'b [T]>,
+    values: &'b [T],
+  ) -> Result<(), UniformWarning>
+  where
+    &'b [T]: Uniformable<B>,
+  {
+    if values.len() > uniform.size() {
+      return Err(UniformWarning::array_overflow(values.len(), uniform.size()));
+    }
+
+    self.set(uniform, values);
+    Ok(())
+  }
+
   /// Get back a [`UniformBuilder`] to dynamically access [`Uniform`] objects.
   pub fn query(&mut self) -> Result<UniformBuilder<'a, B>, ProgramError> {
     unsafe {
@@ -1094,6 +1302,45 @@ where
   B: ?Sized + Shader,
   Sem: Semantics,
 {
+  /// Get the list of active vertex attributes of the program.
+  ///
+  /// Built-in attributes (i.e. `gl_*`) are not part of the list.
+  pub fn active_attributes(&self) -> Vec<ActiveAttribute> {
+    unsafe { B::active_attributes(&self.repr) }
+  }
+
+  /// Check that every active vertex attribute of the program is provided by either `V` — the
+  /// vertex type — or `W` — the instance type — with a compatible type.
+  ///
+  /// This is typically used to check that a [`Tess`] can be correctly rendered with this
+  /// program. Use `()` for `W` if you don’t use instance data.
+  ///
+  /// An empty list means everything is fine.
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  pub fn validate_vertex_attribs<V, W>(&self) -> Vec<VertexAttribWarning>
+  where
+    V: Vertex,
+    W: Vertex,
+  {
+    let mut descs = V::vertex_desc();
+    descs.extend(W::vertex_desc());
+
+    self
+      .active_attributes()
+      .into_iter()
+      .filter_map(
+        |attrib| match descs.iter().find(|desc| desc.name == attrib.name) {
+          None => Some(VertexAttribWarning::unbound(attrib.name)),
+          Some(desc) if !vertex_attrib_type_matches(attrib.ty, desc.attrib_desc.ty) => Some(
+            VertexAttribWarning::type_mismatch(attrib.name, attrib.ty, desc.attrib_desc.ty),
+          ),
+          Some(_) => None,
+        },
+      )
+      .collect()
+  }
+
   /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
   ///
   /// # Parametricity
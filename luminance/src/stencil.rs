@@ -0,0 +1,179 @@
+//! Stencil test related features.
+//!
+//! The stencil test is a per-fragment test that compares a reference value against the value
+//! already stored in the stencil buffer, and discards the fragment if the comparison fails.
+//! Depending on the outcome of both the stencil and depth tests, the stored value can be updated,
+//! which allows to incrementally build masks over several draw calls.
+//!
+//! The stencil test is part of the [`RenderState`]. It requires the framebuffer you render to to
+//! be backed by a stencil buffer — typically the back buffer, if you asked for one when creating
+//! the window.
+//!
+//! # Planar mirrors and portals
+//!
+//! The typical use of the stencil test is to restrict the render of a scene to the area covered
+//! by a mirror or a portal. Start the pipeline by clearing the stencil buffer to `0` (see
+//! [`PipelineState::enable_clear_stencil`]) and then:
+//!
+//! 1. Render the mirror surface with [`StencilTest::write`]. Fragments covered by the mirror
+//!    get the reference value written to the stencil buffer.
+//! 2. Render the reflected scene with [`StencilTest::equal`], using the same reference value.
+//!    Only the fragments lying inside the mirror pass the test.
+//! 3. Render the rest of the scene with a [`RenderState`] without stencil test. As render states
+//!    are applied for each render gate, there is nothing to restore by hand.
+//!
+//! ```ignore
+//! let mirror_state = RenderState::default().set_stencil_test(StencilTest::write(1));
+//! let reflected_state = RenderState::default()
+//!   .set_stencil_test(StencilTest::equal(1))
+//!   .set_face_culling(FaceCulling::new(FaceCullingOrder::CW, FaceCullingMode::Back));
+//! let scene_state = RenderState::default();
+//! ```
+//!
+//! Because the reflected scene is mirrored, its triangles’ winding order is flipped, hence the
+//! [`FaceCullingOrder::CW`] in the example above.
+//!
+//! # Value and write masks
+//!
+//! A [`StencilTest`] has two independent masks. [`StencilTest::mask`] is the _value mask_: it
+//! selects the bits of the reference and stored values taking part in the comparison.
+//! [`StencilTest::write_mask`] selects the bits of the stored value that the
+//! [`StencilOperations`] are allowed to modify. Keeping them separate allows to store several
+//! masks in the bits of the same stencil buffer — each pass reading and writing its own bits — or
+//! to test the stencil buffer without ever modifying it, with a write mask of `0x00`.
+//!
+//! [`RenderState`]: crate::render_state::RenderState
+//! [`PipelineState::enable_clear_stencil`]: crate::pipeline::PipelineState::enable_clear_stencil
+//! [`FaceCullingOrder::CW`]: crate::face_culling::FaceCullingOrder::CW
+
+/// Stencil test setup.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct StencilTest {
+  /// Comparison to perform.
+  pub comparison: StencilComparison,
+  /// Reference value, compared against the stored stencil value.
+  pub reference: u8,
+  /// Value mask, applied to both the reference and the stored values before comparing them.
+  pub mask: u8,
+  /// Write mask, selecting the bits of the stored value that can be modified by the operations.
+  pub write_mask: u8,
+  /// Operations to perform on the stored stencil value, depending on the outcome of the tests.
+  pub operations: StencilOperations,
+}
+
+impl StencilTest {
+  /// Create a new [`StencilTest`].
+  ///
+  /// The stored stencil value is never modified; use [`StencilTest::set_operations`] to change
+  /// that. The write mask is `0xFF`: once operations are set, all the bits can be modified.
+  pub fn new(comparison: StencilComparison, reference: u8, mask: u8) -> Self {
+    StencilTest {
+      comparison,
+      reference,
+      mask,
+      write_mask: 0xFF,
+      operations: StencilOperations::default(),
+    }
+  }
+
+  /// Override the [`StencilOperations`].
+  pub fn set_operations(self, operations: StencilOperations) -> Self {
+    StencilTest { operations, ..self }
+  }
+
+  /// Override the write mask.
+  pub fn set_write_mask(self, write_mask: u8) -> Self {
+    StencilTest { write_mask, ..self }
+  }
+
+  /// Stencil test always passing and writing `reference` wherever fragments are rendered.
+  ///
+  /// This is the test to use to render the shape of a mask, such as a mirror or a portal.
+  pub fn write(reference: u8) -> Self {
+    StencilTest::new(StencilComparison::Always, reference, 0xFF).set_operations(
+      StencilOperations::new(StencilOp::Keep, StencilOp::Keep, StencilOp::Replace),
+    )
+  }
+
+  /// Stencil test passing only where the stored value is equal to `reference`.
+  ///
+  /// The stored value is never modified. This is the test to use to render inside a mask
+  /// previously rendered with [`StencilTest::write`].
+  pub fn equal(reference: u8) -> Self {
+    StencilTest::new(StencilComparison::Equal, reference, 0xFF)
+  }
+}
+
+/// Stencil comparison to perform while stencil test. `a` is the reference value and `b` is the
+/// stencil value that is already stored, both masked with [`StencilTest::mask`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StencilComparison {
+  /// Stencil test never succeeds.
+  Never,
+  /// Stencil test always succeeds.
+  Always,
+  /// Stencil test succeeds if `a == b`.
+  Equal,
+  /// Stencil test succeeds if `a != b`.
+  NotEqual,
+  /// Stencil test succeeds if `a < b`.
+  Less,
+  /// Stencil test succeeds if `a <= b`.
+  LessOrEqual,
+  /// Stencil test succeeds if `a > b`.
+  Greater,
+  /// Stencil test succeeds if `a >= b`.
+  GreaterOrEqual,
+}
+
+/// Operations to perform on the stored stencil value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct StencilOperations {
+  /// Operation to perform when the stencil test fails.
+  pub stencil_fails: StencilOp,
+  /// Operation to perform when the stencil test passes but the depth test fails.
+  pub depth_fails: StencilOp,
+  /// Operation to perform when both the stencil and depth tests pass.
+  pub depth_passes: StencilOp,
+}
+
+impl StencilOperations {
+  /// Create a new [`StencilOperations`].
+  pub fn new(stencil_fails: StencilOp, depth_fails: StencilOp, depth_passes: StencilOp) -> Self {
+    StencilOperations {
+      stencil_fails,
+      depth_fails,
+      depth_passes,
+    }
+  }
+}
+
+/// Default implementation of [`StencilOperations`].
+///
+/// All operations are [`StencilOp::Keep`].
+impl Default for StencilOperations {
+  fn default() -> Self {
+    StencilOperations::new(StencilOp::Keep, StencilOp::Keep, StencilOp::Keep)
+  }
+}
+
+/// Operation to perform on a stored stencil value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StencilOp {
+  /// Keep the stored value.
+  Keep,
+  /// Set the stored value to `0`.
+  Zero,
+  /// Replace the stored value with the reference value.
+  Replace,
+  /// Increment the stored value, clamping to the maximum value.
+  Increment,
+  /// Increment the stored value, wrapping to `0` on overflow.
+  IncrementWrap,
+  /// Decrement the stored value, clamping to `0`.
+  Decrement,
+  /// Decrement the stored value, wrapping to the maximum value on underflow.
+  DecrementWrap,
+  /// Bitwise invert the stored value.
+  Invert,
+}
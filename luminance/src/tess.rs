@@ -11,7 +11,11 @@
 //!   is the fact of drawing with the same [`Tess`] (GPU buffers) several times, only changing the
 //!   instance index every time a new render is performed. This is done entirely on the GPU to
 //!   prevent bandwidth exhaustion. The index of the instance, in the shader stages, is often used
-//!   to pick material properties, matrices, etc. to customize each instances.
+//!   to pick material properties, matrices, etc. to customize each instances. On hardware or
+//!   contexts without support for instanced rendering, rendering several instances renders
+//!   nothing and [`TessGate::render`] returns [`TessError::UnsupportedInstancing`] instead of
+//!   crashing; applications targeting such hardware can check for that error and fall back to one
+//!   render per instance, passing the instance index as a uniform.
 //! - An indexed configuration, allowing to tell the GPU how to render the vertices by referring to
 //!   them via indices.
 //! - For indexed configuration, an optional _primitive restart index_ can be specified. That
@@ -26,7 +30,9 @@
 //!
 //! - _Attributeless_: when you only specify the [`Mode`] and number of vertices to render (and
 //!   optionally the number of instances). That will create a vertex set with no vertex data. Your
-//!   vertex shader will be responsible for creating the vertex attributes on the fly.
+//!   vertex shader will be responsible for creating the vertex attributes on the fly, typically
+//!   from `gl_VertexID`. No vertex buffer is allocated nor bound, so that a full-screen pass is
+//!   only a three-vertex draw — see [`Tess::fullscreen_triangle`].
 //! - _Direct geometry_: when you pass vertices directly.
 //! - _Indexed geometry_: when you pass vertices and reference from with indices.
 //! - _Instanced geometry_: when you ask to use instances, making the graphics pipeline create
@@ -47,6 +53,16 @@
 //! [`View::slice`] and [`View::inst_slice`] methods, which accept Rust’s range operators
 //! to create the [`TessView`]s in a more comfortable way.
 //!
+//! Because views are validated against the [`Tess`] they are created from and only change the
+//! range of vertices (or indices) handed to the GPU, they are the way to pack several levels of
+//! detail in a single [`Tess`] and select one at draw time:
+//!
+//! ```ignore
+//! // LOD 0 uses indices 0..600, LOD 1 uses 600..750
+//! let lod_view = if far { tess.view(600..750)? } else { tess.view(..600)? };
+//! tess_gate.render(lod_view)?;
+//! ```
+//!
 //! # Tessellation mapping
 //!
 //! Sometimes, you will want to edit tessellations in a dynamic way instead of re-creating new
@@ -62,6 +78,7 @@
 //! > will not help you with resizing a [`Tess`], as this is not currently supported.
 //!
 //! [`TessGate`]: crate::tess_gate::TessGate
+//! [`TessGate::render`]: crate::tess_gate::TessGate::render
 
 use std::error;
 use std::fmt;
@@ -95,12 +112,45 @@ use crate::vertex::{Deinterleave, Vertex, VertexDesc};
 /// restart index_ with [`TessBuilder::set_primitive_restart_index`]. Whenever a vertex index is set
 /// to the same value as the _primitive restart index_, the value is not interpreted as a vertex
 /// index but just a marker / hint to start a new primitive.
+///
+/// Most of the time, you don’t have to pick the restart index yourself: use
+/// [`TessBuilder::enable_primitive_restart`], which uses the maximum value of the index type —
+/// `0xFF` for `u8`, `0xFFFF` for `u16` and `0xFFFFFFFF` for `u32` — as restart index. That value
+/// can then not be used as a regular vertex index. WebGL2 doesn’t support any other restart index,
+/// so it’s also the only portable choice.
 #[derive(Copy, Clone, Debug)]
 pub enum Mode {
   /// A single point.
   ///
   /// Points are left unconnected from each other and represent a _point cloud_. This is the typical
   /// primitive mode you want to do, for instance, particles rendering.
+  ///
+  /// Points are rendered as _point sprites_: the vertex shader sets the size in pixels of each
+  /// point by writing to `gl_PointSize`, and the fragment shader gets the coordinates of the
+  /// fragment within the point via `gl_PointCoord`, ranging from `(0, 0)` to `(1, 1)`. That
+  /// allows to texture particles without generating quads:
+  ///
+  /// ```glsl
+  /// // vertex shader
+  /// in vec3 position;
+  /// in float size;
+  ///
+  /// uniform mat4 view_projection;
+  ///
+  /// void main() {
+  ///   gl_Position = view_projection * vec4(position, 1.);
+  ///   gl_PointSize = size;
+  /// }
+  ///
+  /// // fragment shader
+  /// uniform sampler2D sprite;
+  ///
+  /// out vec4 frag;
+  ///
+  /// void main() {
+  ///   frag = texture(sprite, gl_PointCoord);
+  /// }
+  /// ```
   Point,
   /// A line, defined by two points.
   ///
@@ -144,6 +194,8 @@ pub enum Mode {
   /// buffer form a patch.
   ///
   /// If you want to employ tessellation shaders, this is the only primitive mode you can use.
+  /// Conversely, patches can only be rendered with a shader program that has tessellation
+  /// stages; rendering them with any other program is rejected by the backend.
   Patch(usize),
 }
 
@@ -261,6 +313,10 @@ pub enum TessError {
   InternalBufferError(BufferError),
   /// Forbidden primitive mode by hardware.
   ForbiddenPrimitiveMode(Mode),
+  /// Patches were rendered with a shader program that has no tessellation stages.
+  MissingTessellationStages,
+  /// Several instances were rendered but the backend doesn’t support instanced rendering.
+  UnsupportedInstancing,
 }
 
 impl TessError {
@@ -288,6 +344,16 @@ impl TessError {
   pub fn forbidden_primitive_mode(mode: Mode) -> Self {
     TessError::ForbiddenPrimitiveMode(mode)
   }
+
+  /// Patches were rendered with a shader program that has no tessellation stages.
+  pub fn missing_tessellation_stages() -> Self {
+    TessError::MissingTessellationStages
+  }
+
+  /// Several instances were rendered but the backend doesn’t support instanced rendering.
+  pub fn unsupported_instancing() -> Self {
+    TessError::UnsupportedInstancing
+  }
 }
 
 impl fmt::Display for TessError {
@@ -300,6 +366,10 @@ impl fmt::Display for TessError {
       }
       TessError::InternalBufferError(ref e) => write!(f, "internal buffer error: {}", e),
       TessError::ForbiddenPrimitiveMode(ref e) => write!(f, "forbidden primitive mode: {}", e),
+      TessError::MissingTessellationStages => {
+        f.write_str("patches require a shader program with tessellation stages")
+      }
+      TessError::UnsupportedInstancing => f.write_str("instanced rendering is not supported"),
     }
   }
 }
@@ -359,6 +429,12 @@ pub unsafe trait TessIndex: Copy {
   /// `None` means that you disable indexing.
   const INDEX_TYPE: Option<TessIndexType>;
 
+  /// Primitive restart index used by [`TessBuilder::enable_primitive_restart`].
+  ///
+  /// This is the maximum value of the index type, which can then not be used as a vertex index.
+  /// It defaults to `None`, meaning that the type has no such index.
+  const PRIMITIVE_RESTART_INDEX: Option<Self> = None;
+
   /// Get and convert the index to [`u32`], if possible.
   fn try_into_u32(self) -> Option<u32>;
 }
@@ -374,6 +450,7 @@ unsafe impl TessIndex for () {
 /// Boop.
 unsafe impl TessIndex for u8 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U8);
+  const PRIMITIVE_RESTART_INDEX: Option<Self> = Some(u8::MAX);
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
@@ -383,6 +460,7 @@ unsafe impl TessIndex for u8 {
 /// Boop.
 unsafe impl TessIndex for u16 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U16);
+  const PRIMITIVE_RESTART_INDEX: Option<Self> = Some(u16::MAX);
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
@@ -392,6 +470,7 @@ unsafe impl TessIndex for u16 {
 /// Wuuuuuuha.
 unsafe impl TessIndex for u32 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U32);
+  const PRIMITIVE_RESTART_INDEX: Option<Self> = Some(u32::MAX);
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
@@ -527,6 +606,7 @@ where
   vert_nb: usize,
   inst_nb: usize,
   restart_index: Option<I>,
+  bounds: Option<Aabb>,
   _phantom: PhantomData<&'a mut ()>,
 }
 
@@ -569,6 +649,29 @@ where
     self.restart_index = Some(restart_index);
     self
   }
+
+  /// Enable primitive restart, using the maximum value of the index type as restart index.
+  ///
+  /// See [`TessIndex::PRIMITIVE_RESTART_INDEX`] for the value used for each index type. Calling
+  /// that function replaces any index previously set with
+  /// [`TessBuilder::set_primitive_restart_index`]. Index types without a restart index leave
+  /// primitive restart disabled.
+  pub fn enable_primitive_restart(mut self) -> Self {
+    self.restart_index = I::PRIMITIVE_RESTART_INDEX;
+    self
+  }
+
+  /// Set the bounding box of the [`Tess`].
+  ///
+  /// The bounding box is not used for rendering; it’s only stored along with the geometry, to be
+  /// retrieved with [`Tess::bounds`] — for instance, for frustum culling. Use
+  /// [`Aabb::from_points`] to compute it from the positions of the vertices.
+  ///
+  /// Calling that function twice replace the previously set value.
+  pub fn set_bounds(mut self, bounds: Aabb) -> Self {
+    self.bounds = Some(bounds);
+    self
+  }
 }
 
 impl<'a, B, V, I, W, S> TessBuilder<'a, B, V, I, W, S>
@@ -599,6 +702,7 @@ where
       vert_nb: 0,
       inst_nb: 0,
       restart_index: None,
+      bounds: None,
       _phantom: PhantomData,
     }
   }
@@ -629,6 +733,7 @@ where
       vert_nb: self.vert_nb,
       inst_nb: self.inst_nb,
       restart_index: None,
+      bounds: self.bounds,
       _phantom: PhantomData,
     }
   }
@@ -658,6 +763,7 @@ where
       vert_nb: self.vert_nb,
       inst_nb: self.inst_nb,
       restart_index: self.restart_index,
+      bounds: self.bounds,
       _phantom: PhantomData,
     }
   }
@@ -686,6 +792,7 @@ where
       vert_nb: self.vert_nb,
       inst_nb: self.inst_nb,
       restart_index: self.restart_index,
+      bounds: self.bounds,
       _phantom: PhantomData,
     }
   }
@@ -791,6 +898,7 @@ where
     // validate input data before giving it to the backend
     let vert_nb = self.guess_vertex_len()?;
     let inst_nb = self.guess_instance_len()?;
+    let bounds = self.bounds;
 
     unsafe {
       self
@@ -806,6 +914,7 @@ where
         )
         .map(|repr| Tess {
           repr,
+          bounds,
           _phantom: PhantomData,
         })
     }
@@ -874,6 +983,41 @@ where
   }
 }
 
+/// Axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+  /// Minimum corner.
+  pub min: [f32; 3],
+  /// Maximum corner.
+  pub max: [f32; 3],
+}
+
+impl Aabb {
+  /// Create a new [`Aabb`] from its minimum and maximum corners.
+  pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+    Aabb { min, max }
+  }
+
+  /// Compute the smallest [`Aabb`] containing all the input points.
+  ///
+  /// Return [`None`] if there is no point.
+  pub fn from_points(points: impl IntoIterator<Item = [f32; 3]>) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+
+    let aabb = points.fold(Aabb::new(first, first), |mut aabb, p| {
+      for ((min, max), x) in aabb.min.iter_mut().zip(&mut aabb.max).zip(&p) {
+        *min = min.min(*x);
+        *max = max.max(*x);
+      }
+
+      aabb
+    });
+
+    Some(aabb)
+  }
+}
+
 /// A GPU vertex set.
 ///
 /// Vertex set are the only way to represent space data. The dimension you choose is up to you, but
@@ -897,6 +1041,7 @@ where
   S: ?Sized,
 {
   pub(crate) repr: B::TessRepr,
+  bounds: Option<Aabb>,
   _phantom: PhantomData<*const S>,
 }
 
@@ -918,6 +1063,19 @@ where
     unsafe { B::tess_instances_nb(&self.repr) }
   }
 
+  /// Get the bounding box of the [`Tess`], if any was set.
+  pub fn bounds(&self) -> Option<Aabb> {
+    self.bounds
+  }
+
+  /// Set the bounding box of the [`Tess`].
+  ///
+  /// Passing [`None`] removes the bounding box. Update it when changing the positions of the
+  /// vertices via slicing.
+  pub fn set_bounds(&mut self, bounds: impl Into<Option<Aabb>>) {
+    self.bounds = bounds.into();
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _index storage_.
@@ -939,6 +1097,38 @@ where
   }
 }
 
+impl<B> Tess<B, ()>
+where
+  B: ?Sized + TessBackend<(), (), (), Interleaved>,
+{
+  /// Create an attributeless [`Tess`] made of a single triangle covering the whole screen.
+  ///
+  /// That is the usual way to run full-screen passes, such as post-processing: a single triangle
+  /// larger than the screen is clipped to it, which — contrary to a quad made of two triangles —
+  /// doesn’t have a diagonal seam where fragments are shaded twice. The [`Tess`] has three
+  /// vertices and no vertex data: the vertex shader generates the positions and texture
+  /// coordinates from `gl_VertexID`:
+  ///
+  /// ```glsl
+  /// out vec2 v_uv;
+  ///
+  /// void main() {
+  ///   // (0, 0), (2, 0), (0, 2)
+  ///   v_uv = vec2((gl_VertexID << 1) & 2, gl_VertexID & 2);
+  ///   gl_Position = vec4(v_uv * 2. - 1., 0., 1.);
+  /// }
+  /// ```
+  pub fn fullscreen_triangle<C>(ctx: &mut C) -> Result<Self, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    TessBuilder::new(ctx)
+      .set_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+  }
+}
+
 impl<B, V, I, W> Tess<B, V, I, W, Interleaved>
 where
   B: ?Sized + TessBackend<V, I, W, Interleaved>,
@@ -5,7 +5,13 @@
 //! [`Tess`]: crate::tess::Tess
 
 use crate::backend::tess_gate::TessGate as TessGateBackend;
-use crate::tess::{TessIndex, TessVertexData, TessView};
+use crate::tess::{TessError, TessIndex, TessVertexData, TessView};
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// whether the empty tessellation view warning was already logged
+#[cfg(debug_assertions)]
+static EMPTY_VIEW_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// Tessellation gate.
 pub struct TessGate<'a, B>
@@ -20,7 +26,19 @@ where
   B: ?Sized,
 {
   /// Enter the [`TessGate`] by sharing a [`TessView`].
-  pub fn render<'b, T, V, I, W, S>(&'b mut self, tess_view: T)
+  ///
+  /// In debug builds, rendering a view with no vertices — which draws nothing — emits a warning the
+  /// first time it happens. Views are always within the bounds of their [`Tess`], as they are
+  /// checked when created. However, the indices are not checked against the number of vertices and
+  /// the vertex attributes are not checked against the inputs of the shader program.
+  ///
+  /// Rendering fails — and nothing is drawn — if the backend cannot render the view, for instance
+  /// with [`TessError::MissingTessellationStages`] when rendering patches with a shader program
+  /// lacking tessellation stages, or [`TessError::UnsupportedInstancing`] when rendering several
+  /// instances without instanced rendering support.
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  pub fn render<'b, T, V, I, W, S>(&'b mut self, tess_view: T) -> Result<(), TessError>
   where
     B: TessGateBackend<V, I, W, S>,
     T: Into<TessView<'b, B, V, I, W, S>>,
@@ -31,6 +49,11 @@ where
   {
     let tess_view = tess_view.into();
 
+    #[cfg(debug_assertions)]
+    if tess_view.vert_nb == 0 && !EMPTY_VIEW_WARNED.swap(true, Ordering::Relaxed) {
+      log::warn!("rendering a tessellation view with no vertices; nothing is drawn");
+    }
+
     unsafe {
       self.backend.render(
         &tess_view.tess.repr,
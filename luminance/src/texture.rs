@@ -28,6 +28,16 @@
 //!   feel free to read their documentation.
 //!
 //! # Creating a texture
+//!
+//! # Texel layout
+//!
+//! Texels passed to and retrieved from textures are _tightly packed_: rows follow each other
+//! without any padding, whatever the width of the texture and the size of its pixels. For
+//! instance, a 3×3 [`NormRGB8UI`] texture is uploaded with 27 bytes. The pixel store alignment is
+//! adjusted by the backends on every transfer, so that there is no need to realign rows — which
+//! would result in skewed images otherwise.
+//!
+//! [`NormRGB8UI`]: crate::pixel::NormRGB8UI
 
 use std::error;
 use std::fmt;
@@ -414,6 +424,10 @@ impl Dimensionable for Dim2Array {
 }
 
 /// A `Sampler` object gives hint on how a `Texture` should be sampled.
+///
+/// More fields might be added in the future: build samplers from [`Sampler::default`] with
+/// the struct update syntax (`Sampler { mag_filter: MagFilter::Nearest, ..Sampler::default() }`)
+/// rather than listing every field.
 #[derive(Clone, Copy, Debug)]
 pub struct Sampler {
   /// How should we wrap around the *r* sampling coordinate?
@@ -428,9 +442,35 @@ pub struct Sampler {
   pub mag_filter: MagFilter,
   /// For depth textures, should we perform depth comparison and if so, how?
   pub depth_comparison: Option<DepthComparison>,
+  /// For cubemaps, should we filter across the edges of faces?
+  ///
+  /// Seamless filtering samples texels from the neighbor faces near the edges, removing the
+  /// visible seams of filtered environment maps. It is set per texture when the backend supports
+  /// it — with OpenGL, that is the `GL_ARB_seamless_cubemap_per_texture` extension. Otherwise,
+  /// all cubemaps are seamless as long as a cubemap asking for it is alive. WebGL2 cubemaps are
+  /// always seamless.
+  pub seamless_cubemap: bool,
+  /// Minimum level of detail that can be selected when sampling.
+  pub min_lod: f32,
+  /// Maximum level of detail that can be selected when sampling.
+  ///
+  /// Levels of detail are clamped to `min_lod..=max_lod`, which — contrary to
+  /// [`Texture::set_mipmap_levels`] — can be fractional.
+  pub max_lod: f32,
+  /// Bias added to the level of detail computed when sampling.
+  ///
+  /// A negative bias selects finer mipmaps, sharpening the texture at the expense of aliasing.
+  /// WebGL2 doesn’t support it: the bias is ignored there.
+  pub lod_bias: f32,
 }
 
 /// Default value is as following:
+///
+/// - Wrapping is [`Wrap::ClampToEdge`] on all coordinates.
+/// - Minification filter is [`MinFilter::NearestMipmapLinear`].
+/// - Magnification filter is [`MagFilter::Linear`].
+/// - No depth comparison, nor seamless cubemap filtering.
+/// - Levels of detail range from `-1000` to `1000`, without bias.
 impl Default for Sampler {
   fn default() -> Self {
     Sampler {
@@ -440,6 +480,10 @@ impl Default for Sampler {
       min_filter: MinFilter::NearestMipmapLinear,
       mag_filter: MagFilter::Linear,
       depth_comparison: None,
+      seamless_cubemap: false,
+      min_lod: -1000.,
+      max_lod: 1000.,
+      lod_bias: 0.,
     }
   }
 }
@@ -485,6 +529,29 @@ pub enum TextureError {
   CannotRetrieveTexels(String),
   /// Failed to upload texels.
   CannotUploadTexels(String),
+  /// Cannot copy texels between textures.
+  ///
+  /// The carried [`String`] gives the reason of the failure.
+  CannotCopyTexels(String),
+  /// Invalid range of mipmap levels.
+  ///
+  /// The base level must not be greater than the max level, which must be an existing mipmap level
+  /// of the texture.
+  InvalidMipmapLevels {
+    /// Requested base level.
+    base_level: usize,
+    /// Requested max level.
+    max_level: usize,
+  },
+  /// Too many mipmaps were asked for the dimensions of the texture.
+  ///
+  /// See [`Texture::max_mipmaps`] for the number of mipmaps a texture can have.
+  TooManyMipmaps {
+    /// Requested number of extra mipmaps.
+    mipmaps: usize,
+    /// Maximum number of extra mipmaps for the dimensions of the texture.
+    max_mipmaps: usize,
+  },
 }
 
 impl TextureError {
@@ -515,6 +582,27 @@ impl TextureError {
   pub fn cannot_upload_texels(reason: impl Into<String>) -> Self {
     TextureError::CannotUploadTexels(reason.into())
   }
+
+  /// Cannot copy texels between textures.
+  pub fn cannot_copy_texels(reason: impl Into<String>) -> Self {
+    TextureError::CannotCopyTexels(reason.into())
+  }
+
+  /// Invalid range of mipmap levels.
+  pub fn invalid_mipmap_levels(base_level: usize, max_level: usize) -> Self {
+    TextureError::InvalidMipmapLevels {
+      base_level,
+      max_level,
+    }
+  }
+
+  /// Too many mipmaps were asked for the dimensions of the texture.
+  pub fn too_many_mipmaps(mipmaps: usize, max_mipmaps: usize) -> Self {
+    TextureError::TooManyMipmaps {
+      mipmaps,
+      max_mipmaps,
+    }
+  }
 }
 
 impl fmt::Display for TextureError {
@@ -544,6 +632,28 @@ impl fmt::Display for TextureError {
       TextureError::CannotUploadTexels(ref e) => {
         write!(f, "cannot upload texels to texture: {}", e)
       }
+
+      TextureError::CannotCopyTexels(ref e) => {
+        write!(f, "cannot copy texels between textures: {}", e)
+      }
+
+      TextureError::InvalidMipmapLevels {
+        ref base_level,
+        ref max_level,
+      } => write!(
+        f,
+        "invalid mipmap levels: base level {}, max level {}",
+        base_level, max_level
+      ),
+
+      TextureError::TooManyMipmaps {
+        ref mipmaps,
+        ref max_mipmaps,
+      } => write!(
+        f,
+        "too many mipmaps: {} asked, at most {} allowed",
+        mipmaps, max_mipmaps
+      ),
     }
   }
 }
@@ -573,7 +683,13 @@ where
   /// `size` is the wished size of the [`Texture`].
   ///
   /// `mipmaps` is the number of extra mipmaps to allocate with the texture. `0` means that the
-  /// texture will only be made of a _base level_.
+  /// texture will only be made of a _base level_. Use [`Texture::max_mipmaps`] to allocate the full
+  /// mipmap chain, or any smaller number for a partial chain. Asking for more mipmaps than the
+  /// dimensions allow fails with [`TextureError::TooManyMipmaps`].
+  ///
+  /// Stencil-only pixel formats, such as [`Stencil8`](crate::pixel::Stencil8), cannot be used for
+  /// textures and fail with [`TextureError::UnsupportedPixelFormat`]: use a
+  /// [`Renderbuffer`](crate::renderbuffer::Renderbuffer) instead.
   ///
   /// `sampler` is a [`Sampler`] object that will be used when sampling the texture from inside a
   /// shader, for instance.
@@ -591,6 +707,17 @@ where
   where
     C: GraphicsContext<Backend = B>,
   {
+    // stencil textures are not supported before OpenGL 4.4
+    let pf = P::pixel_format();
+    if pf.is_stencil_pixel() {
+      return Err(TextureError::unsupported_pixel_format(pf));
+    }
+
+    let max_mipmaps = Self::max_mipmaps(size);
+    if mipmaps > max_mipmaps {
+      return Err(TextureError::too_many_mipmaps(mipmaps, max_mipmaps));
+    }
+
     unsafe {
       ctx
         .backend()
@@ -603,6 +730,20 @@ where
     }
   }
 
+  /// Maximum number of extra mipmaps a texture of size `size` can have.
+  ///
+  /// That is the number of mipmaps of the full chain, halving the largest dimension down to `1`
+  /// — i.e. `floor(log2(max_dim))`. Array layers are not taken into account.
+  pub fn max_mipmaps(size: D::Size) -> usize {
+    let max_dim = match D::dim() {
+      Dim::Dim1 | Dim::Dim1Array => D::width(size),
+      Dim::Dim2 | Dim::Cubemap | Dim::Dim2Array => D::width(size).max(D::height(size)),
+      Dim::Dim3 => D::width(size).max(D::height(size)).max(D::depth(size)),
+    };
+
+    (31 - max_dim.max(1).leading_zeros()) as usize
+  }
+
   /// Return the number of mipmaps.
   pub fn mipmaps(&self) -> usize {
     unsafe { B::mipmaps(&self.repr) }
@@ -613,6 +754,40 @@ where
     self.size
   }
 
+  /// Resize the texture.
+  ///
+  /// The storage of the texture is reallocated for the new `size`, keeping the same number of
+  /// mipmaps and the same sampler. The previous content of the texture is lost and all its mipmap
+  /// levels can be sampled again (see [`Texture::set_mipmap_levels`]).
+  pub fn resize(&mut self, size: D::Size) -> Result<(), TextureError> {
+    unsafe { B::resize(&mut self.repr, size)? };
+    self.size = size;
+    Ok(())
+  }
+
+  /// Restrict the mipmap levels that can be sampled to `base_level..=max_level`.
+  ///
+  /// Mipmap levels outside of that range are ignored when sampling (and when generating mipmaps).
+  /// That is useful when streaming mipmaps in: allocate the texture with all its mipmaps, upload
+  /// the coarse ones first and lower `base_level` as the finer ones arrive, without re-creating
+  /// the texture. By default, all the mipmap levels of the texture can be sampled.
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::InvalidMipmapLevels`] is returned if `base_level` is greater than
+  /// `max_level` or if `max_level` is not a mipmap level of the texture.
+  pub fn set_mipmap_levels(
+    &mut self,
+    base_level: usize,
+    max_level: usize,
+  ) -> Result<(), TextureError> {
+    if base_level > max_level || max_level >= self.mipmaps() {
+      return Err(TextureError::invalid_mipmap_levels(base_level, max_level));
+    }
+
+    unsafe { B::set_mipmap_levels(&mut self.repr, base_level, max_level) }
+  }
+
   /// Clear the texture with a single pixel value.
   ///
   /// This function will assign the input pixel value to all the pixels in the rectangle described
@@ -676,6 +851,54 @@ where
     unsafe { B::upload_raw(&mut self.repr, gen_mipmaps, self.size, texels) }
   }
 
+  /// Copy a region of a mipmap level of the texture to a mipmap level of another texture.
+  ///
+  /// The region starts at `src_offset` in the `src_level` mipmap of this texture and is copied at
+  /// `dst_offset` in the `dst_level` mipmap of `dst`. Both textures have the same pixel format, so
+  /// texels are copied as-is, without any conversion or filtering — and without any draw. That
+  /// works for array layers and 3D slices too, via the offsets and `size`.
+  ///
+  /// # Errors
+  ///
+  /// [`TextureError::CannotCopyTexels`] is returned if a mipmap level doesn’t exist, if the
+  /// region doesn’t fit in either texture or if the backend doesn’t support copying texels. With
+  /// OpenGL, it requires OpenGL 4.3 or the `GL_ARB_copy_image` extension; WebGL2 doesn’t support
+  /// it.
+  #[allow(clippy::too_many_arguments)]
+  pub fn copy_to(
+    &self,
+    dst: &mut Self,
+    src_level: usize,
+    src_offset: D::Offset,
+    dst_level: usize,
+    dst_offset: D::Offset,
+    size: D::Size,
+  ) -> Result<(), TextureError> {
+    if src_level >= self.mipmaps() || dst_level >= dst.mipmaps() {
+      return Err(TextureError::cannot_copy_texels("no such mipmap level"));
+    }
+
+    if !region_fits::<D>(self.size, src_level, src_offset, size)
+      || !region_fits::<D>(dst.size, dst_level, dst_offset, size)
+    {
+      return Err(TextureError::cannot_copy_texels(
+        "region out of the bounds of the texture",
+      ));
+    }
+
+    unsafe {
+      B::copy_part(
+        &self.repr,
+        src_level,
+        src_offset,
+        &mut dst.repr,
+        dst_level,
+        dst_offset,
+        size,
+      )
+    }
+  }
+
   /// Get a copy of all the pixels from the texture.
   pub fn get_raw_texels(&self) -> Result<Vec<P::RawEncoding>, TextureError>
   where
@@ -684,3 +907,79 @@ where
     unsafe { B::get_raw_texels(&self.repr, self.size) }
   }
 }
+
+impl<B, P> Texture<B, Dim2, P>
+where
+  B: ?Sized + TextureBackend<Dim2, P>,
+  P: Pixel,
+{
+  /// Normalized texture coordinates of a rectangular region of the texture, such as a sprite in
+  /// an atlas.
+  ///
+  /// The region starts at the texel `offset` and spans `size` texels, using the same convention
+  /// as [`Texture::upload_part`]: the texel row at `offset[1]` is the one at `v = offset[1] /
+  /// height`. The coordinates are returned as `[u0, v0, u1, v1]`, `(u0, v0)` being the corner at
+  /// `offset`.
+  ///
+  /// If `half_texel_inset` is `true`, the coordinates are moved half a texel inwards on each side,
+  /// so that linear filtering never samples the texels surrounding the region — which avoids
+  /// bleeding from neighbor sprites.
+  ///
+  /// Returns [`None`] if the end of the region, `offset + size`, overflows.
+  pub fn atlas_uv(
+    &self,
+    offset: [u32; 2],
+    size: [u32; 2],
+    half_texel_inset: bool,
+  ) -> Option<[f32; 4]> {
+    let width = self.size[0].max(1) as f32;
+    let height = self.size[1].max(1) as f32;
+    let inset = if half_texel_inset { 0.5 } else { 0. };
+    let end_x = offset[0].checked_add(size[0])?;
+    let end_y = offset[1].checked_add(size[1])?;
+
+    Some([
+      (offset[0] as f32 + inset) / width,
+      (offset[1] as f32 + inset) / height,
+      (end_x as f32 - inset) / width,
+      (end_y as f32 - inset) / height,
+    ])
+  }
+}
+
+// Check whether a region lies within a given mipmap level of a texture.
+fn region_fits<D>(texture_size: D::Size, level: usize, offset: D::Offset, size: D::Size) -> bool
+where
+  D: Dimensionable,
+{
+  // only spatial dimensions shrink with mipmap levels; layers and cubemap faces don’t
+  let (height_shrinks, depth_shrinks) = match D::dim() {
+    Dim::Dim1 | Dim::Dim1Array => (false, false),
+    Dim::Dim2 | Dim::Cubemap | Dim::Dim2Array => (true, false),
+    Dim::Dim3 => (true, true),
+  };
+
+  let level_dim = |dim: u32, shrinks: bool| {
+    if shrinks {
+      dim.checked_shr(level as u32).unwrap_or(0).max(1)
+    } else {
+      dim
+    }
+  };
+
+  let fits = |offset: u32, len: u32, dim: u32| offset as u64 + len as u64 <= dim as u64;
+
+  fits(
+    D::x_offset(offset),
+    D::width(size),
+    level_dim(D::width(texture_size), true),
+  ) && fits(
+    D::y_offset(offset),
+    D::height(size),
+    level_dim(D::height(texture_size), height_shrinks),
+  ) && fits(
+    D::z_offset(offset),
+    D::depth(size),
+    level_dim(D::depth(texture_size), depth_shrinks),
+  )
+}
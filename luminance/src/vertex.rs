@@ -210,8 +210,16 @@ pub unsafe trait VertexAttrib {
 /// several tessellations – that allows better composition with shaders. Basically, the best advice
 /// to follow: define your semantics once, and keep to them.
 ///
+/// When a [`Program`] is created with a given semantics type, the [`Semantics::index`] of each
+/// semantics is bound as the location of the vertex attribute with the same name, before the
+/// program gets linked. Every program sharing a semantics type then agrees on the attribute
+/// locations — without any `layout (location = …)` in the shaders — and can render the same
+/// tessellations.
+///
 /// > Note: feel free to use the [luminance-derive] crate to automatically derive this trait from
 /// > an `enum`.
+///
+/// [`Program`]: crate::shader::Program
 pub trait Semantics: Sized + Copy + Clone + Debug {
   /// Retrieve the semantics index of this semantics.
   fn index(&self) -> usize;
@@ -0,0 +1,41 @@
+use luminance::pipeline::{ClearColor, PipelineState};
+
+#[test]
+fn enable_clear_toggles_all_buffers() {
+  let st = PipelineState::default()
+    .enable_clear_depth(false)
+    .enable_clear(true);
+
+  assert!(st.is_clear_color_enabled());
+  assert!(st.is_clear_depth_enabled());
+  assert!(st.is_clear_stencil_enabled());
+
+  let st = st.enable_clear(false);
+
+  assert!(!st.is_clear_color_enabled());
+  assert!(!st.is_clear_depth_enabled());
+  assert!(!st.is_clear_stencil_enabled());
+}
+
+#[test]
+fn enable_clear_keeps_clear_values() {
+  let st = PipelineState::default()
+    .set_clear_color([0.1, 0.2, 0.3, 1.])
+    .set_clear_depth(0.5)
+    .set_clear_stencil(3)
+    .enable_clear(false);
+
+  assert_eq!(st.clear_color(), ClearColor::Float([0.1, 0.2, 0.3, 1.]));
+  assert_eq!(st.clear_depth(), 0.5);
+  assert_eq!(st.clear_stencil(), 3);
+}
+
+#[test]
+fn integer_clear_colors_are_kept_exact() {
+  let st = PipelineState::default().set_clear_color([u32::MAX, 0, 0, 0]);
+  assert_eq!(st.clear_color(), ClearColor::UInt([u32::MAX, 0, 0, 0]));
+  assert_eq!(st.clear_color().as_uint(), [u32::MAX, 0, 0, 0]);
+
+  let st = PipelineState::default().set_clear_color([-1, i32::MAX, 0, 0]);
+  assert_eq!(st.clear_color().as_int(), [-1, i32::MAX, 0, 0]);
+}
@@ -0,0 +1,21 @@
+use luminance::polygon_offset::PolygonOffset;
+use luminance::render_state::RenderState;
+
+fn assert_eq_impl<T: Eq>() {}
+
+#[test]
+fn render_state_is_eq() {
+  assert_eq_impl::<RenderState>();
+}
+
+#[test]
+fn floating_point_settings_compare_bitwise() {
+  let st = RenderState::default()
+    .set_min_sample_shading(f32::NAN)
+    .set_polygon_offset(PolygonOffset::new(f32::NAN, 1.))
+    .set_depth_bounds((0., 0.5));
+
+  assert_eq!(st, st.clone());
+  assert!(st.diff(&st.clone()).is_empty());
+  assert_ne!(st, st.clone().set_depth_bounds((-0., 0.5)));
+}